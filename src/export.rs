@@ -0,0 +1,62 @@
+// Text formats for copying or exporting the issue list out of the app
+
+/// GitHub-flavoured Markdown table, with every column padded to its widest cell
+pub fn to_gfm_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    // Pipes would end the cell early and newlines the row
+    let escape = |cell: &str| cell.replace('|', "\\|").replace(['\r', '\n'], " ");
+    let headers: Vec<String> = headers.iter().map(|h| escape(h)).collect();
+    let rows: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| row.iter().map(|cell| escape(cell)).collect())
+        .collect();
+
+    // Separator cells need at least three dashes
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count().max(3)).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[String]| {
+        let padded: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(i, width)| {
+                let cell = cells.get(i).map(String::as_str).unwrap_or("");
+                format!("{}{}", cell, " ".repeat(width - cell.chars().count()))
+            })
+            .collect();
+        format!("| {} |\n", padded.join(" | "))
+    };
+
+    let mut table = format_row(&headers);
+    let separator: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+    table.push_str(&format!("| {} |\n", separator.join(" | ")));
+    for row in &rows {
+        table.push_str(&format_row(row));
+    }
+    table
+}
+
+/// RFC 4180 CSV with a header row; fields with commas, quotes or line breaks are quoted
+pub fn to_csv(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let escape = |cell: &str| {
+        if cell.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", cell.replace('"', "\"\""))
+        } else {
+            cell.to_string()
+        }
+    };
+
+    let mut csv = String::new();
+    let header_cells: Vec<String> = headers.iter().map(|h| escape(h)).collect();
+    csv.push_str(&header_cells.join(","));
+    csv.push_str("\r\n");
+    for row in rows {
+        let cells: Vec<String> = row.iter().map(|cell| escape(cell)).collect();
+        csv.push_str(&cells.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
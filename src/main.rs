@@ -1,11 +1,20 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
     fs,
+    io::{BufRead, BufReader, Write},
     path::{Path, PathBuf},
-    process::Command,
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Condvar, Mutex,
+    },
+    thread,
 };
 
+mod export;
+
 use eframe::egui;
+use egui_commonmark::{CommonMarkCache, CommonMarkViewer};
 use egui_extras::{Column, TableBuilder};
 use font_kit::{family_name::FamilyName, properties::Properties, source::SystemSource};
 use serde::{Deserialize, Serialize};
@@ -16,2430 +25,9565 @@ struct Issue {
     title: String,
     description: String,
     status: String,
+    #[serde(deserialize_with = "deserialize_priority")]
     priority: i32,
     issue_type: String,
     #[serde(default)]
     assignee: Option<String>,
+    // Reporter of the issue
+    #[serde(default)]
+    created_by: Option<String>,
     #[serde(default)]
     notes: Option<String>,
     created_at: String,
     updated_at: String,
     #[serde(default)]
+    closed_at: Option<String>,
+    #[serde(default)]
     dependencies: Vec<Issue>,
     #[serde(default)]
+    due_date: Option<String>,
+    // bd stores these as "labels"
+    #[serde(default, alias = "labels")]
+    tags: Vec<String>,
+    // Team-specific metadata such as "component" or "customer"
+    #[serde(default)]
+    custom_fields: HashMap<String, String>,
+    #[serde(default, deserialize_with = "deserialize_comments")]
+    comments: Vec<Comment>,
+    // Personal accent color (hex) from user_labels.json, not the issue database
+    #[serde(skip)]
+    user_label_color: Option<String>,
+    #[serde(default)]
     source_directory: String,
+    // Path of the monitored directory this issue was loaded from
+    #[serde(skip)]
+    source_path: PathBuf,
+    // bd list included every show-only field, so the entry can be cached without a bd show
+    #[serde(skip)]
+    listed_in_full: bool,
 }
 
-// Configuration for a single monitored directory
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct DirectoryConfig {
-    path: PathBuf,
-    visible: bool,
-    #[serde(default)]
-    display_name: String,
+/// Accept integer priorities as well as the named levels some backends emit.
+/// Always serialized back as an integer.
+fn deserialize_priority<'de, D>(deserializer: D) -> Result<i32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawPriority {
+        Number(i32),
+        Name(String),
+    }
+
+    Ok(match RawPriority::deserialize(deserializer)? {
+        RawPriority::Number(priority) => priority,
+        RawPriority::Name(name) => match name.trim().to_lowercase().as_str() {
+            "critical" => 0,
+            "high" => 1,
+            "medium" => 2,
+            "low" => 3,
+            "backlog" => 4,
+            // Unknown names are treated as medium
+            _ => 2,
+        },
+    })
 }
 
-// Application configuration persisted to ~/.config/beadui/config.yaml
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-struct AppConfig {
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Comment {
     #[serde(default)]
-    directories: Vec<DirectoryConfig>,
+    id: i64,
     #[serde(default)]
-    sidebar_collapsed: bool,
+    author: String,
+    text: String,
+    #[serde(default)]
+    created_at: String,
+    #[serde(default)]
+    resolved: bool,
 }
 
-impl AppConfig {
-    /// Get the path to the config file: ~/.config/beadui/config.yaml
-    fn config_path() -> Option<PathBuf> {
-        dirs::config_dir().map(|mut path| {
-            path.push("beadui");
-            path.push("config.yaml");
-            path
-        })
-    }
+fn unresolved_comment_count(issue: &Issue) -> usize {
+    issue.comments.iter().filter(|c| !c.resolved).count()
+}
 
-    /// Load config from ~/.config/beadui/config.yaml
-    /// Returns default config if file doesn't exist or is corrupt
-    fn load() -> Self {
-        let config_path = match Self::config_path() {
-            Some(path) => path,
-            None => return Self::default(),
-        };
+/// bd comments cannot be edited, so resolving one appends a marker comment instead
+fn resolution_marker(comment_id: i64, resolved: bool) -> String {
+    let verb = if resolved { "Resolved" } else { "Reopened" };
+    format!("{} comment #{}", verb, comment_id)
+}
 
-        // If file doesn't exist, return default
-        if !config_path.exists() {
-            return Self::default();
-        }
+fn parse_resolution_marker(text: &str) -> Option<(i64, bool)> {
+    let (verb, id) = text.trim().split_once(" comment #")?;
+    let resolved = match verb {
+        "Resolved" => true,
+        "Reopened" => false,
+        _ => return None,
+    };
+    Some((id.parse().ok()?, resolved))
+}
 
-        // Try to read and parse the file
-        match fs::read_to_string(&config_path) {
-            Ok(contents) => serde_yaml::from_str::<AppConfig>(&contents).unwrap_or_default(),
-            Err(_) => Self::default(),
+/// Apply the latest resolution marker to each comment and drop the markers themselves
+fn apply_resolution_markers(comments: Vec<Comment>) -> Vec<Comment> {
+    let mut resolved = HashMap::new();
+    let mut kept = Vec::new();
+    for comment in comments {
+        match parse_resolution_marker(&comment.text) {
+            Some((id, is_resolved)) => {
+                resolved.insert(id, is_resolved);
+            }
+            None => kept.push(comment),
         }
     }
-
-    /// Save config to ~/.config/beadui/config.yaml
-    /// Creates directory if it doesn't exist
-    fn save(&self) -> Result<(), String> {
-        let config_path = Self::config_path()
-            .ok_or_else(|| "Could not determine config directory".to_string())?;
-
-        // Create parent directory if it doesn't exist
-        if let Some(parent) = config_path.parent() {
-            fs::create_dir_all(parent)
-                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    for comment in &mut kept {
+        if let Some(&is_resolved) = resolved.get(&comment.id) {
+            comment.resolved = is_resolved;
         }
+    }
+    kept
+}
 
-        // Serialize to YAML
-        let yaml = serde_yaml::to_string(self)
-            .map_err(|e| format!("Failed to serialize config: {}", e))?;
+fn deserialize_comments<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Comment>, D::Error> {
+    Vec::<Comment>::deserialize(deserializer).map(apply_resolution_markers)
+}
 
-        // Write to file
-        fs::write(&config_path, yaml).map_err(|e| format!("Failed to write config file: {}", e))?;
+/// A GitHub-style task list line (`- [ ] item` / `- [x] item`) in the notes
+#[derive(Debug, Clone)]
+struct ChecklistItem {
+    checked: bool,
+    text: String,
+    line_index: usize,
+}
 
-        Ok(())
-    }
+fn parse_checklist_items(text: &str) -> Vec<ChecklistItem> {
+    text.lines()
+        .enumerate()
+        .filter_map(|(line_index, line)| {
+            let rest = line.trim_start().strip_prefix("- [")?;
+            let (mark, item) = rest.split_at_checked(2)?;
+            let checked = match mark {
+                " ]" => false,
+                "x]" | "X]" => true,
+                _ => return None,
+            };
+            Some(ChecklistItem {
+                checked,
+                text: item.trim().to_string(),
+                line_index,
+            })
+        })
+        .collect()
+}
 
-    /// Abbreviate path by replacing home directory with ~
-    fn abbreviate_path(path: impl AsRef<Path>) -> String {
-        let path = path.as_ref();
-        if let Some(home_dir) = dirs::home_dir() {
-            if let Ok(suffix) = path.strip_prefix(&home_dir) {
-                return format!("~/{}", suffix.display());
+/// Rewrite the checkbox marker on one notes line, leaving every other line untouched
+fn set_checklist_item(text: &str, line_index: usize, checked: bool) -> String {
+    text.split('\n')
+        .enumerate()
+        .map(|(i, line)| {
+            if i != line_index {
+                return line.to_string();
             }
-        }
-        path.display().to_string()
-    }
+            let indent = line.len() - line.trim_start().len();
+            let mark = if checked { "x" } else { " " };
+            format!("{}- [{}]{}", &line[..indent], mark, &line[indent + 5..])
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
-    /// Compute display names for all directories
-    /// Shows just the base name for unique names, or "base (~/path)" for duplicates
-    fn compute_display_names(&mut self) {
-        // Group directories by their base name
-        let mut base_name_groups: HashMap<String, Vec<usize>> = HashMap::new();
+/// Small filled circle with a number in it, used for row badges
+fn draw_count_badge(ui: &mut egui::Ui, count: usize) -> egui::Response {
+    let font = egui::FontId::proportional(10.0);
+    let galley =
+        ui.painter()
+            .layout_no_wrap(count.to_string(), font, ui.visuals().strong_text_color());
+    let diameter = (galley.size().x + 6.0).max(16.0);
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(diameter, 16.0), egui::Sense::hover());
+    ui.painter().rect_filled(
+        rect,
+        8.0,
+        ui.visuals().selection.bg_fill.gamma_multiply(0.6),
+    );
+    ui.painter().galley(
+        rect.center() - galley.size() / 2.0,
+        galley,
+        egui::Color32::PLACEHOLDER,
+    );
+    response
+}
 
-        for (idx, dir) in self.directories.iter().enumerate() {
-            let base_name = dir
-                .path
-                .file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or("")
-                .to_string();
+fn parse_timestamp(value: &str) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    chrono::DateTime::parse_from_rfc3339(value).ok()
+}
 
-            base_name_groups.entry(base_name).or_default().push(idx);
-        }
+/// Whether a timestamp falls in the ISO week (Monday to Sunday, local time) `weeks_ago` weeks back
+fn is_in_week(timestamp: &str, weeks_ago: u32) -> bool {
+    use chrono::Datelike;
+    let reference = chrono::Local::now().date_naive() - chrono::Duration::weeks(weeks_ago as i64);
+    parse_timestamp(timestamp)
+        .map(|t| t.with_timezone(&chrono::Local).date_naive().iso_week() == reference.iso_week())
+        .unwrap_or(false)
+}
 
-        // Set display names based on uniqueness
-        for (base_name, indices) in base_name_groups {
-            if indices.len() == 1 {
-                // Unique name - just show base name
-                let idx = indices[0];
-                self.directories[idx].display_name = base_name;
-            } else {
-                // Duplicate names - show base name with abbreviated path
-                for idx in indices {
-                    let abbreviated = Self::abbreviate_path(&self.directories[idx].path);
-                    self.directories[idx].display_name = format!("{} ({})", base_name, abbreviated);
-                }
-            }
-        }
-    }
+fn is_this_week(timestamp: &str) -> bool {
+    is_in_week(timestamp, 0)
 }
 
-// Snapshot-based cache for BdClient results
-#[derive(Clone)]
-struct SnapshotCache {
-    get_issue_cache: HashMap<String, Issue>,
-    // Map from issue_id -> (source_directory, db_path)
-    issue_sources: HashMap<String, (String, Option<PathBuf>)>,
+// Quick filter to issues created or updated in a recent ISO week
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WeekFilter {
+    ThisWeek,
+    LastWeek,
 }
 
-impl SnapshotCache {
-    fn new() -> Self {
-        Self {
-            get_issue_cache: HashMap::new(),
-            issue_sources: HashMap::new(),
+impl WeekFilter {
+    fn matches(self, timestamp: &str) -> bool {
+        match self {
+            WeekFilter::ThisWeek => is_this_week(timestamp),
+            WeekFilter::LastWeek => is_in_week(timestamp, 1),
         }
     }
+}
 
-    fn clear(&mut self) {
-        self.get_issue_cache.clear();
-        self.issue_sources.clear();
-    }
-
-    fn register_issue_source(
-        &mut self,
-        issue_id: &str,
-        source_directory: &str,
-        db_path: Option<PathBuf>,
-    ) {
-        self.issue_sources.insert(
-            issue_id.to_string(),
-            (source_directory.to_string(), db_path),
-        );
-    }
+// Field a `field:value` term in the filter box is restricted to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SearchField {
+    Id,
+    Title,
+    Description,
+    // Raw status or readiness, so `status:blocked` works
+    Status,
+    Type,
+    Assignee,
+    // Only searched through its prefix, e.g. `priority:1`
+    Priority,
+    Blockers,
+    Dependents,
+}
 
-    fn get_issue(&mut self, id: &str) -> Result<Issue, String> {
-        // Check cache first
-        if let Some(cached_issue) = self.get_issue_cache.get(id) {
-            return Ok(cached_issue.clone());
+impl SearchField {
+    fn from_prefix(prefix: &str) -> Option<Self> {
+        match prefix.to_lowercase().as_str() {
+            "id" => Some(SearchField::Id),
+            "title" => Some(SearchField::Title),
+            "description" | "desc" => Some(SearchField::Description),
+            "status" => Some(SearchField::Status),
+            "type" => Some(SearchField::Type),
+            "assignee" => Some(SearchField::Assignee),
+            "priority" => Some(SearchField::Priority),
+            "blockers" => Some(SearchField::Blockers),
+            "dependents" => Some(SearchField::Dependents),
+            _ => None,
         }
-
-        // Cache miss - fetch from CLI using the registered source
-        let db_path = self
-            .issue_sources
-            .get(id)
-            .and_then(|(_, path)| path.clone());
-        let issue = BdClient::get_issue_uncached(id, db_path.as_ref())?;
-
-        // Store in cache
-        self.get_issue_cache.insert(id.to_string(), issue.clone());
-
-        Ok(issue)
     }
 }
 
-struct BdClient;
+// Parsed filter box text: `/pattern` is a case-insensitive regex over every field;
+// otherwise `field:value` terms each match one field and the remaining text is the
+// usual substring search across all of them
+enum SearchQuery {
+    Regex(regex::Regex),
+    Terms {
+        fields: Vec<(SearchField, String)>,
+        text: String,
+    },
+}
 
-impl BdClient {
-    fn list_issues(
-        db_path: Option<&PathBuf>,
-        source_directory: &str,
-    ) -> Result<Vec<Issue>, String> {
-        let mut cmd = Command::new("bd");
-        cmd.arg("list").arg("--json");
+impl SearchQuery {
+    /// None when there is nothing to filter by, including a regex that doesn't compile
+    fn parse(query: &str) -> Option<Self> {
+        let query = query.trim();
+        if query.is_empty() {
+            return None;
+        }
+        if let Some(pattern) = query.strip_prefix('/') {
+            return regex::RegexBuilder::new(pattern)
+                .case_insensitive(true)
+                .build()
+                .ok()
+                .map(SearchQuery::Regex);
+        }
 
-        // Add --db flag if db_path is provided
-        if let Some(path) = db_path {
-            // Construct path to .beads/*.db file
-            let mut db_file = path.clone();
-            db_file.push(".beads");
+        let mut fields = Vec::new();
+        let mut words = Vec::new();
+        for word in query.split_whitespace() {
+            // Unknown prefixes (e.g. a pasted URL) are searched as plain text
+            let field = word
+                .split_once(':')
+                .filter(|(_, value)| !value.is_empty())
+                .and_then(|(prefix, value)| Some((SearchField::from_prefix(prefix)?, value)));
+            match field {
+                Some((field, value)) => fields.push((field, value.to_lowercase())),
+                None => words.push(word),
+            }
+        }
+        // Without any field terms this is the whole query, spacing and all
+        let text = if fields.is_empty() {
+            query.to_lowercase()
+        } else {
+            words.join(" ").to_lowercase()
+        };
+        Some(SearchQuery::Terms { fields, text })
+    }
 
-            // Find the .db file in .beads directory
-            if let Ok(entries) = fs::read_dir(&db_file) {
-                for entry in entries.flatten() {
-                    let entry_path = entry.path();
-                    if entry_path.extension().and_then(|s| s.to_str()) == Some("db") {
-                        cmd.arg("--db").arg(&entry_path);
-                        break;
-                    }
-                }
+    /// Whether an issue with these field values (see search_values) matches
+    fn matches(&self, values: &[(SearchField, String)]) -> bool {
+        let mut any_field = values
+            .iter()
+            .filter(|(field, _)| *field != SearchField::Priority)
+            .map(|(_, value)| value);
+        match self {
+            SearchQuery::Regex(re) => any_field.any(|value| re.is_match(value)),
+            SearchQuery::Terms { fields, text } => {
+                let fields_match = fields.iter().all(|(field, term)| {
+                    values
+                        .iter()
+                        .any(|(f, value)| f == field && value.contains(term.as_str()))
+                });
+                fields_match && (text.is_empty() || any_field.any(|value| value.contains(text)))
             }
         }
+    }
+}
 
-        let output = cmd
-            .output()
-            .map_err(|e| format!("Failed to execute bd: {}", e))?;
+/// Lowercased values the filter box searches for an issue
+fn search_values(
+    issue: &Issue,
+    readiness: &str,
+    blockers_count: usize,
+    dependents_count: usize,
+) -> Vec<(SearchField, String)> {
+    let mut values = vec![
+        (SearchField::Id, issue.id.to_lowercase()),
+        (SearchField::Title, issue.title.to_lowercase()),
+        (SearchField::Description, issue.description.to_lowercase()),
+        (SearchField::Status, issue.status.to_lowercase()),
+        (SearchField::Status, readiness.to_lowercase()),
+        (SearchField::Type, issue.issue_type.to_lowercase()),
+        (SearchField::Priority, format!("p{}", issue.priority)),
+        (SearchField::Blockers, blockers_count.to_string()),
+        (SearchField::Dependents, dependents_count.to_string()),
+    ];
+    if let Some(assignee) = &issue.assignee {
+        values.push((SearchField::Assignee, assignee.to_lowercase()));
+    }
+    values
+}
 
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
-        }
+/// Compact "time ago" string, e.g. "just now", "5m ago", "3h ago", "2d ago"
+fn format_relative_time(
+    timestamp: chrono::DateTime<chrono::FixedOffset>,
+    now: chrono::DateTime<chrono::Local>,
+) -> String {
+    let elapsed = now.signed_duration_since(timestamp);
+    if elapsed.num_seconds() < 60 {
+        "just now".to_string()
+    } else if elapsed.num_minutes() < 60 {
+        format!("{}m ago", elapsed.num_minutes())
+    } else if elapsed.num_hours() < 24 {
+        format!("{}h ago", elapsed.num_hours())
+    } else if elapsed.num_days() < 14 {
+        format!("{}d ago", elapsed.num_days())
+    } else if elapsed.num_days() < 60 {
+        format!("{}w ago", elapsed.num_weeks())
+    } else if elapsed.num_days() < 365 {
+        format!("{}mo ago", elapsed.num_days() / 30)
+    } else {
+        format!("{}y ago", elapsed.num_days() / 365)
+    }
+}
 
-        let json = String::from_utf8_lossy(&output.stdout);
-        let mut issues: Vec<Issue> =
-            serde_json::from_str(&json).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+fn format_updated_at(issue: &Issue) -> String {
+    parse_timestamp(&issue.updated_at)
+        .map(|updated| format_relative_time(updated, chrono::Local::now()))
+        .unwrap_or_else(|| issue.updated_at.clone())
+}
 
-        // Set source_directory on all issues
-        for issue in &mut issues {
-            issue.source_directory = source_directory.to_string();
-        }
+/// Parse a due date stored either as a plain date or an RFC 3339 timestamp
+fn parse_due_date(value: &str) -> Option<chrono::NaiveDate> {
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .or_else(|| {
+            chrono::DateTime::parse_from_rfc3339(value)
+                .ok()
+                .map(|dt| dt.date_naive())
+        })
+}
 
-        Ok(issues)
+fn format_due_date(issue: &Issue) -> String {
+    issue
+        .due_date
+        .as_deref()
+        .and_then(parse_due_date)
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| "–".to_string())
+}
+
+// Compact month-grid date picker
+struct CalendarWidget<'a> {
+    selected_date: &'a mut Option<chrono::NaiveDate>,
+}
+
+impl<'a> CalendarWidget<'a> {
+    fn new(selected_date: &'a mut Option<chrono::NaiveDate>) -> Self {
+        Self { selected_date }
     }
+}
 
-    fn list_issues_from_all(directories: &[DirectoryConfig]) -> Vec<Issue> {
-        let mut all_issues = Vec::new();
+impl egui::Widget for CalendarWidget<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        use chrono::Datelike;
 
-        for dir_config in directories {
-            if !dir_config.visible {
-                continue;
-            }
+        let today = chrono::Local::now().date_naive();
+        let month_id = ui.id().with("calendar_month");
 
-            // Use display_name as source_directory identifier
-            let source_name = if dir_config.display_name.is_empty() {
-                dir_config
-                    .path
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("")
-                    .to_string()
-            } else {
-                dir_config.display_name.clone()
-            };
+        // The displayed month is kept in egui memory so the arrows survive across frames
+        let mut month = ui
+            .data(|d| d.get_temp::<chrono::NaiveDate>(month_id))
+            .unwrap_or_else(|| {
+                let anchor = self.selected_date.unwrap_or(today);
+                anchor.with_day(1).unwrap_or(anchor)
+            });
+        let mut changed = false;
 
-            match Self::list_issues(Some(&dir_config.path), &source_name) {
-                Ok(mut issues) => {
-                    all_issues.append(&mut issues);
+        let inner = ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                if ui.small_button("◀").clicked() {
+                    month = month
+                        .checked_sub_months(chrono::Months::new(1))
+                        .unwrap_or(month);
                 }
-                Err(_) => {
-                    // Silently skip directories that fail to load
-                    // Could add error tracking here if needed
+                ui.label(egui::RichText::new(month.format("%B %Y").to_string()).strong());
+                if ui.small_button("▶").clicked() {
+                    month = month
+                        .checked_add_months(chrono::Months::new(1))
+                        .unwrap_or(month);
                 }
-            }
-        }
+            });
 
-        all_issues
-    }
+            egui::Grid::new(month_id.with("grid"))
+                .spacing([2.0, 2.0])
+                .show(ui, |ui| {
+                    for weekday in ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"] {
+                        ui.label(egui::RichText::new(weekday).small());
+                    }
+                    ui.end_row();
 
-    fn get_issue_uncached(id: &str, db_path: Option<&PathBuf>) -> Result<Issue, String> {
-        let mut cmd = Command::new("bd");
-        cmd.arg("show").arg(id).arg("--json");
+                    let mut column = month.weekday().num_days_from_monday();
+                    for _ in 0..column {
+                        ui.label("");
+                    }
 
-        // Add --db flag if db_path is provided
-        if let Some(path) = db_path {
-            // Construct path to .beads/*.db file
-            let mut db_file = path.clone();
-            db_file.push(".beads");
+                    let mut day = month;
+                    while day.month() == month.month() {
+                        let mut text = egui::RichText::new(day.day().to_string());
+                        if day == today {
+                            text = text.underline();
+                        }
+                        let is_selected = *self.selected_date == Some(day);
+                        if ui
+                            .add_sized([24.0, 18.0], egui::SelectableLabel::new(is_selected, text))
+                            .clicked()
+                        {
+                            *self.selected_date = Some(day);
+                            changed = true;
+                        }
 
-            // Find the .db file in .beads directory
-            if let Ok(entries) = fs::read_dir(&db_file) {
-                for entry in entries.flatten() {
-                    let entry_path = entry.path();
-                    if entry_path.extension().and_then(|s| s.to_str()) == Some("db") {
-                        cmd.arg("--db").arg(&entry_path);
-                        break;
+                        column += 1;
+                        if column == 7 {
+                            ui.end_row();
+                            column = 0;
+                        }
+                        match day.succ_opt() {
+                            Some(next) => day = next,
+                            None => break,
+                        }
                     }
-                }
+                });
+
+            if ui.small_button("Clear").clicked() {
+                *self.selected_date = None;
+                changed = true;
             }
-        }
+        });
 
-        let output = cmd
-            .output()
-            .map_err(|e| format!("Failed to execute bd: {}", e))?;
+        ui.data_mut(|d| d.insert_temp(month_id, month));
 
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        let mut response = inner.response;
+        if changed {
+            response.mark_changed();
         }
+        response
+    }
+}
 
-        let json = String::from_utf8_lossy(&output.stdout);
-        serde_json::from_str(&json).map_err(|e| format!("Failed to parse JSON: {}", e))
+// Comma-separated tag field that suggests existing tags matching the tag being typed
+struct TagAutocomplete<'a> {
+    // Known tags with usage counts, most used first
+    all_tags: &'a [(String, usize)],
+    text: &'a mut String,
+}
+
+impl<'a> TagAutocomplete<'a> {
+    fn new(all_tags: &'a [(String, usize)], text: &'a mut String) -> Self {
+        Self { all_tags, text }
     }
+}
 
-    fn update_issue(id: &str, field: &str, value: &str, db_path: Option<&PathBuf>) -> Result<(), String> {
-        let mut cmd = Command::new("bd");
-        cmd.arg("update")
-            .arg(id)
-            .arg(format!("--{}", field))
-            .arg(value);
+impl egui::Widget for TagAutocomplete<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let edit_id = ui.make_persistent_id("tag_autocomplete");
+        let popup_id = edit_id.with("popup");
+
+        // The tag being typed is whatever follows the last comma
+        let split = self.text.rfind(',').map(|i| i + 1).unwrap_or(0);
+        let (prefix, query) = self.text.split_at(split);
+        let query = query.trim().to_lowercase();
+        let entered = parse_tags(prefix);
+        let suggestions: Vec<&(String, usize)> = self
+            .all_tags
+            .iter()
+            .filter(|(tag, _)| {
+                tag.to_lowercase().contains(&query)
+                    && tag.to_lowercase() != query
+                    && !entered.contains(tag)
+            })
+            .take(8)
+            .collect();
 
-        // Add --db flag if db_path is provided
-        if let Some(path) = db_path {
-            // Construct path to .beads/*.db file
-            let mut db_file = path.clone();
-            db_file.push(".beads");
+        let had_focus = ui.memory(|mem| mem.has_focus(edit_id));
+        let tab_pressed = had_focus && ui.input(|i| i.key_pressed(egui::Key::Tab));
+
+        // Lock focus while there are suggestions so Tab completes instead of moving on
+        let mut response = ui.add(
+            egui::TextEdit::singleline(self.text)
+                .id(edit_id)
+                .hint_text("tag1, tag2")
+                .lock_focus(!suggestions.is_empty())
+                .desired_width(f32::INFINITY),
+        );
 
-            // Find the .db file in .beads directory
-            if let Ok(entries) = fs::read_dir(&db_file) {
-                for entry in entries.flatten() {
-                    let entry_path = entry.path();
-                    if entry_path.extension().and_then(|s| s.to_str()) == Some("db") {
-                        cmd.arg("--db").arg(&entry_path);
-                        break;
+        let mut completion = None;
+        if response.has_focus() {
+            if suggestions.is_empty() || ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                ui.memory_mut(|mem| {
+                    if mem.is_popup_open(popup_id) {
+                        mem.close_popup();
                     }
-                }
+                });
+            } else {
+                ui.memory_mut(|mem| mem.open_popup(popup_id));
+            }
+            if tab_pressed {
+                completion = suggestions.first().map(|(tag, _)| tag.clone());
             }
         }
 
-        let output = cmd
-            .output()
-            .map_err(|e| format!("Failed to execute bd: {}", e))?;
-
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
-        }
-
-        Ok(())
-    }
-
-    fn add_dependency(blocked_issue_id: &str, blocker_issue_id: &str, db_path: Option<&PathBuf>) -> Result<(), String> {
-        // bd dep add <blocked> <blocker>
-        let mut cmd = Command::new("bd");
-        cmd.arg("dep")
-            .arg("add")
-            .arg(blocked_issue_id)
-            .arg(blocker_issue_id);
-
-        // Add --db flag if db_path is provided
-        if let Some(path) = db_path {
-            // Construct path to .beads/*.db file
-            let mut db_file = path.clone();
-            db_file.push(".beads");
-
-            // Find the .db file in .beads directory
-            if let Ok(entries) = fs::read_dir(&db_file) {
-                for entry in entries.flatten() {
-                    let entry_path = entry.path();
-                    if entry_path.extension().and_then(|s| s.to_str()) == Some("db") {
-                        cmd.arg("--db").arg(&entry_path);
-                        break;
+        egui::popup_below_widget(
+            ui,
+            popup_id,
+            &response,
+            egui::PopupCloseBehavior::CloseOnClickOutside,
+            |ui| {
+                ui.set_min_width(150.0);
+                for (tag, count) in &suggestions {
+                    let label = format!("{} ({})", tag, count);
+                    if ui.selectable_label(false, label).clicked() {
+                        completion = Some(tag.clone());
                     }
                 }
-            }
-        }
-
-        let output = cmd
-            .output()
-            .map_err(|e| format!("Failed to execute bd: {}", e))?;
+            },
+        );
 
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        if let Some(tag) = completion {
+            // Recompute the split since the text may have been edited this frame
+            let split = self.text.rfind(',').map(|i| i + 1).unwrap_or(0);
+            let mut completed = self.text[..split].to_string();
+            if !completed.is_empty() {
+                completed.push(' ');
+            }
+            completed.push_str(&tag);
+            completed.push_str(", ");
+            *self.text = completed;
+
+            // Keep typing the next tag with the cursor after the completion
+            if let Some(mut state) = egui::TextEdit::load_state(ui.ctx(), edit_id) {
+                let end = egui::text::CCursor::new(self.text.chars().count());
+                state
+                    .cursor
+                    .set_char_range(Some(egui::text::CCursorRange::one(end)));
+                state.store(ui.ctx(), edit_id);
+            }
+            response.request_focus();
+            response.mark_changed();
         }
 
-        Ok(())
+        response
     }
+}
 
-    fn remove_dependency(blocked_issue_id: &str, blocker_issue_id: &str, db_path: Option<&PathBuf>) -> Result<(), String> {
-        // bd dep remove <blocked> <blocker>
-        let mut cmd = Command::new("bd");
-        cmd.arg("dep")
-            .arg("remove")
-            .arg(blocked_issue_id)
-            .arg(blocker_issue_id);
-
-        // Add --db flag if db_path is provided
-        if let Some(path) = db_path {
-            // Construct path to .beads/*.db file
-            let mut db_file = path.clone();
-            db_file.push(".beads");
+// Configuration for a single monitored directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DirectoryConfig {
+    path: PathBuf,
+    visible: bool,
+    #[serde(default)]
+    display_name: String,
+    // Set when the user renamed the directory; compute_display_names keeps the name
+    #[serde(default)]
+    custom_display_name: bool,
+    // Path is a pattern such as ~/work/* whose matching subdirectories are loaded
+    #[serde(default)]
+    is_glob: bool,
+    // Expanded from a glob entry at startup; never written back to config.yaml
+    #[serde(skip)]
+    is_glob_child: bool,
+}
 
-            // Find the .db file in .beads directory
-            if let Ok(entries) = fs::read_dir(&db_file) {
-                for entry in entries.flatten() {
-                    let entry_path = entry.path();
-                    if entry_path.extension().and_then(|s| s.to_str()) == Some("db") {
-                        cmd.arg("--db").arg(&entry_path);
-                        break;
-                    }
-                }
-            }
+impl DirectoryConfig {
+    /// Name stamped on issues as source_directory: the display name, or the folder name
+    fn source_name(&self) -> String {
+        if self.display_name.is_empty() {
+            self.path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("")
+                .to_string()
+        } else {
+            self.display_name.clone()
         }
+    }
+}
 
-        let output = cmd
-            .output()
-            .map_err(|e| format!("Failed to execute bd: {}", e))?;
+// Current schema version of config.yaml; bump when fields are renamed or removed
+const CURRENT_CONFIG_VERSION: u32 = 1;
 
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
-        }
+// Application configuration persisted to ~/.config/beadui/config.yaml
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct AppConfig {
+    // Missing in files written before versioning existed, which are treated as version 0
+    #[serde(default)]
+    config_version: u32,
+    #[serde(default)]
+    directories: Vec<DirectoryConfig>,
+    // Glob entries taken out of directories by expand_directory_globs; save writes them
+    // back instead of their expanded children
+    #[serde(skip)]
+    directory_globs: Vec<DirectoryConfig>,
+    #[serde(default)]
+    sidebar_collapsed: bool,
+    #[serde(default)]
+    sidebar_position: SidebarPosition,
+    // Hide the Directory column when only one directory is visible
+    #[serde(default = "default_true")]
+    auto_hide_single_directory_column: bool,
+    // Whether the first-launch tour has been completed or skipped
+    #[serde(default)]
+    has_seen_tour: bool,
+    // Name matched against issue assignees for personal highlights
+    #[serde(default)]
+    current_user: Option<String>,
+    // "My Issues" also lists issues current_user reported but someone else is assigned
+    #[serde(default)]
+    my_issues_includes_created: bool,
+    // Row background (hex, optionally with alpha) for my in-progress issues
+    #[serde(default = "default_my_active_issue_color")]
+    my_active_issue_color: String,
+    // Templates offered in the create issue dialog
+    #[serde(default = "default_issue_templates")]
+    issue_templates: Vec<IssueTemplate>,
+    // Guards applied when changing status in the detail panel
+    #[serde(default = "default_status_transition_rules")]
+    status_transition_rules: Vec<TransitionRule>,
+    // Saved to {first directory}/.beadui/config.yaml so it can be committed with the project
+    #[serde(default)]
+    portable: bool,
+    // Create dialog validation
+    #[serde(default = "default_min_title_length")]
+    min_title_length: usize,
+    // Titles longer than this are cut with "…" in the table, unless wrap_titles is on
+    #[serde(default = "default_title_max_chars")]
+    title_max_chars: usize,
+    // Let title cells grow taller instead of truncating
+    #[serde(default)]
+    wrap_titles: bool,
+    // Accept issue types outside KNOWN_ISSUE_TYPES (e.g. from templates)
+    #[serde(default)]
+    allow_custom_types: bool,
+    // Show a system tray icon (only in builds with the "tray" feature)
+    #[serde(default)]
+    systray_enabled: bool,
+    // Issue table column widths as fractions of the table width, in column order
+    #[serde(default)]
+    column_width_fractions: Vec<f32>,
+    // Most issue details kept in the snapshot cache before evicting the least recently used.
+    // Raised to the number of listed issues when there are more of them
+    #[serde(default = "default_cache_max_entries")]
+    cache_max_entries: usize,
+    // Column to sort by (descending) after each refresh, unless the user picked a sort since
+    #[serde(default)]
+    auto_sort_on_refresh: Option<SortColumn>,
+    // (column, ascending) last picked in each view mode, applied when switching to it;
+    // seeded so the card views open sorted by priority and the matrix by impact
+    #[serde(default = "default_view_mode_sort_defaults")]
+    view_mode_sort_defaults: HashMap<String, (SortColumn, bool)>,
+    // Only list issues updated within this many days
+    #[serde(default)]
+    time_horizon_days: Option<u32>,
+    #[serde(default)]
+    theme: ThemeConfig,
+    // Unblocked open issues untouched for this many days show as "stale_open"
+    #[serde(default = "default_stale_open_days")]
+    stale_open_days: Option<u32>,
+    // Break sort ties by load order; off trades that for an unstable sort
+    #[serde(default = "default_true")]
+    sort_stable: bool,
+    // Sprint board WIP limits: per readiness column, and per assignee within a column
+    #[serde(default)]
+    wip_limits: HashMap<String, usize>,
+    #[serde(default)]
+    wip_limit_per_assignee: Option<usize>,
+    // Named sets of column filters offered in the Presets menu
+    #[serde(default)]
+    filter_presets: BTreeMap<String, HashMap<SortColumn, ColumnFilter>>,
+    // Column filters for a first run, before any have been saved, and for "Reset to
+    // Default Filters"; None falls back to the built-in "hide closed"
+    #[serde(default)]
+    default_filters: Option<HashMap<SortColumn, ColumnFilter>>,
+    // Sort and column filters as they were last left, restored at startup. A column
+    // name this version doesn't know falls back to the defaults instead of failing the load.
+    #[serde(
+        default = "default_sort_keys",
+        deserialize_with = "deserialize_or_default_sort_keys"
+    )]
+    sort_keys: Vec<(SortColumn, bool)>,
+    #[serde(default, deserialize_with = "deserialize_or_none")]
+    column_filters: Option<HashMap<SortColumn, ColumnFilter>>,
+    // Shorten assignee names in the table (filters still match the raw value)
+    #[serde(default)]
+    assignee_display: AssigneeDisplayFormat,
+    // Window inner size and outer position when last moved or resized
+    #[serde(default)]
+    window_width: Option<f32>,
+    #[serde(default)]
+    window_height: Option<f32>,
+    #[serde(default)]
+    window_x: Option<f32>,
+    #[serde(default)]
+    window_y: Option<f32>,
+}
 
-        Ok(())
-    }
+fn default_stale_open_days() -> Option<u32> {
+    Some(60)
+}
 
-    fn create_issue(
-        title: &str,
-        description: &str,
-        issue_type: &str,
-        priority: i32,
-        assignee: Option<&str>,
-        db_path: Option<&PathBuf>,
-    ) -> Result<(), String> {
-        let mut cmd = Command::new("bd");
-        cmd.arg("create").arg(title);
+fn default_cache_max_entries() -> usize {
+    500
+}
 
-        // Add description if not empty
-        if !description.is_empty() {
-            cmd.arg("-d").arg(description);
-        }
+fn default_view_mode_sort_defaults() -> HashMap<String, (SortColumn, bool)> {
+    [
+        (ViewMode::Swimlane, (SortColumn::Priority, true)),
+        (ViewMode::SprintBoard, (SortColumn::Priority, true)),
+        (ViewMode::Board, (SortColumn::Priority, true)),
+        (ViewMode::Matrix, (SortColumn::TransitiveImpact, false)),
+    ]
+    .into_iter()
+    .map(|(mode, sort)| (mode.name().to_string(), sort))
+    .collect()
+}
 
-        // Add type
-        cmd.arg("-t").arg(issue_type);
+const KNOWN_ISSUE_TYPES: [&str; 5] = ["task", "feature", "bug", "epic", "chore"];
 
-        // Add priority
-        cmd.arg("-p").arg(priority.to_string());
+fn normalize_column_widths(widths: &[f32], table_width: f32) -> Vec<f32> {
+    if table_width <= 0.0 {
+        return vec![0.0; widths.len()];
+    }
+    widths.iter().map(|width| width / table_width).collect()
+}
 
-        // Add assignee if provided
-        if let Some(assignee_val) = assignee {
-            if !assignee_val.is_empty() {
-                cmd.arg("--assignee").arg(assignee_val);
-            }
-        }
+fn denormalize_column_widths(fractions: &[f32], table_width: f32) -> Vec<f32> {
+    fractions
+        .iter()
+        .map(|fraction| fraction * table_width.max(0.0))
+        .collect()
+}
 
-        // Add --db flag if db_path is provided
-        if let Some(path) = db_path {
-            // Construct path to .beads/*.db file
-            let mut db_file = path.clone();
-            db_file.push(".beads");
+fn default_min_title_length() -> usize {
+    5
+}
 
-            // Find the .db file in .beads directory
-            if let Ok(entries) = fs::read_dir(&db_file) {
-                for entry in entries.flatten() {
-                    let entry_path = entry.path();
-                    if entry_path.extension().and_then(|s| s.to_str()) == Some("db") {
-                        cmd.arg("--db").arg(&entry_path);
-                        break;
-                    }
-                }
-            }
-        }
+fn default_title_max_chars() -> usize {
+    80
+}
 
-        let output = cmd
-            .output()
-            .map_err(|e| format!("Failed to execute bd: {}", e))?;
+/// First max_chars characters of text, with "…" appended when anything was cut
+fn truncate_with_ellipsis(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((byte_idx, _)) => format!("{}…", &text[..byte_idx]),
+        None => text.to_string(),
+    }
+}
 
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
-        }
+fn title_errors(title: &str, config: &AppConfig) -> Vec<String> {
+    let length = title.trim().chars().count();
+    if length == 0 {
+        vec!["Title is required".to_string()]
+    } else if length < config.min_title_length {
+        vec![format!(
+            "Title must be at least {} characters",
+            config.min_title_length
+        )]
+    } else {
+        Vec::new()
+    }
+}
 
-        Ok(())
+fn issue_type_errors(issue_type: &str, config: &AppConfig) -> Vec<String> {
+    if config.allow_custom_types || KNOWN_ISSUE_TYPES.contains(&issue_type) {
+        Vec::new()
+    } else {
+        vec![format!("Unknown issue type \"{}\"", issue_type)]
     }
 }
 
-#[derive(Clone, Debug, Default)]
-struct ColumnFilter {
-    // Values that are explicitly excluded
-    excluded_values: HashSet<String>,
+/// All problems that should block creating an issue
+fn validate_new_issue(title: &str, issue_type: &str, config: &AppConfig) -> Vec<String> {
+    let mut errors = title_errors(title, config);
+    errors.extend(issue_type_errors(issue_type, config));
+    errors
 }
 
-impl ColumnFilter {
-    fn new_with_excluded(excluded: Vec<String>) -> Self {
-        Self {
-            excluded_values: excluded.into_iter().collect(),
-        }
-    }
+// Optional hex overrides for theme colors; unset entries follow the egui visuals
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ThemeConfig {
+    #[serde(default)]
+    hover_bg: Option<String>,
+    #[serde(default)]
+    selected_bg: Option<String>,
+    #[serde(default)]
+    error: Option<String>,
+    #[serde(default)]
+    warning: Option<String>,
+}
 
-    fn is_filtered(&self, value: &str) -> bool {
-        self.excluded_values.contains(value)
-    }
+// Named colors used across the UI, resolved once per frame
+#[derive(Debug, Clone, Copy)]
+struct ThemeTokens {
+    hover_bg: egui::Color32,
+    selected_bg: egui::Color32,
+    error: egui::Color32,
+    warning: egui::Color32,
+    // Starting color offered when picking a personal label color
+    label_default: egui::Color32,
+    priority_avg: egui::Color32,
+    blocked: egui::Color32,
+    in_progress: egui::Color32,
+    closed: egui::Color32,
+    stale_open_bg: egui::Color32,
+    resize_handle: egui::Color32,
+}
 
-    fn toggle_exclude(&mut self, value: String) {
-        if self.excluded_values.contains(&value) {
-            self.excluded_values.remove(&value);
-        } else {
-            self.excluded_values.insert(value);
+impl ThemeTokens {
+    fn from_config(config: &ThemeConfig, visuals: &egui::Visuals) -> ThemeTokens {
+        let color = |hex: &Option<String>, fallback: egui::Color32| {
+            hex.as_deref()
+                .and_then(|hex| egui::Color32::from_hex(hex).ok())
+                .unwrap_or(fallback)
+        };
+        ThemeTokens {
+            hover_bg: color(&config.hover_bg, visuals.widgets.hovered.bg_fill),
+            selected_bg: color(&config.selected_bg, visuals.selection.bg_fill),
+            error: color(&config.error, egui::Color32::RED),
+            warning: color(&config.warning, egui::Color32::YELLOW),
+            label_default: egui::Color32::from_rgb(220, 60, 60),
+            priority_avg: egui::Color32::LIGHT_BLUE,
+            blocked: egui::Color32::from_rgb(220, 80, 80),
+            in_progress: egui::Color32::from_rgb(230, 180, 60),
+            closed: egui::Color32::GRAY,
+            stale_open_bg: egui::Color32::from_rgba_unmultiplied(120, 100, 150, 50),
+            resize_handle: egui::Color32::from_rgb(0, 200, 220),
         }
     }
+}
 
-    fn has_active_filters(&self) -> bool {
-        !self.excluded_values.is_empty()
+impl Default for ThemeTokens {
+    fn default() -> Self {
+        Self::from_config(&ThemeConfig::default(), &egui::Visuals::dark())
     }
 }
 
-struct BeadUiApp {
-    issues: Vec<Issue>,
-    selected_index: Option<usize>,
-    filter_text: String,
-    error_message: Option<String>,
-    sort_by: SortColumn,
-    sort_ascending: bool,
-    current_issue: Option<Issue>,
-    edit_modified: bool,
-    hovered_row: Option<usize>,
-    split_ratio: f32, // Ratio of list height to total height (0.0 to 1.0)
-    column_filters: HashMap<SortColumn, ColumnFilter>,
-    column_visibility: HashMap<SortColumn, bool>,
-    // Map from issue_id -> list of issue_ids that depend on it
-    dependents_map: HashMap<String, Vec<String>>,
-    // Snapshot-based cache for BdClient calls
-    snapshot_cache: SnapshotCache,
-    // Application configuration
-    config: AppConfig,
-    // Create issue dialog state
-    show_create_dialog: bool,
-    create_title: String,
-    create_description: String,
-    create_type: String,
-    create_priority: i32,
-    create_assignee: String,
-    create_directory_index: usize, // Index into config.directories for the selected directory
-    // Dependency management
-    add_blocker_text: String, // Text input for adding a new blocker
-    pending_blocker_removal: Option<(String, String, String, String)>, // (issue_id, issue_title, blocker_id, blocker_title)
+// Restriction on moving an issue from one status to another
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TransitionRule {
+    from: String,
+    to: String,
+    // Ask for a reason, which is appended to the issue notes
+    #[serde(default)]
+    requires_comment: bool,
+    #[serde(default)]
+    forbidden: bool,
 }
 
-// Struct to hold pre-computed display values for an issue
-struct IssueDisplay {
-    original_idx: usize,
-    issue: Issue,
-    readiness: String,
-    blockers_count: usize,
-    dependents_count: usize,
+fn default_status_transition_rules() -> Vec<TransitionRule> {
+    ["closed", "in_progress"]
+        .into_iter()
+        .map(|from| TransitionRule {
+            from: from.to_string(),
+            to: "open".to_string(),
+            requires_comment: true,
+            forbidden: false,
+        })
+        .collect()
 }
 
-#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
-enum SortColumn {
-    Id,
-    Directory,
-    Title,
-    Status,
-    Priority,
-    Type,
-    Assignee,
-    Blockers,
-    Dependents,
+// Preset for the create issue dialog. The description may contain
+// {{title}}, {{date}}, {{assignee}} and {{id}} placeholders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IssueTemplate {
+    name: String,
+    issue_type: String,
+    priority: i32,
+    #[serde(default)]
+    description: String,
 }
 
-impl Default for BeadUiApp {
-    fn default() -> Self {
-        // Initialize column filters with status excluding "closed" by default
-        let mut column_filters = HashMap::new();
-        column_filters.insert(
-            SortColumn::Status,
-            ColumnFilter::new_with_excluded(vec!["closed".to_string()]),
-        );
+fn default_issue_templates() -> Vec<IssueTemplate> {
+    vec![
+        IssueTemplate {
+            name: "Bug report".to_string(),
+            issue_type: "bug".to_string(),
+            priority: 1,
+            description: "{{title}}\n\nReported {{date}} by {{assignee}}.\n\nSteps to reproduce:\n1. \n\nExpected:\n\nActual:\n".to_string(),
+        },
+        IssueTemplate {
+            name: "Feature".to_string(),
+            issue_type: "feature".to_string(),
+            priority: 2,
+            description: "Goal: {{title}}\n\nAcceptance criteria:\n- \n".to_string(),
+        },
+    ]
+}
 
-        // Load config from file
-        let mut config = AppConfig::load();
+/// Placeholder values known before an issue is created ({{id}} is filled in afterwards)
+fn template_vars<'a>(
+    title: &'a str,
+    date: &'a str,
+    assignee: &'a str,
+) -> HashMap<&'static str, &'a str> {
+    HashMap::from([("title", title), ("date", date), ("assignee", assignee)])
+}
 
-        // Auto-add current working directory if not already present
-        if let Ok(cwd) = std::env::current_dir() {
-            let cwd_exists = config.directories.iter().any(|d| d.path == cwd);
+/// Replace each {{key}} placeholder with its value
+fn substitute_template(template: &str, vars: &HashMap<&str, &str>) -> String {
+    let mut result = template.to_string();
+    for (key, value) in vars {
+        result = result.replace(&format!("{{{{{}}}}}", key), value);
+    }
+    result
+}
 
-            if !cwd_exists {
-                // Add PWD to config as visible by default
-                config.directories.push(DirectoryConfig {
-                    path: cwd,
-                    visible: true,
-                    display_name: String::new(), // Will be computed later
-                });
+/// Path to ~/.config/beadui/user_labels.json (issue id -> hex color)
+fn user_labels_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|path| path.join("beadui").join("user_labels.json"))
+}
 
-                // Compute display names for all directories
-                config.compute_display_names();
+fn load_user_labels() -> HashMap<String, String> {
+    user_labels_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
 
-                // Save the updated config
-                let _ = config.save();
+fn save_user_labels(labels: &HashMap<String, String>) -> Result<(), String> {
+    let path =
+        user_labels_path().ok_or_else(|| "Could not determine config directory".to_string())?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+    let json = serde_json::to_string_pretty(labels)
+        .map_err(|e| format!("Failed to serialize user labels: {}", e))?;
+    atomic_write(&path, json.as_bytes()).map_err(|e| format!("Failed to write user labels: {}", e))
+}
+
+/// Directories matching a path pattern where * and ? wildcards may appear in any
+/// component and a leading ~ is the home directory; results are sorted
+fn expand_glob(pattern: &Path) -> Vec<PathBuf> {
+    let pattern = match (pattern.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => pattern.to_path_buf(),
+    };
+
+    let mut matches = vec![PathBuf::new()];
+    for component in pattern.components() {
+        let part = component.as_os_str().to_string_lossy();
+        if !part.contains(['*', '?']) {
+            for path in &mut matches {
+                path.push(component);
             }
+            continue;
         }
 
-        // Find the first visible directory index for default creation
-        let first_visible_idx = config
-            .directories
-            .iter()
-            .position(|d| d.visible)
-            .unwrap_or(0);
-
-        let mut app = Self {
-            issues: Vec::new(),
-            selected_index: None,
-            filter_text: String::new(),
-            error_message: None,
-            sort_by: SortColumn::Priority,
-            sort_ascending: true,
-            current_issue: None,
-            edit_modified: false,
-            hovered_row: None,
-            split_ratio: 0.5, // Start with 50/50 split
-            column_filters,
-            column_visibility: HashMap::from([
-                (SortColumn::Id, true),
-                (SortColumn::Directory, true),
-                (SortColumn::Title, true),
-                (SortColumn::Status, true),
-                (SortColumn::Priority, true),
-                (SortColumn::Type, true),
-                (SortColumn::Assignee, true),
-                (SortColumn::Blockers, true),
-                (SortColumn::Dependents, true),
-            ]),
-            dependents_map: HashMap::new(),
-            snapshot_cache: SnapshotCache::new(),
-            config,
-            show_create_dialog: false,
-            create_title: String::new(),
-            create_description: String::new(),
-            create_type: "task".to_string(),
-            create_priority: 2,
-            create_assignee: String::new(),
-            create_directory_index: first_visible_idx,
-            add_blocker_text: String::new(),
-            pending_blocker_removal: None,
+        let wildcard = regex::escape(&part)
+            .replace(r"\*", ".*")
+            .replace(r"\?", ".");
+        let Ok(re) = regex::Regex::new(&format!("^{}$", wildcard)) else {
+            return Vec::new();
         };
-        app.refresh();
-        app
+        matches = matches
+            .iter()
+            .filter_map(|parent| fs::read_dir(parent).ok())
+            .flatten()
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .filter(|entry| re.is_match(&entry.file_name().to_string_lossy()))
+            .map(|entry| entry.path())
+            .collect();
     }
+
+    matches.retain(|path| path.is_dir());
+    matches.sort();
+    matches
 }
 
-impl BeadUiApp {
-    fn new(cc: &eframe::CreationContext<'_>) -> Self {
-        // Configure fonts and styles for better system appearance
-        Self::setup_custom_fonts(cc);
-        Self::default()
+/// Write through a temp file in the same directory and rename it over path, so a crash
+/// mid-write never leaves a truncated file behind
+fn atomic_write(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let tmp_path = path.with_file_name(format!(".{}.tmp", file_name));
+
+    let mut file = fs::File::create(&tmp_path)?;
+    file.write_all(content)?;
+    file.sync_all()?;
+    drop(file);
+
+    #[cfg(windows)]
+    {
+        // Renaming onto an existing file can fail on Windows, so retry after removing it and
+        // fall back to writing in place
+        if fs::rename(&tmp_path, path).is_ok() {
+            return Ok(());
+        }
+        if fs::remove_file(path).is_ok() && fs::rename(&tmp_path, path).is_ok() {
+            return Ok(());
+        }
+        let _ = fs::remove_file(&tmp_path);
+        fs::write(path, content)
+    }
+    #[cfg(not(windows))]
+    {
+        fs::rename(&tmp_path, path)
     }
+}
 
-    fn load_system_fonts(cc: &eframe::CreationContext<'_>) {
-        let mut fonts = egui::FontDefinitions::default();
+fn default_my_active_issue_color() -> String {
+    "#90ee9050".to_string()
+}
 
-        // Try to load system UI font
-        let system_source = SystemSource::new();
+// Which side of the window the directories sidebar is docked to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+enum SidebarPosition {
+    #[default]
+    Left,
+    Right,
+}
 
-        // Try to find the system UI font based on platform
-        let ui_font_result = if cfg!(target_os = "macos") {
-            // On macOS, try system UI font (which will be San Francisco on modern macOS)
-            system_source.select_best_match(&[FamilyName::SansSerif], &Properties::new())
-        } else if cfg!(target_os = "windows") {
-            // On Windows, try Segoe UI
-            system_source
-                .select_best_match(
-                    &[FamilyName::Title("Segoe UI".to_string())],
-                    &Properties::new(),
-                )
-                .or_else(|_| {
-                    system_source.select_best_match(&[FamilyName::SansSerif], &Properties::new())
-                })
-        } else {
-            // On Linux, try common UI fonts
-            system_source
-                .select_best_match(
-                    &[FamilyName::Title("Ubuntu".to_string())],
-                    &Properties::new(),
-                )
-                .or_else(|_| {
-                    system_source.select_best_match(
-                        &[FamilyName::Title("Cantarell".to_string())],
-                        &Properties::new(),
-                    )
-                })
-                .or_else(|_| {
-                    system_source.select_best_match(&[FamilyName::SansSerif], &Properties::new())
-                })
-        };
+// How assignee names are shown in the table and detail panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+enum AssigneeDisplayFormat {
+    #[default]
+    Raw,
+    Initials,
+    FirstName,
+    TruncatedEmail,
+}
 
-        // Load the system font if found
-        if let Ok(handle) = ui_font_result {
-            if let Ok(font) = handle.load() {
-                if let Some(font_data) = font.copy_font_data() {
-                    fonts.font_data.insert(
-                        "system_ui".to_owned(),
-                        egui::FontData::from_owned(font_data.to_vec()),
-                    );
+/// Shorten a raw assignee ("alice.smith", "Alice Smith", "asmith@corp.com") for display
+fn format_assignee(raw: &str, format: AssigneeDisplayFormat) -> String {
+    let local_part = raw.split('@').next().unwrap_or(raw);
+    let formatted = match format {
+        AssigneeDisplayFormat::Raw => raw.to_string(),
+        AssigneeDisplayFormat::Initials => local_part
+            .split(|c: char| c.is_whitespace() || c == '.' || c == '_' || c == '-')
+            .filter_map(|word| word.chars().next())
+            .flat_map(char::to_uppercase)
+            .collect(),
+        AssigneeDisplayFormat::FirstName => local_part
+            .split(|c: char| c.is_whitespace() || c == '.')
+            .find(|word| !word.is_empty())
+            .unwrap_or(local_part)
+            .to_string(),
+        AssigneeDisplayFormat::TruncatedEmail => local_part.to_string(),
+    };
+    // Never shorten a name down to nothing
+    if formatted.is_empty() {
+        raw.to_string()
+    } else {
+        formatted
+    }
+}
 
-                    // Set system UI font as the first proportional font
-                    fonts
-                        .families
-                        .entry(egui::FontFamily::Proportional)
-                        .or_default()
-                        .insert(0, "system_ui".to_owned());
-                }
-            }
-        }
+fn default_true() -> bool {
+    true
+}
 
-        // Load system monospace font
-        let mono_font_result =
-            system_source.select_best_match(&[FamilyName::Monospace], &Properties::new());
+fn default_sort_keys() -> Vec<(SortColumn, bool)> {
+    vec![(SortColumn::Priority, true)]
+}
 
-        if let Ok(handle) = mono_font_result {
-            if let Ok(font) = handle.load() {
-                if let Some(font_data) = font.copy_font_data() {
-                    fonts.font_data.insert(
-                        "system_mono".to_owned(),
-                        egui::FontData::from_owned(font_data.to_vec()),
-                    );
+fn deserialize_or_default_sort_keys<'de, D>(
+    deserializer: D,
+) -> Result<Vec<(SortColumn, bool)>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = serde_yaml::Value::deserialize(deserializer)?;
+    Ok(serde_yaml::from_value(value)
+        .ok()
+        .filter(|keys: &Vec<(SortColumn, bool)>| !keys.is_empty())
+        .unwrap_or_else(default_sort_keys))
+}
 
-                    fonts
-                        .families
-                        .entry(egui::FontFamily::Monospace)
-                        .or_default()
-                        .insert(0, "system_mono".to_owned());
-                }
-            }
+fn deserialize_or_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    let value = serde_yaml::Value::deserialize(deserializer)?;
+    Ok(serde_yaml::from_value(value).ok())
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            config_version: CURRENT_CONFIG_VERSION,
+            directories: Vec::new(),
+            directory_globs: Vec::new(),
+            sidebar_collapsed: false,
+            sidebar_position: SidebarPosition::Left,
+            auto_hide_single_directory_column: true,
+            has_seen_tour: false,
+            current_user: None,
+            my_issues_includes_created: false,
+            my_active_issue_color: default_my_active_issue_color(),
+            issue_templates: default_issue_templates(),
+            status_transition_rules: default_status_transition_rules(),
+            portable: false,
+            min_title_length: default_min_title_length(),
+            title_max_chars: default_title_max_chars(),
+            wrap_titles: false,
+            allow_custom_types: false,
+            systray_enabled: false,
+            column_width_fractions: Vec::new(),
+            cache_max_entries: default_cache_max_entries(),
+            auto_sort_on_refresh: None,
+            view_mode_sort_defaults: default_view_mode_sort_defaults(),
+            time_horizon_days: None,
+            theme: ThemeConfig::default(),
+            stale_open_days: default_stale_open_days(),
+            sort_stable: true,
+            wip_limits: HashMap::new(),
+            wip_limit_per_assignee: None,
+            filter_presets: BTreeMap::new(),
+            default_filters: None,
+            sort_keys: default_sort_keys(),
+            column_filters: None,
+            assignee_display: AssigneeDisplayFormat::Raw,
+            window_width: None,
+            window_height: None,
+            window_x: None,
+            window_y: None,
         }
+    }
+}
 
-        cc.egui_ctx.set_fonts(fonts);
+impl AppConfig {
+    /// The rule governing a status change, if any
+    fn transition_rule(&self, from: &str, to: &str) -> Option<&TransitionRule> {
+        self.status_transition_rules
+            .iter()
+            .find(|rule| rule.from == from && rule.to == to)
     }
 
-    fn setup_custom_fonts(cc: &eframe::CreationContext<'_>) {
-        // Load system fonts first
-        Self::load_system_fonts(cc);
+    /// Name shown, filtered and sorted on for an issue's directory: the directory's
+    /// current display name, so a rename applies before the next refresh, falling back
+    /// to the name the issue was loaded with
+    fn directory_name<'a>(&'a self, issue: &'a Issue) -> &'a str {
+        self.directories
+            .iter()
+            .find(|d| d.path == issue.source_path)
+            .map(|d| d.display_name.as_str())
+            .filter(|name| !name.is_empty())
+            .unwrap_or(&issue.source_directory)
+    }
 
-        // Set up better font sizing that matches system UI conventions
-        let mut style = (*cc.egui_ctx.style()).clone();
+    /// The configured default column filters, or the built-in "hide closed"
+    fn default_column_filters(&self) -> HashMap<SortColumn, ColumnFilter> {
+        self.default_filters.clone().unwrap_or_else(|| {
+            HashMap::from([(
+                SortColumn::Status,
+                ColumnFilter::new_with_excluded(vec!["closed".to_string()]),
+            )])
+        })
+    }
 
-        // Configure text styles with appropriate sizes for a native look
-        // These sizes work well on macOS and other platforms
-        style.text_styles = [
-            (
-                egui::TextStyle::Small,
-                egui::FontId::new(11.0, egui::FontFamily::Proportional),
-            ),
-            (
-                egui::TextStyle::Body,
-                egui::FontId::new(13.0, egui::FontFamily::Proportional),
-            ),
-            (
-                egui::TextStyle::Button,
-                egui::FontId::new(13.0, egui::FontFamily::Proportional),
-            ),
-            (
-                egui::TextStyle::Heading,
-                egui::FontId::new(17.0, egui::FontFamily::Proportional),
-            ),
-            (
-                egui::TextStyle::Monospace,
-                egui::FontId::new(12.0, egui::FontFamily::Monospace),
-            ),
-        ]
-        .into();
+    /// Get the path to the config file: ./.beadui/config.yaml if present,
+    /// otherwise ~/.config/beadui/config.yaml
+    fn config_path() -> Option<PathBuf> {
+        if let Some(local_path) = std::env::current_dir()
+            .ok()
+            .map(|cwd| Self::portable_config_path(&cwd))
+            .filter(|path| path.exists())
+        {
+            return Some(local_path);
+        }
 
-        // Adjust spacing for a cleaner look
-        style.spacing.item_spacing = egui::vec2(8.0, 6.0);
-        style.spacing.button_padding = egui::vec2(8.0, 4.0);
-        style.spacing.window_margin = egui::Margin::same(8.0);
+        Self::global_config_path()
+    }
 
-        cc.egui_ctx.set_style(style);
+    /// ~/.config/beadui/config.yaml
+    fn global_config_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|mut path| {
+            path.push("beadui");
+            path.push("config.yaml");
+            path
+        })
     }
 
-    fn compute_dependents_map(&mut self) {
-        // Build a map of issue_id -> list of issues that depend on it
-        let mut dependents_map: HashMap<String, Vec<String>> = HashMap::new();
-
-        // We need to load full issue details to get dependencies
-        for issue in &self.issues {
-            if let Ok(full_issue) = self.snapshot_cache.get_issue(&issue.id) {
-                // For each dependency (blocker), add this issue as a dependent
-                for dep in &full_issue.dependencies {
-                    dependents_map
-                        .entry(dep.id.clone())
-                        .or_default()
-                        .push(issue.id.clone());
-                }
-            }
+    /// Project-local config location inside a monitored directory
+    fn portable_config_path(directory: &Path) -> PathBuf {
+        directory.join(".beadui").join("config.yaml")
+    }
+
+    /// Where save() writes, and where load() ends up reading. A portable config lives in
+    /// ./.beadui/ when started from a directory that has one, otherwise in the first
+    /// directory's .beadui/
+    fn save_path(&self) -> Option<PathBuf> {
+        if !self.portable {
+            return Self::config_path();
         }
+        std::env::current_dir()
+            .ok()
+            .map(|cwd| Self::portable_config_path(&cwd))
+            .filter(|path| path.exists())
+            .or_else(|| {
+                self.directories
+                    .first()
+                    .map(|first| Self::portable_config_path(&first.path))
+            })
+    }
 
-        self.dependents_map = dependents_map;
+    /// Load config from config_path(), following a portable config to its save_path()
+    /// when started from outside that directory
+    fn load() -> Self {
+        let Some(config_path) = Self::config_path() else {
+            return Self::default();
+        };
+        let config = Self::load_from(&config_path);
+        match config.save_path() {
+            Some(portable_path)
+                if config.portable && portable_path != config_path && portable_path.exists() =>
+            {
+                Self::load_from(&portable_path)
+            }
+            _ => config,
+        }
     }
 
-    fn refresh(&mut self) {
-        // Clear the snapshot cache on refresh
-        self.snapshot_cache.clear();
+    /// Returns default config if file doesn't exist or is corrupt
+    fn load_from(config_path: &Path) -> Self {
+        // If file doesn't exist, return default
+        if !config_path.exists() {
+            return Self::default();
+        }
 
-        // Load issues from all visible directories
-        self.issues = BdClient::list_issues_from_all(&self.config.directories);
+        // Try to read and parse the file
+        let contents = match fs::read_to_string(config_path) {
+            Ok(contents) => contents,
+            Err(_) => return Self::default(),
+        };
 
-        // Register all issue sources in the cache
-        for dir_config in &self.config.directories {
-            if dir_config.visible {
-                for issue in &self.issues {
-                    if issue.source_directory == dir_config.display_name
-                        || (dir_config.display_name.is_empty()
-                            && issue.source_directory
-                                == dir_config
-                                    .path
-                                    .file_name()
-                                    .and_then(|n| n.to_str())
-                                    .unwrap_or(""))
-                    {
-                        self.snapshot_cache.register_issue_source(
-                            &issue.id,
-                            &issue.source_directory,
-                            Some(dir_config.path.clone()),
-                        );
-                    }
+        // Don't migrate (and overwrite) a file we can't even parse
+        let Ok(raw) = serde_yaml::from_str::<serde_yaml::Value>(&contents) else {
+            return Self::default();
+        };
+        let version = raw
+            .get("config_version")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0) as u32;
+
+        if version < CURRENT_CONFIG_VERSION {
+            // Only rewrite the file once the upgraded config loads cleanly, and rewrite
+            // this one rather than wherever the config would save to
+            match Self::migrate(raw, version) {
+                Ok(config) => {
+                    let _ = config.save_to(config_path);
+                    config
+                }
+                Err(e) => {
+                    Self::log_migration(&format!("v{} not migrated: {}", version, e));
+                    Self::default()
                 }
             }
+        } else {
+            serde_yaml::from_str::<AppConfig>(&contents).unwrap_or_default()
         }
-
-        self.compute_dependents_map();
-        self.error_message = None;
     }
 
-    fn get_blockers_count(&mut self, issue_id: &str) -> usize {
-        // Get full issue to count active blockers (dependencies that are not closed)
-        if let Ok(full_issue) = self.snapshot_cache.get_issue(issue_id) {
-            full_issue
-                .dependencies
-                .iter()
-                .filter(|dep| dep.status != "closed")
-                .count()
-        } else {
-            0
+    /// Upgrade a config written by an older version of beadui, one version at a time
+    fn migrate(mut value: serde_yaml::Value, from_version: u32) -> Result<AppConfig, String> {
+        if from_version < 1 {
+            // v0 -> v1: config_version was introduced; no fields were renamed
+            Self::log_migration("v0 -> v1: added config_version");
         }
-    }
 
-    fn get_dependents_count(&self, issue_id: &str) -> usize {
-        self.dependents_map
-            .get(issue_id)
-            .map(|v| v.len())
-            .unwrap_or(0)
+        if let serde_yaml::Value::Mapping(map) = &mut value {
+            map.insert(
+                serde_yaml::Value::from("config_version"),
+                serde_yaml::Value::from(CURRENT_CONFIG_VERSION),
+            );
+        }
+
+        serde_yaml::from_value(value).map_err(|e| e.to_string())
     }
 
-    fn get_readiness(&mut self, issue: &Issue) -> String {
-        // Compute readiness based on status and blockers
-        match issue.status.as_str() {
-            "closed" => "closed".to_string(),
-            "in_progress" => "in_progress".to_string(),
-            _ => {
-                // For open issues, check if they're blocked
-                let blockers_count = self.get_blockers_count(&issue.id);
-                if blockers_count > 0 {
-                    "blocked".to_string()
-                } else {
-                    "ready".to_string()
-                }
-            }
+    /// Append a line to ~/.config/beadui/migration.log
+    fn log_migration(message: &str) {
+        let Some(log_path) = Self::config_path().map(|path| path.with_file_name("migration.log"))
+        else {
+            return;
+        };
+        if let Some(parent) = log_path.parent() {
+            let _ = fs::create_dir_all(parent);
         }
-    }
-
-    fn get_column_value(&mut self, issue: &Issue, column: SortColumn) -> String {
-        match column {
-            SortColumn::Id => issue.id.clone(),
-            SortColumn::Directory => issue.source_directory.clone(),
-            SortColumn::Title => issue.title.clone(),
-            SortColumn::Status => self.get_readiness(issue),
-            SortColumn::Priority => format!("P{}", issue.priority),
-            SortColumn::Type => issue.issue_type.clone(),
-            SortColumn::Assignee => issue.assignee.clone().unwrap_or_else(|| "-".to_string()),
-            SortColumn::Blockers => self.get_blockers_count(&issue.id).to_string(),
-            SortColumn::Dependents => self.get_dependents_count(&issue.id).to_string(),
+        if let Ok(mut file) = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&log_path)
+        {
+            let _ = writeln!(file, "{} {}", chrono::Local::now().to_rfc3339(), message);
         }
     }
 
-    fn get_column_cardinality(&mut self, column: SortColumn) -> usize {
-        let mut unique_values = HashSet::new();
-        for issue in &self.issues.clone() {
-            unique_values.insert(self.get_column_value(issue, column));
-        }
-        unique_values.len()
+    /// Save config to ~/.config/beadui/config.yaml (or the portable location)
+    /// Creates directory if it doesn't exist
+    fn save(&self) -> Result<(), String> {
+        let config_path = self
+            .save_path()
+            .ok_or_else(|| "Could not determine config directory".to_string())?;
+        self.save_to(&config_path)
     }
 
-    fn filtered_and_sorted_issues(&mut self) -> Vec<IssueDisplay> {
-        let filter = self.filter_text.to_lowercase();
+    fn save_to(&self, config_path: &Path) -> Result<(), String> {
+        // Create parent directory if it doesn't exist
+        if let Some(parent) = config_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+        }
 
-        // Clone issues before iterating to avoid borrow checker issues
-        let issues_clone = self.issues.clone();
+        // Write the glob patterns rather than whatever they matched this session
+        let mut saved = self.clone();
+        saved.directories.retain(|d| !d.is_glob_child);
+        saved
+            .directories
+            .extend(self.directory_globs.iter().cloned());
 
-        // Pre-compute values that require cache access and clone issues
-        let mut filtered: Vec<IssueDisplay> = issues_clone
-            .iter()
-            .enumerate()
-            .filter_map(|(idx, issue)| {
-                // Pre-compute values needed for filtering and sorting
-                let readiness = self.get_readiness(issue);
-                let blockers_count = self.get_blockers_count(&issue.id);
-                let dependents_count = self.get_dependents_count(&issue.id);
+        // Serialize to YAML
+        let yaml = serde_yaml::to_string(&saved)
+            .map_err(|e| format!("Failed to serialize config: {}", e))?;
 
-                // Apply text search filter - search through all visible fields including computed ones
-                if !filter.is_empty() {
-                    let text_match = issue.id.to_lowercase().contains(&filter)
-                        || issue.title.to_lowercase().contains(&filter)
-                        || issue.description.to_lowercase().contains(&filter)
-                        || issue.status.to_lowercase().contains(&filter)
-                        || issue.issue_type.to_lowercase().contains(&filter)
-                        || issue
-                            .assignee
-                            .as_ref()
-                            .map(|a| a.to_lowercase().contains(&filter))
-                            .unwrap_or(false)
-                        || readiness.to_lowercase().contains(&filter)
-                        || blockers_count.to_string().contains(&filter)
-                        || dependents_count.to_string().contains(&filter);
-                    if !text_match {
-                        return None;
-                    }
-                }
+        // Write to file
+        atomic_write(config_path, yaml.as_bytes())
+            .map_err(|e| format!("Failed to write config file: {}", e))?;
 
-                // Apply column filters
-                for (column, column_filter) in &self.column_filters {
-                    let value = match column {
-                        SortColumn::Id => issue.id.clone(),
-                        SortColumn::Directory => issue.source_directory.clone(),
-                        SortColumn::Title => issue.title.clone(),
-                        SortColumn::Status => readiness.clone(),
-                        SortColumn::Priority => format!("P{}", issue.priority),
-                        SortColumn::Type => issue.issue_type.clone(),
-                        SortColumn::Assignee => {
-                            issue.assignee.clone().unwrap_or_else(|| "-".to_string())
-                        }
-                        SortColumn::Blockers => blockers_count.to_string(),
-                        SortColumn::Dependents => dependents_count.to_string(),
-                    };
-                    if column_filter.is_filtered(&value) {
-                        return None;
-                    }
-                }
+        Ok(())
+    }
 
-                Some(IssueDisplay {
-                    original_idx: idx,
-                    issue: issue.clone(),
-                    readiness,
-                    blockers_count,
-                    dependents_count,
-                })
-            })
-            .collect();
+    fn my_active_issue_fill(&self) -> egui::Color32 {
+        egui::Color32::from_hex(&self.my_active_issue_color)
+            .or_else(|_| egui::Color32::from_hex(&default_my_active_issue_color()))
+            .unwrap_or(egui::Color32::LIGHT_GREEN)
+    }
 
-        filtered.sort_by(|a, b| {
-            let cmp = match self.sort_by {
-                SortColumn::Id => a.issue.id.cmp(&b.issue.id),
-                SortColumn::Directory => a.issue.source_directory.cmp(&b.issue.source_directory),
-                SortColumn::Title => a.issue.title.cmp(&b.issue.title),
-                SortColumn::Status => a.readiness.cmp(&b.readiness),
-                SortColumn::Priority => a.issue.priority.cmp(&b.issue.priority),
-                SortColumn::Type => a.issue.issue_type.cmp(&b.issue.issue_type),
-                SortColumn::Assignee => a
-                    .issue
-                    .assignee
-                    .as_ref()
-                    .unwrap_or(&String::new())
-                    .cmp(b.issue.assignee.as_ref().unwrap_or(&String::new())),
-                SortColumn::Blockers => a.blockers_count.cmp(&b.blockers_count),
-                SortColumn::Dependents => a.dependents_count.cmp(&b.dependents_count),
-            };
-            if self.sort_ascending {
-                cmp
-            } else {
-                cmp.reverse()
+    /// Abbreviate path by replacing home directory with ~
+    fn abbreviate_path(path: impl AsRef<Path>) -> String {
+        let path = path.as_ref();
+        if let Some(home_dir) = dirs::home_dir() {
+            if let Ok(suffix) = path.strip_prefix(&home_dir) {
+                return format!("~/{}", suffix.display());
             }
-        });
-
-        filtered
+        }
+        path.display().to_string()
     }
 
-    fn show_sidebar(&mut self, ctx: &egui::Context) {
-        let mut config_changed = false;
-        let mut add_directory_clicked = false;
+    /// Replace glob entries with one transient entry per matching subdirectory
+    fn expand_directory_globs(&mut self) {
+        let (globs, directories): (Vec<_>, Vec<_>) = std::mem::take(&mut self.directories)
+            .into_iter()
+            .partition(|d| d.is_glob);
+        self.directories = directories;
+
+        for glob in &globs {
+            for path in expand_glob(&glob.path) {
+                if self.directories.iter().any(|d| d.path == path) {
+                    continue;
+                }
+                self.directories.push(DirectoryConfig {
+                    path,
+                    visible: glob.visible,
+                    display_name: String::new(),
+                    custom_display_name: false,
+                    is_glob: false,
+                    is_glob_child: true,
+                });
+            }
+        }
+        self.directory_globs = globs;
+        self.compute_display_names();
+    }
 
-        egui::SidePanel::left("directories_sidebar")
-            .resizable(true)
-            .default_width(200.0)
-            .show_animated(ctx, !self.config.sidebar_collapsed, |ui| {
-                ui.heading("Directories");
-                ui.separator();
+    /// Compute display names for all directories
+    /// Shows just the base name for unique names, or "base (~/path)" for duplicates
+    fn compute_display_names(&mut self) {
+        // Group directories by their base name
+        let mut base_name_groups: HashMap<String, Vec<usize>> = HashMap::new();
 
-                // Show list of directories with checkboxes
-                for dir in &mut self.config.directories {
-                    let mut visible = dir.visible;
-                    if ui.checkbox(&mut visible, &dir.display_name).changed() {
-                        dir.visible = visible;
-                        config_changed = true;
-                    }
-                }
+        for (idx, dir) in self.directories.iter().enumerate() {
+            if dir.custom_display_name {
+                continue;
+            }
+            let base_name = dir
+                .path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("")
+                .to_string();
 
-                ui.separator();
+            base_name_groups.entry(base_name).or_default().push(idx);
+        }
 
-                // Add directory button
-                if ui.button("+ Add Directory").clicked() {
-                    add_directory_clicked = true;
+        // Set display names based on uniqueness
+        for (base_name, indices) in base_name_groups {
+            if indices.len() == 1 {
+                // Unique name - just show base name
+                let idx = indices[0];
+                self.directories[idx].display_name = base_name;
+            } else {
+                // Duplicate names - show base name with abbreviated path
+                for idx in indices {
+                    let abbreviated = Self::abbreviate_path(&self.directories[idx].path);
+                    self.directories[idx].display_name = format!("{} ({})", base_name, abbreviated);
                 }
+            }
+        }
+    }
+}
 
-                ui.separator();
-            });
-
-        // Handle add directory button click
-        if add_directory_clicked {
-            if let Some(folder) = rfd::FileDialog::new().pick_folder() {
-                // Validate that the directory contains .beads/ subdirectory
-                let mut beads_path = folder.clone();
-                beads_path.push(".beads");
+// Snapshot-based cache for BdClient results
+#[derive(Clone)]
+struct SnapshotCache {
+    get_issue_cache: HashMap<String, Issue>,
+    // Map from issue_id -> (source_directory, db_path)
+    issue_sources: HashMap<String, (String, Option<PathBuf>)>,
+    max_entries: usize,
+    // When each cached id was last used, as a tick of use_clock, and the same ticks in
+    // order so the least recently used id is always the first entry of use_order
+    last_used: HashMap<String, u64>,
+    use_order: BTreeMap<u64, String>,
+    use_clock: u64,
+    hits: usize,
+    misses: usize,
+    evictions: usize,
+}
 
-                if beads_path.exists() && beads_path.is_dir() {
-                    // Check if this directory is not already in the config
-                    let already_exists = self.config.directories.iter().any(|d| d.path == folder);
+impl SnapshotCache {
+    fn new(max_entries: usize) -> Self {
+        Self {
+            get_issue_cache: HashMap::new(),
+            issue_sources: HashMap::new(),
+            max_entries,
+            last_used: HashMap::new(),
+            use_order: BTreeMap::new(),
+            use_clock: 0,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
 
-                    if !already_exists {
-                        // Add the directory to config
-                        self.config.directories.push(DirectoryConfig {
-                            path: folder,
-                            visible: true,
-                            display_name: String::new(), // Will be computed
-                        });
+    fn clear(&mut self) {
+        self.get_issue_cache.clear();
+        self.issue_sources.clear();
+        self.last_used.clear();
+        self.use_order.clear();
+    }
 
-                        // Compute display names
-                        self.config.compute_display_names();
+    /// Entry limit: the configured maximum, but never fewer than the listed issues. The
+    /// table reads every listed issue each frame, and saves diff against the cached
+    /// copy, so evicting one of them would only trigger another bd show
+    fn capacity(&self) -> usize {
+        self.max_entries.max(self.issue_sources.len()).max(1)
+    }
 
-                        config_changed = true;
-                    } else {
-                        self.error_message = Some("Directory already added".to_string());
-                    }
-                } else {
-                    self.error_message = Some(
-                        "Selected directory does not contain a .beads/ subdirectory".to_string(),
-                    );
-                }
+    /// Cache an issue as most recently used, evicting the least recently used past the limit
+    fn insert_issue(&mut self, id: String, issue: Issue) {
+        if !self.get_issue_cache.contains_key(&id) {
+            while self.get_issue_cache.len() >= self.capacity() {
+                let Some((_, oldest)) = self.use_order.pop_first() else {
+                    break;
+                };
+                self.last_used.remove(&oldest);
+                self.get_issue_cache.remove(&oldest);
+                self.evictions += 1;
             }
         }
+        self.get_issue_cache.insert(id.clone(), issue);
+        self.touch(id);
+    }
 
-        // Save config if anything changed
-        if config_changed {
-            let _ = self.config.save();
-            // Refresh to reload issues with new visibility settings
-            self.refresh();
+    /// Mark an id as the most recently used
+    fn touch(&mut self, id: String) {
+        self.use_clock += 1;
+        if let Some(previous) = self.last_used.insert(id.clone(), self.use_clock) {
+            self.use_order.remove(&previous);
         }
+        self.use_order.insert(self.use_clock, id);
     }
 
-    fn show_list_view(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Show sidebar first (so it's on the left)
-        self.show_sidebar(ctx);
+    /// Drop one issue so the next get_issue refetches it
+    fn invalidate(&mut self, id: &str) {
+        self.get_issue_cache.remove(id);
+        if let Some(used) = self.last_used.remove(id) {
+            self.use_order.remove(&used);
+        }
+    }
 
-        // Header panel
-        egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
-            // Add extra vertical spacing for better visual padding
-            ui.add_space(2.0);
+    fn register_issue_source(
+        &mut self,
+        issue_id: &str,
+        source_directory: &str,
+        db_path: Option<PathBuf>,
+    ) {
+        self.issue_sources.insert(
+            issue_id.to_string(),
+            (source_directory.to_string(), db_path),
+        );
+    }
 
-            ui.horizontal(|ui| {
-                // Sidebar toggle button
-                let sidebar_button_text = if self.config.sidebar_collapsed {
-                    "▶"
-                } else {
-                    "◀"
-                };
-                if ui.button(sidebar_button_text).clicked() {
-                    self.config.sidebar_collapsed = !self.config.sidebar_collapsed;
-                    let _ = self.config.save();
-                    self.refresh();
-                }
+    fn get_issue(&mut self, id: &str) -> Result<Issue, String> {
+        self.get_issue_ref(id).cloned()
+    }
 
-                if ui.button("Refresh").clicked() {
-                    self.refresh();
-                }
-                ui.separator();
-                if ui.button("+ Create Issue").clicked() {
-                    self.show_create_dialog = true;
-                }
+    /// The cached issue, without fetching it on a miss or counting the lookup as a use
+    fn cached_issue(&self, id: &str) -> Option<&Issue> {
+        self.get_issue_cache.get(id)
+    }
 
-                // Add filter on the right side of the same line
-                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    ui.text_edit_singleline(&mut self.filter_text);
-                    ui.label("Filter:");
-
-                    // Columns visibility menu
-                    ui.menu_button("Columns", |ui| {
-                        let mut toggle_column = None;
-
-                        for (column, name) in [
-                            (SortColumn::Id, "ID"),
-                            (SortColumn::Directory, "Directory"),
-                            (SortColumn::Title, "Title"),
-                            (SortColumn::Status, "Status"),
-                            (SortColumn::Priority, "Priority"),
-                            (SortColumn::Type, "Type"),
-                            (SortColumn::Assignee, "Assignee"),
-                            (SortColumn::Blockers, "Blockers"),
-                            (SortColumn::Dependents, "Dependents"),
-                        ] {
-                            let is_visible = self.column_visibility.get(&column).copied().unwrap_or(true);
-                            let mut visible = is_visible;
-
-                            if ui.checkbox(&mut visible, name).changed() {
-                                toggle_column = Some(column);
-                            }
-                        }
-
-                        // Apply toggle after iteration
-                        if let Some(col) = toggle_column {
-                            let current = self.column_visibility.get(&col).copied().unwrap_or(true);
-                            // Don't allow hiding the last visible column
-                            let visible_count = self.column_visibility.values().filter(|&&v| v).count();
-                            if current && visible_count > 1 {
-                                self.column_visibility.insert(col, false);
-                            } else if !current {
-                                self.column_visibility.insert(col, true);
-                            }
-                        }
-                    });
-                });
-            });
-
-            if let Some(ref error) = self.error_message {
-                ui.colored_label(egui::Color32::RED, error);
+    /// Like get_issue, but borrows the cached issue instead of cloning it.
+    /// Prefer this in per-issue loops, where cloning descriptions dominates.
+    fn get_issue_ref(&mut self, id: &str) -> Result<&Issue, String> {
+        if let Some(used) = self.last_used.get_mut(id) {
+            self.hits += 1;
+            self.use_clock += 1;
+            // Move the id to the newest tick, reusing its key rather than allocating one
+            if let Some(key) = self.use_order.remove(used) {
+                self.use_order.insert(self.use_clock, key);
             }
+            *used = self.use_clock;
+        } else {
+            // Cache miss - fetch from CLI using the registered source
+            self.misses += 1;
+            let db_path = self
+                .issue_sources
+                .get(id)
+                .and_then(|(_, path)| path.clone());
+            let issue = BdClient::get_issue_uncached(id, db_path.as_ref())?;
+
+            // Store in cache
+            self.insert_issue(id.to_string(), issue);
+        }
 
-            // Add extra vertical spacing at bottom for symmetry
-            ui.add_space(2.0);
-        });
-
-        let mut new_sort_by = None;
-        let mut new_selected = None;
-        let mut new_hovered_row = None;
-        let mut filter_toggle: Option<(SortColumn, String)> = None;
-        let mut hide_column_request: Option<SortColumn> = None;
+        Ok(&self.get_issue_cache[id])
+    }
+}
 
-        // Use CentralPanel for the resizable split view
-        egui::CentralPanel::default().show(ctx, |ui| {
-            let available_height = ui.available_height();
+// A queued bd invocation: CLI arguments, working directory and where to send its stdout
+struct BdJob {
+    args: Vec<String>,
+    cwd: Option<PathBuf>,
+    reply: mpsc::Sender<Result<String, String>>,
+}
 
-            // Only show split if an issue is selected
-            if self.selected_index.is_some() {
-                // Calculate list height based on split ratio (min 150px, max available - 150px)
-                let min_panel_height = 150.0;
-                let list_height = (available_height * self.split_ratio)
-                    .max(min_panel_height)
-                    .min(available_height - min_panel_height);
+// Long-running `bd server --stdio` process, for backends that have one. Each request is
+// one JSON line `{"args": [...], "cwd": ...}` and each reply one JSON line
+// `{"ok", "stdout", "stderr"}`.
+struct BdServer {
+    child: Child,
+    stdin: ChildStdin,
+    // Reply lines, read on their own thread so a silent server can be timed out
+    lines: mpsc::Receiver<String>,
+}
 
-                // Separator/divider (draggable)
-                let separator_height = 12.0;
+// How long a new server has to answer `--version` before one-shot calls are used instead
+const BD_SERVER_HANDSHAKE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+// How long a job may wait for its reply before the server is abandoned
+const BD_SERVER_REPLY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
 
-                // List panel - clip it to end before the separator
-                let list_rect = egui::Rect::from_min_size(
-                    ui.cursor().min,
-                    egui::vec2(ui.available_width(), list_height),
-                );
-                // Create a tighter clip rect that stops before the separator
-                let list_clip_rect = egui::Rect::from_min_size(
-                    ui.cursor().min,
-                    egui::vec2(ui.available_width(), list_height - separator_height),
-                );
-                let mut list_ui = ui.new_child(
-                    egui::UiBuilder::new()
-                        .max_rect(list_rect)
-                        .layout(egui::Layout::top_down(egui::Align::LEFT)),
-                );
-                list_ui.set_clip_rect(list_clip_rect);
+#[derive(Deserialize)]
+struct BdServerReply {
+    ok: bool,
+    #[serde(default)]
+    stdout: String,
+    #[serde(default)]
+    stderr: String,
+}
 
-                self.show_list_table(
-                    &mut list_ui,
-                    &mut new_sort_by,
-                    &mut new_selected,
-                    &mut new_hovered_row,
-                    &mut filter_toggle,
-                            &mut hide_column_request,
-                    Some(list_height - separator_height),
-                );
-                let separator_rect = egui::Rect::from_min_size(
-                    egui::pos2(list_rect.min.x, list_rect.max.y),
-                    egui::vec2(ui.available_width(), separator_height),
-                );
+impl BdServer {
+    fn spawn(program: &str) -> Option<Self> {
+        let mut child = Command::new(program)
+            .arg("server")
+            .arg("--stdio")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .ok()?;
+        let stdin = child.stdin.take()?;
+        let stdout = BufReader::new(child.stdout.take()?);
+        let (line_sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in stdout.lines().map_while(Result::ok) {
+                if line_sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
 
-                let separator_id = ui.id().with("split_separator");
-                let separator_response =
-                    ui.interact(separator_rect, separator_id, egui::Sense::drag());
+        let mut server = Self {
+            child,
+            stdin,
+            lines,
+        };
+        // Anything but a well-formed reply means the backend has no server mode
+        let version = ["--version".to_string()];
+        let handshake = server.call(&version, None, BD_SERVER_HANDSHAKE_TIMEOUT);
+        matches!(handshake, Ok(Ok(_))).then_some(server)
+    }
 
-                // Draw separator with vertical padding
-                let separator_color =
-                    if separator_response.hovered() || separator_response.dragged() {
-                        ui.visuals().widgets.active.bg_fill
-                    } else {
-                        ui.visuals().widgets.inactive.bg_fill
-                    };
-                let top_padding = 2.0;
-                let _bottom_padding = 6.0;
-                let visual_height = 3.0; // Thin visible line
+    /// Ok(reply) if the server answered in time; Err(()) if it exited, went quiet or
+    /// sent something unreadable
+    fn call(
+        &mut self,
+        args: &[String],
+        cwd: Option<&PathBuf>,
+        timeout: std::time::Duration,
+    ) -> Result<Result<String, String>, ()> {
+        let request = serde_json::json!({ "args": args, "cwd": cwd });
+        writeln!(self.stdin, "{}", request).map_err(|_| ())?;
+        self.stdin.flush().map_err(|_| ())?;
+
+        let line = self.lines.recv_timeout(timeout).map_err(|_| ())?;
+        let reply: BdServerReply = serde_json::from_str(&line).map_err(|_| ())?;
+        Ok(if reply.ok {
+            Ok(reply.stdout)
+        } else {
+            Err(reply.stderr)
+        })
+    }
+}
 
-                let visual_rect = egui::Rect::from_min_size(
-                    egui::pos2(separator_rect.min.x, separator_rect.min.y + top_padding),
-                    egui::vec2(separator_rect.width(), visual_height),
-                );
-                ui.painter().rect_filled(visual_rect, 0.0, separator_color);
+impl Drop for BdServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
 
-                // Change cursor on hover
-                if separator_response.hovered() {
-                    ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeVertical);
-                }
+struct BdWorker {
+    handle: Option<thread::JoinHandle<()>>,
+}
 
-                // Handle dragging
-                if separator_response.dragged() {
-                    if let Some(pointer_pos) = ui.ctx().pointer_latest_pos() {
-                        let new_list_height = pointer_pos.y - list_rect.min.y;
-                        self.split_ratio = (new_list_height / available_height)
-                            .max(min_panel_height / available_height)
-                            .min((available_height - min_panel_height) / available_height);
+impl BdWorker {
+    fn spawn(
+        program: String,
+        queue: Arc<Mutex<VecDeque<BdJob>>>,
+        available: Arc<Condvar>,
+        shutdown: Arc<AtomicBool>,
+    ) -> Self {
+        let handle = thread::spawn(move || {
+            // Started on the first job; stays None once the handshake or a reply fails
+            let mut server: Option<BdServer> = None;
+            let mut server_supported = true;
+
+            loop {
+                let job = {
+                    let mut jobs = queue.lock().unwrap();
+                    loop {
+                        if let Some(job) = jobs.pop_front() {
+                            break job;
+                        }
+                        if shutdown.load(Ordering::SeqCst) {
+                            return;
+                        }
+                        jobs = available.wait(jobs).unwrap();
                     }
-                }
-
-                // Detail panel
-                let detail_rect = egui::Rect::from_min_size(
-                    egui::pos2(list_rect.min.x, separator_rect.max.y),
-                    egui::vec2(
-                        ui.available_width(),
-                        available_height - list_height - separator_height,
-                    ),
-                );
-                let mut detail_ui = ui.new_child(
-                    egui::UiBuilder::new()
-                        .max_rect(detail_rect)
-                        .layout(egui::Layout::top_down(egui::Align::LEFT)),
-                );
-                detail_ui.set_clip_rect(detail_rect);
+                };
 
-                if let Some(idx) = self.selected_index {
-                    if let Some(issue) = self.issues.get(idx) {
-                        let issue_id = issue.id.clone();
-                        self.show_detail_view_split(ctx, &mut detail_ui, &issue_id);
-                    }
+                if server_supported && server.is_none() {
+                    server = BdServer::spawn(&program);
+                    server_supported = server.is_some();
                 }
-            } else {
-                // No issue selected - show list only
-                self.show_list_table(
-                    ui,
-                    &mut new_sort_by,
-                    &mut new_selected,
-                    &mut new_hovered_row,
-                    &mut filter_toggle,
-                            &mut hide_column_request,
-                    None,
-                );
+                let served = server.as_mut().and_then(|s| {
+                    s.call(&job.args, job.cwd.as_ref(), BD_SERVER_REPLY_TIMEOUT)
+                        .ok()
+                });
+                let result = match served {
+                    Some(result) => result,
+                    None => {
+                        // Fall back to a short-lived process from now on
+                        server = None;
+                        server_supported = false;
+                        run_bd(&program, &job.args, job.cwd.as_ref())
+                    }
+                };
+                let _ = job.reply.send(result);
             }
         });
 
-        // Apply changes after borrowing ends
-        if let Some(sort_col) = new_sort_by {
-            if self.sort_by == sort_col {
-                self.sort_ascending = !self.sort_ascending;
-            } else {
-                self.sort_by = sort_col;
-                self.sort_ascending = true;
-            }
+        Self {
+            handle: Some(handle),
         }
+    }
+}
 
-        if let Some(selected) = new_selected {
-            self.selected_index = selected;
+/// Open a terminal window in path using the platform's usual terminal apps. New windows
+/// come to the front on their own.
+fn open_terminal(path: &PathBuf) -> Result<(), std::io::Error> {
+    let candidates: Vec<(String, Vec<std::ffi::OsString>)> = if cfg!(target_os = "macos") {
+        let app = match std::env::var("TERM_PROGRAM").as_deref() {
+            Ok("iTerm.app") => "iTerm",
+            _ => "Terminal",
+        };
+        vec![(
+            "open".to_string(),
+            vec!["-a".into(), app.into(), path.into()],
+        )]
+    } else if cfg!(target_os = "windows") {
+        vec![
+            ("wt.exe".to_string(), vec!["-d".into(), path.into()]),
+            (
+                "cmd.exe".to_string(),
+                vec!["/C".into(), "start".into(), "cmd.exe".into()],
+            ),
+        ]
+    } else {
+        // $TERMINAL is the common way to name a preferred terminal emulator. $TERM is not
+        // used: it names the terminal type for terminfo (e.g. "xterm-256color"), which
+        // often isn't a program that can be run
+        let mut linux: Vec<(String, Vec<std::ffi::OsString>)> = Vec::new();
+        if let Ok(terminal) = std::env::var("TERMINAL") {
+            linux.push((terminal, Vec::new()));
         }
+        linux.push((
+            "gnome-terminal".to_string(),
+            vec!["--working-directory".into(), path.into()],
+        ));
+        linux.push(("xterm".to_string(), Vec::new()));
+        linux.push((
+            "alacritty".to_string(),
+            vec!["--working-directory".into(), path.into()],
+        ));
+        linux
+    };
 
-        if let Some(hovered) = new_hovered_row {
-            self.hovered_row = hovered;
-        } else {
-            self.hovered_row = None;
+    let mut last_error = std::io::Error::new(std::io::ErrorKind::NotFound, "no terminal found");
+    for (program, args) in candidates {
+        match Command::new(&program).args(&args).current_dir(path).spawn() {
+            Ok(mut child) => {
+                // Reaped in the background so the terminal doesn't linger as a zombie
+                thread::spawn(move || child.wait());
+                return Ok(());
+            }
+            Err(e) => last_error = e,
         }
+    }
+    Err(last_error)
+}
 
-        // Apply filter toggle if requested
-        if let Some((column, value)) = filter_toggle {
-            self.column_filters
-                .entry(column)
-                .or_default()
-                .toggle_exclude(value);
-        }
+// Where the missing-bd screen sends people to install it
+const BD_INSTALL_URL: &str = "https://github.com/steveyegge/beads#installation";
 
-        // Handle column hide request
-        if let Some(column) = hide_column_request {
-            let visible_count = self.column_visibility.values().filter(|&&v| v).count();
-            // Don't allow hiding the last visible column
-            if visible_count > 1 {
-                self.column_visibility.insert(column, false);
-            }
-        }
+/// The bd executable to run; BEADUI_BD_BIN overrides it, e.g. to point at a stand-in script
+fn bd_binary() -> String {
+    std::env::var("BEADUI_BD_BIN").unwrap_or_else(|_| "bd".to_string())
+}
 
-        // Keyboard navigation (respects current sort order)
-        ctx.input(|i| {
-            let filtered = self.filtered_and_sorted_issues();
+// .beads/*.db file found for each directory, so repeated bd calls don't rescan it;
+// emptied by clear_db_file_cache on every refresh
+static DB_FILE_CACHE: std::sync::OnceLock<Mutex<HashMap<PathBuf, Option<PathBuf>>>> =
+    std::sync::OnceLock::new();
 
-            if i.key_pressed(egui::Key::ArrowDown) {
-                if let Some(current_idx) = self.selected_index {
-                    // Find current issue in filtered list
-                    if let Some(pos) = filtered.iter().position(|d| d.original_idx == current_idx) {
-                        // Move to next in filtered list
-                        if pos + 1 < filtered.len() {
-                            self.selected_index = Some(filtered[pos + 1].original_idx);
-                        }
-                    }
-                } else if !filtered.is_empty() {
-                    // Select first item in filtered list
-                    self.selected_index = Some(filtered[0].original_idx);
-                }
-            }
+/// The first .beads/*.db file in a directory, if it has one
+fn resolve_db_file(path: &Path) -> Option<PathBuf> {
+    let cache = DB_FILE_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(db_file) = cache.lock().unwrap().get(path) {
+        return db_file.clone();
+    }
 
-            if i.key_pressed(egui::Key::ArrowUp) {
-                if let Some(current_idx) = self.selected_index {
-                    // Find current issue in filtered list
-                    if let Some(pos) = filtered.iter().position(|d| d.original_idx == current_idx) {
-                        // Move to previous in filtered list
-                        if pos > 0 {
-                            self.selected_index = Some(filtered[pos - 1].original_idx);
-                        }
-                    }
-                }
-            }
-        });
+    let db_file = fs::read_dir(path.join(".beads")).ok().and_then(|entries| {
+        entries
+            .flatten()
+            .map(|entry| entry.path())
+            .find(|entry_path| entry_path.extension().and_then(|s| s.to_str()) == Some("db"))
+    });
+    cache
+        .lock()
+        .unwrap()
+        .insert(path.to_path_buf(), db_file.clone());
+    db_file
+}
+
+/// Forget resolved database files, e.g. after a database was created or moved
+fn clear_db_file_cache() {
+    if let Some(cache) = DB_FILE_CACHE.get() {
+        cache.lock().unwrap().clear();
     }
+}
 
-    fn show_list_table(
-        &mut self,
-        ui: &mut egui::Ui,
-        new_sort_by: &mut Option<SortColumn>,
-        new_selected: &mut Option<Option<usize>>,
-        new_hovered_row: &mut Option<Option<usize>>,
-        filter_toggle: &mut Option<(SortColumn, String)>,
-        mut hide_column_request: &mut Option<SortColumn>,
-        max_height: Option<f32>,
-    ) {
-        let filtered = self.filtered_and_sorted_issues();
+/// Run one short-lived bd process and return its stdout
+fn run_bd(program: &str, args: &[String], cwd: Option<&PathBuf>) -> Result<String, String> {
+    let mut cmd = Command::new(program);
+    cmd.args(args);
+    if let Some(dir) = cwd {
+        cmd.current_dir(dir);
+    }
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to execute bd: {}", e))?;
 
-        // Pre-compute cardinalities to avoid borrow checker issues in context menus
-        let id_cardinality = self.get_column_cardinality(SortColumn::Id);
-        let directory_cardinality = self.get_column_cardinality(SortColumn::Directory);
-        let title_cardinality = self.get_column_cardinality(SortColumn::Title);
-        let status_cardinality = self.get_column_cardinality(SortColumn::Status);
-        let priority_cardinality = self.get_column_cardinality(SortColumn::Priority);
-        let type_cardinality = self.get_column_cardinality(SortColumn::Type);
-        let assignee_cardinality = self.get_column_cardinality(SortColumn::Assignee);
-        let blockers_cardinality = self.get_column_cardinality(SortColumn::Blockers);
-        let dependents_cardinality = self.get_column_cardinality(SortColumn::Dependents);
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
 
-        // Wrap table in ScrollArea to ensure proper clipping at boundaries
-        let mut scroll_area = egui::ScrollArea::vertical().id_salt("list_table_scroll");
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
 
-        // Apply max_height if provided (used in split view to prevent overdraw)
-        if let Some(height) = max_height {
-            scroll_area = scroll_area.max_height(height);
+// Fixed set of worker threads for running many bd calls concurrently
+struct BdPool {
+    workers: Vec<BdWorker>,
+    queue: Arc<Mutex<VecDeque<BdJob>>>,
+    // Wakes idle workers when jobs are queued
+    available: Arc<Condvar>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl BdPool {
+    fn new(size: usize) -> Self {
+        Self::with_program(size, bd_binary())
+    }
+
+    /// A pool whose workers run program in place of bd_binary()
+    fn with_program(size: usize, program: String) -> Self {
+        let queue = Arc::new(Mutex::new(VecDeque::new()));
+        let available = Arc::new(Condvar::new());
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let workers = (0..size.max(1))
+            .map(|_| {
+                BdWorker::spawn(
+                    program.clone(),
+                    queue.clone(),
+                    available.clone(),
+                    shutdown.clone(),
+                )
+            })
+            .collect();
+
+        Self {
+            workers,
+            queue,
+            available,
+            shutdown,
         }
+    }
 
-        scroll_area.show(ui, |ui| {
-            // Calculate Title column width based on available space and visible columns
-            let mut fixed_columns_width = 0.0;
-            if *self.column_visibility.get(&SortColumn::Id).unwrap_or(&true) {
-                fixed_columns_width += 100.0;
-            }
-            if *self.column_visibility.get(&SortColumn::Directory).unwrap_or(&true) {
-                fixed_columns_width += 120.0;
-            }
-            if *self.column_visibility.get(&SortColumn::Status).unwrap_or(&true) {
-                fixed_columns_width += 100.0;
-            }
-            if *self.column_visibility.get(&SortColumn::Priority).unwrap_or(&true) {
-                fixed_columns_width += 70.0;
-            }
-            if *self.column_visibility.get(&SortColumn::Type).unwrap_or(&true) {
-                fixed_columns_width += 100.0;
-            }
-            if *self.column_visibility.get(&SortColumn::Assignee).unwrap_or(&true) {
-                fixed_columns_width += 120.0;
-            }
-            if *self.column_visibility.get(&SortColumn::Blockers).unwrap_or(&true) {
-                fixed_columns_width += 80.0;
-            }
-            if *self.column_visibility.get(&SortColumn::Dependents).unwrap_or(&true) {
-                fixed_columns_width += 80.0;
+    /// Run every (args, cwd) job and return the results in the same order
+    fn run_all(&self, jobs: Vec<(Vec<String>, Option<PathBuf>)>) -> Vec<Result<String, String>> {
+        let receivers: Vec<_> = {
+            let mut queue = self.queue.lock().unwrap();
+            jobs.into_iter()
+                .map(|(args, cwd)| {
+                    let (reply, receiver) = mpsc::channel();
+                    queue.push_back(BdJob { args, cwd, reply });
+                    receiver
+                })
+                .collect()
+        };
+        self.available.notify_all();
+
+        receivers
+            .into_iter()
+            .map(|receiver| {
+                receiver
+                    .recv()
+                    .unwrap_or_else(|_| Err("bd worker exited".to_string()))
+            })
+            .collect()
+    }
+}
+
+impl Drop for BdPool {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        self.available.notify_all();
+        for worker in &mut self.workers {
+            if let Some(handle) = worker.handle.take() {
+                let _ = handle.join();
             }
+        }
+    }
+}
 
-            const SPACING_BUFFER: f32 = 70.0; // Account for table padding, column spacing, and scrollbar
-            let available_width = ui.available_width();
-            let title_width = (available_width - fixed_columns_width - SPACING_BUFFER).max(100.0);
+// Issues from every visible directory, or from one directory reloaded on its own, with
+// the full issues fetched for the cache
+struct RefreshResult {
+    // The directory reloaded by refresh_directory; None for a full refresh
+    directory: Option<DirectoryConfig>,
+    // `bd --version` output, or why bd could not be run
+    bd_version: Result<String, String>,
+    issues: Vec<Issue>,
+    details: Vec<(String, Issue)>,
+    // (display name, error) for each directory that could not be listed
+    errors: Vec<(String, String)>,
+}
 
-            let id_width = if *self.column_visibility.get(&SortColumn::Id).unwrap_or(&true) { 100.0 } else { 0.0 };
-            let dir_width = if *self.column_visibility.get(&SortColumn::Directory).unwrap_or(&true) { 120.0 } else { 0.0 };
-            let title_vis = *self.column_visibility.get(&SortColumn::Title).unwrap_or(&true);
-            let status_width = if *self.column_visibility.get(&SortColumn::Status).unwrap_or(&true) { 100.0 } else { 0.0 };
-            let priority_width = if *self.column_visibility.get(&SortColumn::Priority).unwrap_or(&true) { 70.0 } else { 0.0 };
-            let type_width = if *self.column_visibility.get(&SortColumn::Type).unwrap_or(&true) { 100.0 } else { 0.0 };
-            let assignee_width = if *self.column_visibility.get(&SortColumn::Assignee).unwrap_or(&true) { 120.0 } else { 0.0 };
-            let blockers_width = if *self.column_visibility.get(&SortColumn::Blockers).unwrap_or(&true) { 80.0 } else { 0.0 };
-            let dependents_width = if *self.column_visibility.get(&SortColumn::Dependents).unwrap_or(&true) { 80.0 } else { 0.0 };
-
-            TableBuilder::new(ui)
-                .striped(true)
-                .resizable(true)
-                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
-                .column(Column::exact(id_width)) // ID
-                .column(Column::exact(dir_width)) // Directory
-                .column(Column::exact(if title_vis { title_width } else { 0.0 })) // Title
-                .column(Column::exact(status_width)) // Status
-                .column(Column::exact(priority_width)) // Priority
-                .column(Column::exact(type_width)) // Type
-                .column(Column::exact(assignee_width)) // Assignee
-                .column(Column::exact(blockers_width)) // Blockers
-                .column(Column::exact(dependents_width)) // Dependents
-                .header(25.0, |mut header| {
-                    header.col(|ui| {
-                        if self.sortable_header_ui(
-                            ui,
-                            "ID",
-                            SortColumn::Id,
-                            id_cardinality,
-                            filter_toggle,
-                            &mut hide_column_request,
-                        ) {
-                            *new_sort_by = Some(SortColumn::Id);
-                        }
-                    });
-                    header.col(|ui| {
-                        if self.sortable_header_ui(
-                            ui,
-                            "Directory",
-                            SortColumn::Directory,
-                            directory_cardinality,
-                            filter_toggle,
-                            &mut hide_column_request,
-                        ) {
-                            *new_sort_by = Some(SortColumn::Directory);
-                        }
-                    });
-                    header.col(|ui| {
-                        if self.sortable_header_ui(
-                            ui,
-                            "Title",
-                            SortColumn::Title,
-                            title_cardinality,
-                            filter_toggle,
-                            &mut hide_column_request,
-                        ) {
-                            *new_sort_by = Some(SortColumn::Title);
-                        }
-                    });
-                    header.col(|ui| {
-                        if self.sortable_header_ui(
-                            ui,
-                            "Status",
-                            SortColumn::Status,
-                            status_cardinality,
-                            filter_toggle,
-                            &mut hide_column_request,
-                        ) {
-                            *new_sort_by = Some(SortColumn::Status);
-                        }
-                    });
-                    header.col(|ui| {
-                        if self.sortable_header_ui(
-                            ui,
-                            "Priority",
-                            SortColumn::Priority,
-                            priority_cardinality,
-                            filter_toggle,
-                            &mut hide_column_request,
-                        ) {
-                            *new_sort_by = Some(SortColumn::Priority);
-                        }
-                    });
-                    header.col(|ui| {
-                        if self.sortable_header_ui(
-                            ui,
-                            "Type",
-                            SortColumn::Type,
-                            type_cardinality,
-                            filter_toggle,
-                            &mut hide_column_request,
-                        ) {
-                            *new_sort_by = Some(SortColumn::Type);
-                        }
-                    });
-                    header.col(|ui| {
-                        if self.sortable_header_ui(
-                            ui,
-                            "Assignee",
-                            SortColumn::Assignee,
-                            assignee_cardinality,
-                            filter_toggle,
-                            &mut hide_column_request,
-                        ) {
-                            *new_sort_by = Some(SortColumn::Assignee);
-                        }
-                    });
-                    header.col(|ui| {
-                        if self.sortable_header_ui(
-                            ui,
-                            "Blockers",
-                            SortColumn::Blockers,
-                            blockers_cardinality,
-                            filter_toggle,
-                            &mut hide_column_request,
-                        ) {
-                            *new_sort_by = Some(SortColumn::Blockers);
-                        }
-                    });
-                    header.col(|ui| {
-                        if self.sortable_header_ui(
-                            ui,
-                            "Dependents",
-                            SortColumn::Dependents,
-                            dependents_cardinality,
-                            filter_toggle,
-                            &mut hide_column_request,
-                        ) {
-                            *new_sort_by = Some(SortColumn::Dependents);
-                        }
-                    });
-                })
-                .body(|body| {
-                    body.rows(20.0, filtered.len(), |mut row| {
-                        let row_index = row.index();
-                        if let Some(display) = filtered.get(row_index) {
-                            let original_idx = display.original_idx;
-                            let issue = &display.issue;
-                            let is_selected = self.selected_index == Some(original_idx);
-                            let is_row_hovered = self.hovered_row == Some(original_idx);
+// Fields bd show returns that bd list may leave out. A missing key would otherwise read as
+// "none", so a list entry only stands in for bd show when it has all of them.
+const SHOW_ONLY_FIELDS: &[&str] = &["dependencies", "comments"];
 
-                            row.set_selected(is_selected);
+fn is_full_issue_json(value: &serde_json::Value) -> bool {
+    SHOW_ONLY_FIELDS
+        .iter()
+        .all(|field| value.get(field).is_some())
+}
 
-                            let mut any_cell_hovered = false;
+/// Full issue for each listed issue: the list entry itself when bd list included every
+/// show-only field, otherwise fetched concurrently through the pool. Failed fetches are
+/// left out so get_issue retries and reports them.
+fn fetch_issue_details(issues: &[Issue], pool: &BdPool) -> Vec<(String, Issue)> {
+    let (listed, missing): (Vec<&Issue>, Vec<&Issue>) =
+        issues.iter().partition(|issue| issue.listed_in_full);
+    let mut details: Vec<(String, Issue)> = listed
+        .into_iter()
+        .map(|issue| (issue.id.clone(), issue.clone()))
+        .collect();
+
+    let jobs = missing
+        .iter()
+        .map(|issue| {
+            let db_path = Some(&issue.source_path).filter(|path| !path.as_os_str().is_empty());
+            (BdClient::show_args(&issue.id, db_path), db_path.cloned())
+        })
+        .collect();
 
-                            row.col(|ui| {
-                                let available_size = ui.available_size();
-                                let (id, rect) = ui.allocate_space(available_size);
-                                let response = ui.interact(rect, id, egui::Sense::click());
+    details.extend(
+        missing
+            .iter()
+            .zip(pool.run_all(jobs))
+            .filter_map(|(issue, result)| {
+                let full = result
+                    .and_then(|json| {
+                        serde_json::from_str::<Issue>(&json)
+                            .map_err(|e| format!("Failed to parse JSON: {}", e))
+                    })
+                    .ok()?;
+                Some((issue.id.clone(), full))
+            }),
+    );
+    details
+}
 
-                                if response.hovered() {
-                                    any_cell_hovered = true;
-                                }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Severity {
+    Warning,
+    // bd cannot be run at all, so the issue list would be silently empty
+    Critical,
+}
 
-                                if is_row_hovered {
-                                    ui.painter().rect_filled(
-                                        rect,
-                                        0.0,
-                                        ui.visuals().widgets.hovered.bg_fill,
-                                    );
-                                }
+#[derive(Debug, Clone)]
+struct HealthWarning {
+    severity: Severity,
+    message: String,
+}
 
-                                let mut child_ui = ui.new_child(
-                                    egui::UiBuilder::new()
-                                        .max_rect(rect)
-                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
-                                );
-                                child_ui.set_clip_rect(rect);
-                                child_ui.add(egui::Label::new(&issue.id).selectable(false));
+// Fields `bd update` accepts; "custom.*" fields are passed through as well
+const KNOWN_FIELDS: &[&str] = &[
+    "title",
+    "status",
+    "priority",
+    "type",
+    "assignee",
+    "notes",
+    "description",
+    "tags",
+    "sprint",
+    "due_date",
+];
+
+// Failures caught before bd is run
+#[derive(Debug)]
+enum BdError {
+    UnknownField(String),
+}
 
-                                if response.clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
-                                if response.double_clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
-                                // No context menu for ID column (not useful for filtering)
-                            });
+impl std::fmt::Display for BdError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BdError::UnknownField(field) => write!(f, "Unknown field '{}'", field),
+        }
+    }
+}
 
-                            // Directory column
-                            row.col(|ui| {
-                                let available_size = ui.available_size();
-                                let (id, rect) = ui.allocate_space(available_size);
-                                let response = ui.interact(rect, id, egui::Sense::click());
+/// Reject field names `bd update` doesn't know, before a process is spawned
+fn validate_update_field(field: &str) -> Result<(), BdError> {
+    if field.starts_with("custom.") || KNOWN_FIELDS.contains(&field) {
+        Ok(())
+    } else {
+        Err(BdError::UnknownField(field.to_string()))
+    }
+}
 
-                                if response.hovered() {
-                                    any_cell_hovered = true;
-                                }
+struct BdClient;
 
-                                if is_row_hovered {
-                                    ui.painter().rect_filled(
-                                        rect,
-                                        0.0,
-                                        ui.visuals().widgets.hovered.bg_fill,
-                                    );
-                                }
+impl BdClient {
+    /// A bd command for the directory at db_path: run from that directory, for backends
+    /// that locate the db from the CWD, and pointed at its .beads/*.db file
+    fn command(db_path: Option<&PathBuf>) -> Command {
+        let mut cmd = Command::new(bd_binary());
+        if let Some(path) = db_path {
+            cmd.current_dir(path);
+            if let Some(db_file) = resolve_db_file(path) {
+                cmd.arg("--db").arg(db_file);
+            }
+        }
+        cmd
+    }
 
-                                let mut child_ui = ui.new_child(
-                                    egui::UiBuilder::new()
-                                        .max_rect(rect)
-                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
-                                );
-                                child_ui.set_clip_rect(rect);
-                                child_ui.add(
-                                    egui::Label::new(&issue.source_directory).selectable(false),
-                                );
+    /// Output of `bd --version`, trimmed
+    fn version() -> Result<String, String> {
+        let output = Command::new(bd_binary())
+            .arg("--version")
+            .output()
+            .map_err(|e| format!("Failed to execute bd: {}", e))?;
 
-                                if response.clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
-                                if response.double_clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
 
-                                let directory_value = issue.source_directory.clone();
-                                response.context_menu(|ui| {
-                                    if directory_cardinality > 20 {
-                                        ui.label(format!(
-                                            "⚠ High cardinality ({} values)",
-                                            directory_cardinality
-                                        ));
-                                        ui.label("Filtering not available");
-                                    } else {
-                                        let current_filter =
-                                            self.column_filters.get(&SortColumn::Directory);
-                                        let is_filtered = current_filter
-                                            .map(|f| f.is_filtered(&directory_value))
-                                            .unwrap_or(false);
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
 
-                                        if ui
-                                            .button(if is_filtered {
-                                                format!("✓ Include \"{}\"", directory_value)
-                                            } else {
-                                                format!("✗ Exclude \"{}\"", directory_value)
-                                            })
-                                            .clicked()
-                                        {
-                                            *filter_toggle = Some((
-                                                SortColumn::Directory,
-                                                directory_value.clone(),
-                                            ));
-                                            ui.close_menu();
-                                        }
-                                    }
-                                });
-                            });
+    /// Startup checks that bd is installed and each visible directory's database is readable
+    fn check_health(directories: &[DirectoryConfig]) -> Vec<HealthWarning> {
+        let mut warnings = Vec::new();
+        let critical = |message: String| HealthWarning {
+            severity: Severity::Critical,
+            message,
+        };
 
-                            row.col(|ui| {
-                                let available_size = ui.available_size();
-                                let (id, rect) = ui.allocate_space(available_size);
-                                let response = ui.interact(rect, id, egui::Sense::click());
+        // Running bd is the only reliable check: it honors BEADUI_BD_BIN and doesn't
+        // depend on a `which` being installed
+        if let Err(e) = Self::version() {
+            warnings.push(critical(format!(
+                "{} --version failed: {}",
+                bd_binary(),
+                e.trim()
+            )));
+        }
 
-                                if response.hovered() {
-                                    any_cell_hovered = true;
-                                }
+        for dir_config in directories.iter().filter(|d| d.visible) {
+            let beads_dir = dir_config.path.join(".beads");
+            if let Err(e) = fs::read_dir(&beads_dir) {
+                warnings.push(HealthWarning {
+                    severity: Severity::Warning,
+                    message: format!("{} is not readable: {}", beads_dir.display(), e),
+                });
+            }
+        }
 
-                                if is_row_hovered {
-                                    ui.painter().rect_filled(
-                                        rect,
-                                        0.0,
-                                        ui.visuals().widgets.hovered.bg_fill,
-                                    );
-                                }
+        warnings
+    }
 
-                                let mut child_ui = ui.new_child(
-                                    egui::UiBuilder::new()
-                                        .max_rect(rect)
-                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
-                                );
-                                child_ui.set_clip_rect(rect);
-                                child_ui.add(egui::Label::new(&issue.title).selectable(false));
+    fn list_issues(
+        db_path: Option<&PathBuf>,
+        source_directory: &str,
+    ) -> Result<Vec<Issue>, String> {
+        let mut cmd = Self::command(db_path);
+        cmd.arg("list").arg("--json");
 
-                                if response.clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
-                                if response.double_clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
-                                // No context menu for Title column (not useful for filtering)
-                            });
+        let output = cmd
+            .output()
+            .map_err(|e| format!("Failed to execute bd: {}", e))?;
 
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        let json = String::from_utf8_lossy(&output.stdout);
+        let values: Vec<serde_json::Value> =
+            serde_json::from_str(&json).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+        let mut issues = Vec::with_capacity(values.len());
+        for value in values {
+            let listed_in_full = is_full_issue_json(&value);
+            let mut issue: Issue = serde_json::from_value(value)
+                .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+            issue.listed_in_full = listed_in_full;
+            issues.push(issue);
+        }
+
+        // Set source_directory on all issues
+        for issue in &mut issues {
+            issue.source_directory = source_directory.to_string();
+            if let Some(path) = db_path {
+                issue.source_path = path.clone();
+            }
+        }
+
+        Ok(issues)
+    }
+
+    /// Issues from every visible directory, listed one thread per directory and sorted by
+    /// source directory then id, plus (display name, error) for directories that failed
+    fn list_issues_from_all(
+        directories: &[DirectoryConfig],
+    ) -> (Vec<Issue>, Vec<(String, String)>) {
+        let results: Vec<(String, Result<Vec<Issue>, String>)> = thread::scope(|scope| {
+            let handles: Vec<_> = directories
+                .iter()
+                .filter(|d| d.visible)
+                .map(|dir_config| {
+                    scope.spawn(move || {
+                        let source_name = dir_config.source_name();
+                        let result = Self::list_issues(Some(&dir_config.path), &source_name);
+                        (source_name, result)
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|_| (String::new(), Err("bd list panicked".to_string())))
+                })
+                .collect()
+        });
+
+        let mut all_issues = Vec::new();
+        let mut errors = Vec::new();
+        for (source_name, result) in results {
+            match result {
+                Ok(mut issues) => all_issues.append(&mut issues),
+                Err(e) => errors.push((source_name, e)),
+            }
+        }
+
+        all_issues.sort_by(|a, b| {
+            a.source_directory
+                .cmp(&b.source_directory)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        (all_issues, errors)
+    }
+
+    /// Arguments for `bd show <id> --json`, as run by get_issue_uncached
+    fn show_args(id: &str, db_path: Option<&PathBuf>) -> Vec<String> {
+        let mut args = vec!["show".to_string(), id.to_string(), "--json".to_string()];
+
+        if let Some(db_file) = db_path.and_then(|path| resolve_db_file(path)) {
+            args.push("--db".to_string());
+            args.push(db_file.display().to_string());
+        }
+
+        args
+    }
+
+    fn get_issue_uncached(id: &str, db_path: Option<&PathBuf>) -> Result<Issue, String> {
+        let mut cmd = Self::command(db_path);
+        cmd.arg("show").arg(id).arg("--json");
+
+        let output = cmd
+            .output()
+            .map_err(|e| format!("Failed to execute bd: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        let json = String::from_utf8_lossy(&output.stdout);
+        serde_json::from_str(&json).map_err(|e| format!("Failed to parse JSON: {}", e))
+    }
+
+    fn update_issue(id: &str, field: &str, value: &str, db_path: Option<&PathBuf>) -> Result<(), String> {
+        validate_update_field(field).map_err(|e| e.to_string())?;
+
+        let mut cmd = Self::command(db_path);
+        cmd.arg("update")
+            .arg(id)
+            .arg(format!("--{}", field))
+            .arg(value);
+
+        let output = cmd
+            .output()
+            .map_err(|e| format!("Failed to execute bd: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        Ok(())
+    }
+
+    fn delete_issue(id: &str, db_path: Option<&PathBuf>) -> Result<(), String> {
+        // bd delete only previews without --force; the UI has already asked for confirmation
+        let mut cmd = Self::command(db_path);
+        cmd.arg("delete").arg(id).arg("--force");
+
+        let output = cmd
+            .output()
+            .map_err(|e| format!("Failed to execute bd: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        Ok(())
+    }
+
+    fn add_dependency(blocked_issue_id: &str, blocker_issue_id: &str, db_path: Option<&PathBuf>) -> Result<(), String> {
+        Self::add_dependency_of_type(blocked_issue_id, blocker_issue_id, "blocks", db_path)
+    }
+
+    fn add_dependency_of_type(
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &str,
+        db_path: Option<&PathBuf>,
+    ) -> Result<(), String> {
+        // bd dep add <issue> <depends-on> --type <type>
+        let mut cmd = Self::command(db_path);
+        cmd.arg("dep")
+            .arg("add")
+            .arg(issue_id)
+            .arg(depends_on_id)
+            .arg("--type")
+            .arg(dep_type);
+
+        let output = cmd
+            .output()
+            .map_err(|e| format!("Failed to execute bd: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        Ok(())
+    }
+
+    fn add_comment(issue_id: &str, text: &str, db_path: Option<&PathBuf>) -> Result<(), String> {
+        // bd comments add <issue> <text>
+        let mut cmd = Self::command(db_path);
+        cmd.arg("comments").arg("add").arg(issue_id).arg(text);
+
+        let output = cmd
+            .output()
+            .map_err(|e| format!("Failed to execute bd: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        Ok(())
+    }
+
+    fn remove_dependency(blocked_issue_id: &str, blocker_issue_id: &str, db_path: Option<&PathBuf>) -> Result<(), String> {
+        // bd dep remove <blocked> <blocker>
+        let mut cmd = Self::command(db_path);
+        cmd.arg("dep")
+            .arg("remove")
+            .arg(blocked_issue_id)
+            .arg(blocker_issue_id);
+
+        let output = cmd
+            .output()
+            .map_err(|e| format!("Failed to execute bd: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        Ok(())
+    }
+
+    fn create_issue(
+        title: &str,
+        description: &str,
+        issue_type: &str,
+        priority: i32,
+        assignee: Option<&str>,
+        tags: &[String],
+        db_path: Option<&PathBuf>,
+    ) -> Result<Option<String>, String> {
+        let mut cmd = Self::command(db_path);
+        cmd.arg("create").arg(title).arg("--json");
+
+        // Add description if not empty
+        if !description.is_empty() {
+            cmd.arg("-d").arg(description);
+        }
+
+        // Add type
+        cmd.arg("-t").arg(issue_type);
+
+        // Add priority
+        cmd.arg("-p").arg(priority.to_string());
+
+        // Add assignee if provided
+        if let Some(assignee_val) = assignee {
+            if !assignee_val.is_empty() {
+                cmd.arg("--assignee").arg(assignee_val);
+            }
+        }
+
+        // Add labels if provided
+        if !tags.is_empty() {
+            cmd.arg("--labels").arg(tags.join(","));
+        }
+
+        let output = cmd
+            .output()
+            .map_err(|e| format!("Failed to execute bd: {}", e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        }
+
+        // Return the new issue's id when bd reports it
+        let json = String::from_utf8_lossy(&output.stdout);
+        Ok(serde_json::from_str::<serde_json::Value>(&json)
+            .ok()
+            .and_then(|value| value.get("id").and_then(|id| id.as_str()).map(String::from)))
+    }
+}
+
+// Stored in config as a plain list of excluded values, or a map when it also has
+// patterns or included values
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(from = "ColumnFilterRepr", into = "ColumnFilterRepr")]
+struct ColumnFilter {
+    // Values that are explicitly excluded
+    excluded_values: HashSet<String>,
+    // When non-empty, only these values pass
+    included_values: HashSet<String>,
+    // Values matching any of these patterns are excluded
+    excluded_patterns: Vec<regex::Regex>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum ColumnFilterRepr {
+    Values(Vec<String>),
+    Detailed {
+        #[serde(default)]
+        excluded_values: Vec<String>,
+        #[serde(default)]
+        included_values: Vec<String>,
+        #[serde(default)]
+        excluded_patterns: Vec<String>,
+    },
+}
+
+impl From<ColumnFilter> for ColumnFilterRepr {
+    fn from(filter: ColumnFilter) -> Self {
+        // Sorted so saved presets diff cleanly
+        let mut excluded_values: Vec<String> = filter.excluded_values.into_iter().collect();
+        excluded_values.sort();
+        let mut included_values: Vec<String> = filter.included_values.into_iter().collect();
+        included_values.sort();
+        if filter.excluded_patterns.is_empty() && included_values.is_empty() {
+            ColumnFilterRepr::Values(excluded_values)
+        } else {
+            ColumnFilterRepr::Detailed {
+                excluded_values,
+                included_values,
+                excluded_patterns: filter
+                    .excluded_patterns
+                    .iter()
+                    .map(|re| re.as_str().to_string())
+                    .collect(),
+            }
+        }
+    }
+}
+
+impl From<ColumnFilterRepr> for ColumnFilter {
+    fn from(repr: ColumnFilterRepr) -> Self {
+        match repr {
+            ColumnFilterRepr::Values(values) => ColumnFilter::new_with_excluded(values),
+            ColumnFilterRepr::Detailed {
+                excluded_values,
+                included_values,
+                excluded_patterns,
+            } => {
+                let mut filter = ColumnFilter::new_with_excluded(excluded_values);
+                filter.included_values = included_values.into_iter().collect();
+                // A hand-edited pattern that no longer compiles is dropped
+                for pattern in excluded_patterns {
+                    if let Ok(re) = regex::Regex::new(&pattern) {
+                        filter.add_pattern(re);
+                    }
+                }
+                filter
+            }
+        }
+    }
+}
+
+// Patterns compare by their source text
+impl PartialEq for ColumnFilter {
+    fn eq(&self, other: &Self) -> bool {
+        self.excluded_values == other.excluded_values
+            && self.included_values == other.included_values
+            && self
+                .excluded_patterns
+                .iter()
+                .map(regex::Regex::as_str)
+                .eq(other.excluded_patterns.iter().map(regex::Regex::as_str))
+    }
+}
+
+impl ColumnFilter {
+    fn new_with_excluded(excluded: Vec<String>) -> Self {
+        Self {
+            excluded_values: excluded.into_iter().collect(),
+            included_values: HashSet::new(),
+            excluded_patterns: Vec::new(),
+        }
+    }
+
+    fn is_filtered(&self, value: &str) -> bool {
+        (!self.included_values.is_empty() && !self.included_values.contains(value))
+            || self.excluded_values.contains(value)
+            || self.excluded_patterns.iter().any(|re| re.is_match(value))
+    }
+
+    fn add_pattern(&mut self, pattern: regex::Regex) {
+        // Avoid stacking duplicate patterns
+        if !self
+            .excluded_patterns
+            .iter()
+            .any(|re| re.as_str() == pattern.as_str())
+        {
+            self.excluded_patterns.push(pattern);
+        }
+    }
+
+    fn remove_pattern(&mut self, pattern: &str) {
+        self.excluded_patterns.retain(|re| re.as_str() != pattern);
+    }
+
+    // A value is either excluded or included, never both, so each toggle clears the other
+    fn toggle_exclude(&mut self, value: String) {
+        if self.excluded_values.contains(&value) {
+            self.excluded_values.remove(&value);
+        } else {
+            self.included_values.remove(&value);
+            self.excluded_values.insert(value);
+        }
+    }
+
+    fn toggle_include(&mut self, value: String) {
+        if self.included_values.contains(&value) {
+            self.included_values.remove(&value);
+        } else {
+            self.excluded_values.remove(&value);
+            self.included_values.insert(value);
+        }
+    }
+
+    /// Carry an excluded or included value over to a new name, e.g. a renamed directory
+    fn rename_value(&mut self, from: &str, to: &str) {
+        for values in [&mut self.excluded_values, &mut self.included_values] {
+            if values.remove(from) {
+                values.insert(to.to_string());
+            }
+        }
+    }
+
+    fn has_active_filters(&self) -> bool {
+        !self.excluded_values.is_empty()
+            || !self.included_values.is_empty()
+            || !self.excluded_patterns.is_empty()
+    }
+}
+
+// Which main view is shown in the central panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum ViewMode {
+    #[default]
+    List,
+    // One lane per assignee
+    Swimlane,
+    // Dependency grid of visible issues
+    Matrix,
+    // Readiness columns crossed with assignee lanes
+    SprintBoard,
+    // Kanban columns by readiness
+    Board,
+}
+
+impl ViewMode {
+    // Key for per-view settings in the config file
+    fn name(self) -> &'static str {
+        match self {
+            ViewMode::List => "list",
+            ViewMode::Swimlane => "swimlane",
+            ViewMode::Matrix => "matrix",
+            ViewMode::SprintBoard => "sprint_board",
+            ViewMode::Board => "board",
+        }
+    }
+}
+
+// Card being dragged between swimlanes
+struct SwimlaneCard {
+    original_idx: usize,
+    issue_id: String,
+    title: String,
+    from_assignee: Option<String>,
+    // Pointer position relative to the card's top-left corner when grabbed
+    grab_offset: egui::Vec2,
+}
+
+const SWIMLANE_CARD_WIDTH: f32 = 180.0;
+
+/// Draw a compact issue card and return its rect
+fn draw_swimlane_card(
+    ui: &mut egui::Ui,
+    issue_id: &str,
+    title: &str,
+    selected: bool,
+) -> egui::Rect {
+    let visuals = ui.visuals();
+    let (fill, stroke) = if selected {
+        (visuals.selection.bg_fill, visuals.selection.stroke)
+    } else {
+        (
+            visuals.widgets.inactive.bg_fill,
+            visuals.widgets.noninteractive.bg_stroke,
+        )
+    };
+    egui::Frame::none()
+        .fill(fill)
+        .stroke(stroke)
+        .rounding(4.0)
+        .inner_margin(6.0)
+        .show(ui, |ui| {
+            ui.set_width(SWIMLANE_CARD_WIDTH);
+            ui.add(
+                egui::Label::new(egui::RichText::new(issue_id).small().strong()).selectable(false),
+            );
+            ui.add(egui::Label::new(title).truncate().selectable(false));
+        })
+        .response
+        .rect
+}
+
+// System tray icon and the ids of its menu entries
+#[cfg(feature = "tray")]
+struct Tray {
+    icon: tray_icon::TrayIcon,
+    show_id: tray_icon::menu::MenuId,
+    capture_id: tray_icon::menu::MenuId,
+    refresh_id: tray_icon::menu::MenuId,
+    // (menu item id, issue id) for the recent issues section
+    recent_ids: Vec<(tray_icon::menu::MenuId, String)>,
+}
+
+#[cfg(feature = "tray")]
+impl Tray {
+    fn new() -> Result<Self, String> {
+        use tray_icon::menu::MenuId;
+
+        // tray-icon drives the indicator through GTK on Linux
+        #[cfg(target_os = "linux")]
+        gtk::init().map_err(|e| format!("Failed to initialize GTK: {}", e))?;
+
+        // Plain filled circle, 32x32 RGBA
+        let size = 32u32;
+        let mut rgba = Vec::with_capacity((size * size * 4) as usize);
+        for y in 0..size {
+            for x in 0..size {
+                let dx = x as f32 - size as f32 / 2.0 + 0.5;
+                let dy = y as f32 - size as f32 / 2.0 + 0.5;
+                let alpha = if dx * dx + dy * dy <= (size as f32 / 2.0 - 1.0).powi(2) {
+                    255
+                } else {
+                    0
+                };
+                rgba.extend_from_slice(&[70, 130, 220, alpha]);
+            }
+        }
+        let image = tray_icon::Icon::from_rgba(rgba, size, size)
+            .map_err(|e| format!("Failed to create tray icon: {}", e))?;
+
+        let icon = tray_icon::TrayIconBuilder::new()
+            .with_icon(image)
+            .with_tooltip("beadui")
+            .build()
+            .map_err(|e| format!("Failed to create tray icon: {}", e))?;
+
+        Ok(Self {
+            icon,
+            show_id: MenuId::new("show"),
+            capture_id: MenuId::new("capture"),
+            refresh_id: MenuId::new("refresh"),
+            recent_ids: Vec::new(),
+        })
+    }
+
+    /// Rebuild the menu and badge. `recent` is (issue id, title), newest first.
+    fn update(&mut self, count: usize, recent: &[(String, String)]) {
+        use tray_icon::menu::{Menu, MenuItem, PredefinedMenuItem};
+
+        let menu = Menu::new();
+        let show = MenuItem::new("Show beadui", true, None);
+        let capture = MenuItem::new("Quick Capture", true, None);
+        let refresh = MenuItem::new("Refresh", true, None);
+        let _ = menu.append_items(&[&show, &capture, &refresh, &PredefinedMenuItem::separator()]);
+        self.show_id = show.id().clone();
+        self.capture_id = capture.id().clone();
+        self.refresh_id = refresh.id().clone();
+
+        self.recent_ids.clear();
+        for (issue_id, title) in recent {
+            let item = MenuItem::new(format!("{} - {}", issue_id, title), true, None);
+            let _ = menu.append(&item);
+            self.recent_ids.push((item.id().clone(), issue_id.clone()));
+        }
+
+        self.icon.set_menu(Some(Box::new(menu)));
+        self.icon.set_title(Some(count.to_string()));
+        let _ = self
+            .icon
+            .set_tooltip(Some(format!("beadui: {} unblocked in progress", count)));
+    }
+}
+
+struct BeadUiApp {
+    issues: Vec<Issue>,
+    selected_index: Option<usize>,
+    filter_text: String,
+    error_message: Option<String>,
+    // Sort columns in priority order with their direction (true = ascending); never empty.
+    // Later keys only break ties left by earlier ones
+    sort_keys: Vec<(SortColumn, bool)>,
+    // Set by header clicks so the next refresh leaves the chosen sort alone
+    user_modified_sort: bool,
+    // Sorted column header flashes until this time after a view switch re-sorts
+    resort_highlight_until: f64,
+    current_issue: Option<Issue>,
+    // current_issue as it was loaded, before any edits; saves only write what differs
+    original_issue: Option<Issue>,
+    edit_modified: bool,
+    // (issue_id, summary) from the last detail panel save, shown while that issue is open
+    save_message: Option<(String, String)>,
+    // Name typed in the Presets menu for saving the current filters
+    preset_name_input: String,
+    // Last title sent to the window, so it is only updated on change
+    window_title: String,
+    // Window geometry not yet written to config, and when it last changed
+    pending_window_geometry: Option<(egui::Vec2, egui::Pos2)>,
+    window_geometry_changed_at: f64,
+    // The restored size has been checked against the monitor
+    window_size_clamped: bool,
+    hovered_row: Option<usize>,
+    split_ratio: f32, // Ratio of list height to total height (0.0 to 1.0)
+    column_filters: HashMap<SortColumn, ColumnFilter>,
+    column_visibility: HashMap<SortColumn, bool>,
+    // Map from issue_id -> list of issue_ids that depend on it
+    dependents_map: HashMap<String, Vec<String>>,
+    // Map from issue_id -> number of issues it blocks directly or indirectly; built
+    // alongside dependents_map
+    impact_counts: HashMap<String, usize>,
+    // Set when issues reload; the map is only rebuilt once something shows dependents
+    dependents_map_dirty: bool,
+    // Snapshot-based cache for BdClient calls
+    snapshot_cache: SnapshotCache,
+    // Workers used to prefetch full issues concurrently on refresh; shared with the
+    // background refresh thread
+    bd_pool: Arc<BdPool>,
+    // Results of the refreshes running in the background: at most one full refresh,
+    // plus any single-directory refreshes started since
+    refresh_receivers: Vec<mpsc::Receiver<RefreshResult>>,
+    // A refresh is in flight; the previous issues stay on screen until it lands
+    loading: bool,
+    // Why the last folder picked with Add Directory was rejected, shown under the button
+    add_directory_error: Option<String>,
+    // Why a directory could not be listed on the last load, keyed by its source name
+    directory_errors: HashMap<String, String>,
+    // Whether `bd --version` ran on the last check, and what it printed
+    bd_available: bool,
+    bd_version: Option<String>,
+    // Personal row accent colors keyed by issue id
+    user_labels: HashMap<String, String>,
+    #[cfg(feature = "tray")]
+    tray: Option<Tray>,
+    // Application configuration
+    config: AppConfig,
+    // Create issue dialog state
+    show_create_dialog: bool,
+    // Set outside the sidebar (e.g. the empty state) to open the add directory picker
+    add_directory_requested: bool,
+    create_title: String,
+    create_description: String,
+    create_type: String,
+    create_priority: i32,
+    create_assignee: String,
+    create_tags: String,                  // Comma-separated
+    create_directory_index: usize, // Index into config.directories for the selected directory
+    create_template_index: Option<usize>, // Index into config.issue_templates
+    // Dependency management
+    add_blocker_text: String, // Text input for adding a new blocker
+    pending_blocker_removal: Option<(String, String, String, String)>, // (issue_id, issue_title, blocker_id, blocker_title)
+    // Text input for adding a regex exclusion pattern in the column filter popup
+    filter_pattern_text: String,
+    // Set by keyboard navigation so the next frame scrolls the selected row into view
+    scroll_to_selected: bool,
+    // Time (egui input time) at which an issue id was pasted into the filter box
+    pending_paste_navigation: Option<f64>,
+    // Row flashed after auto-navigation: (original_idx, start time)
+    flash_row: Option<(usize, f64)>,
+    // First-launch tour overlay
+    tour: Option<TourState>,
+    tour_anchors: TourAnchors,
+    // Open issues whose blockers are now all closed (recomputed on refresh)
+    newly_ready: Vec<String>,
+    // Confirmation dialog for "Mark All Newly Ready"
+    pending_mark_ready: Option<Vec<String>>,
+    // Selection waiting on the unsaved changes prompt
+    pending_selection: Option<Option<usize>>,
+    // Issue id awaiting confirmation in the delete dialog
+    pending_delete: Option<String>,
+    // Issues picked with Ctrl/Cmd+click for bulk updates
+    multi_selected: HashSet<String>,
+    show_bulk_dialog: bool,
+    bulk_add_tag: String,
+    bulk_remove_tag: String,
+    // Status change waiting for a reason: (issue_id, from, to)
+    pending_status_change: Option<(String, String, String)>,
+    status_change_reason: String,
+    // Totals shown under the top panel header (recomputed on refresh)
+    summary_stats: SummaryStats,
+    cycle_time_stats: CycleTimeStats,
+    burn_estimate: Option<BurnEstimate>,
+    // Count of non-closed issues per assignee (recomputed on refresh)
+    assignee_workloads: HashMap<String, usize>,
+    // Tags in use with their counts, most used first (recomputed on refresh)
+    all_tags: Vec<(String, usize)>,
+    // Comma-separated tag text for the issue open in the detail panel
+    edit_tags_text: String,
+    // Key typed into the "Add Custom Field" input
+    new_custom_field_key: String,
+    // Detail description shows rendered Markdown rather than the raw text editor
+    description_preview: bool,
+    // Issue the preview toggle was last set for; opening another issue resets it
+    description_preview_id: Option<String>,
+    // Parsed state egui_commonmark keeps between frames for the description preview
+    markdown_cache: CommonMarkCache,
+    show_settings: bool,
+    show_stats: bool,
+    week_filter: Option<WeekFilter>,
+    // Inclusive (min, max) priority shown; PRIORITY_RANGE_FULL shows everything
+    priority_range: (i32, i32),
+    // Only list issues assigned to (or optionally created by) the current user
+    my_issues_filter: bool,
+    view_mode: ViewMode,
+    swimlane_drag: Option<SwimlaneCard>,
+    // Column whose header the pointer is over, and that column's horizontal extent
+    hovered_column: Option<SortColumn>,
+    hovered_column_span: egui::Rangef,
+    // Header cell rects in table order this frame, and the column whose right edge
+    // (where the resize handle sits) the pointer is near
+    header_cell_rects: Vec<egui::Rect>,
+    hovered_resize_col: Option<usize>,
+    // Set from the header context menu; the table is copied after it is built
+    copy_markdown_requested: bool,
+    // Issue ids shown in the matrix view with their dependency matrix; cleared when
+    // issues reload
+    dependency_matrix: Option<(Vec<String>, Vec<Vec<bool>>)>,
+    // Ids of issues reached by following detail panel links, ending with the open issue
+    nav_history: Vec<String>,
+    // Detail panel scroll offset per issue id, and the offset to apply on the next frame
+    detail_scroll_positions: HashMap<String, f32>,
+    detail_scroll_restore: Option<f32>,
+    // (issue_id, previous_status) of an issue closed with Ctrl+W, undoable until the deadline
+    pending_undo: Option<(String, String)>,
+    undo_deadline: f64,
+    // (message, deadline) of the toast shown after copying an id or title
+    copied_toast: Option<(String, f64)>,
+    // Problems found by the startup bd health check that haven't been dismissed
+    health_warnings: Vec<HealthWarning>,
+    theme_tokens: ThemeTokens,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct SummaryStats {
+    total: usize,
+    closed: usize,
+    blocked: usize,
+    in_progress: usize,
+    // Issue count per priority P0..=P4 (out of range priorities are clamped)
+    by_priority: [usize; 5],
+    // Unblocked open issues past stale_open_days; counted with the "ready" issues
+    stale_open: usize,
+}
+
+impl SummaryStats {
+    /// Open issues with no open blockers
+    fn ready(&self) -> usize {
+        self.total - self.closed - self.blocked - self.in_progress
+    }
+}
+
+// Days from creation to closure across closed issues
+#[derive(Clone, Debug, Default)]
+struct CycleTimeStats {
+    mean: f64,
+    median: f64,
+    p90: f64,
+    // Sorted cycle times in days, one per closed issue with both timestamps
+    samples: Vec<f64>,
+}
+
+impl CycleTimeStats {
+    /// Buckets for the Stats panel histogram
+    fn histogram(&self) -> Vec<(&'static str, usize)> {
+        let buckets = [
+            ("<1d", 1.0),
+            ("1-3d", 3.0),
+            ("3-7d", 7.0),
+            ("1-2w", 14.0),
+            ("2-4w", 28.0),
+        ];
+        let mut counts: Vec<(&str, usize)> = buckets.iter().map(|(label, _)| (*label, 0)).collect();
+        counts.push(("4w+", 0));
+        for days in &self.samples {
+            let idx = buckets
+                .iter()
+                .position(|(_, limit)| days < limit)
+                .unwrap_or(buckets.len());
+            counts[idx].1 += 1;
+        }
+        counts
+    }
+}
+
+fn compute_cycle_time_stats(issues: &[Issue]) -> CycleTimeStats {
+    let mut samples: Vec<f64> = issues
+        .iter()
+        .filter(|issue| issue.status == "closed")
+        .filter_map(|issue| {
+            let created = parse_timestamp(&issue.created_at)?;
+            let closed = parse_timestamp(issue.closed_at.as_deref()?)?;
+            Some((closed - created).num_seconds().max(0) as f64 / 86_400.0)
+        })
+        .collect();
+    if samples.is_empty() {
+        return CycleTimeStats::default();
+    }
+    samples.sort_by(f64::total_cmp);
+
+    let percentile = |p: f64| samples[((samples.len() - 1) as f64 * p).round() as usize];
+    CycleTimeStats {
+        mean: samples.iter().sum::<f64>() / samples.len() as f64,
+        median: percentile(0.5),
+        p90: percentile(0.9),
+        samples,
+    }
+}
+
+// Projection of when the open issues will be closed at this week's pace
+#[derive(Clone, Copy, Debug)]
+struct BurnEstimate {
+    velocity_per_day: f32,
+    estimated_days_remaining: f32,
+    estimated_completion: chrono::NaiveDate,
+}
+
+/// Velocity is issues closed this ISO week over the days of the week so far (today
+/// included); None when fewer than 3 were closed this week
+fn compute_burn_estimate(issues: &[Issue]) -> Option<BurnEstimate> {
+    use chrono::Datelike;
+    let closed_this_week = issues
+        .iter()
+        .filter(|issue| issue.status == "closed")
+        .filter(|issue| issue.closed_at.as_deref().is_some_and(is_this_week))
+        .count();
+    if closed_this_week < 3 {
+        return None;
+    }
+
+    let today = chrono::Local::now().date_naive();
+    let days_in_week = today.weekday().number_from_monday() as f32;
+    let velocity_per_day = closed_this_week as f32 / days_in_week;
+    let remaining = issues
+        .iter()
+        .filter(|issue| issue.status != "closed")
+        .count();
+    let estimated_days_remaining = remaining as f32 / velocity_per_day;
+    Some(BurnEstimate {
+        velocity_per_day,
+        estimated_days_remaining,
+        estimated_completion: today
+            + chrono::Duration::days(estimated_days_remaining.ceil() as i64),
+    })
+}
+
+/// Totals for the summary line, from the issues' cached details only; an issue whose
+/// details were not fetched counts as unblocked
+fn compute_summary_stats(
+    issues: &[Issue],
+    cache: &SnapshotCache,
+    config: &AppConfig,
+) -> SummaryStats {
+    let mut stats = SummaryStats {
+        total: issues.len(),
+        ..Default::default()
+    };
+
+    for issue in issues {
+        stats.by_priority[issue.priority.clamp(0, 4) as usize] += 1;
+        match issue.status.as_str() {
+            "closed" => stats.closed += 1,
+            "in_progress" => stats.in_progress += 1,
+            _ => {
+                // Open issues are blocked while any dependency is still open
+                let blocked = cache
+                    .cached_issue(&issue.id)
+                    .is_some_and(|full| full.dependencies.iter().any(|dep| dep.status != "closed"));
+                if blocked {
+                    stats.blocked += 1;
+                } else if is_stale_open(issue, config) {
+                    stats.stale_open += 1;
+                }
+            }
+        }
+    }
+
+    stats
+}
+
+// A single callout in the first-launch tour
+struct TourStep {
+    target_rect: egui::Rect,
+    title: String,
+    body: String,
+}
+
+struct TourState {
+    step: usize,
+    steps: Vec<TourStep>,
+}
+
+// Screen rects of the UI elements the tour points at, captured each frame
+#[derive(Default)]
+struct TourAnchors {
+    sidebar: Option<egui::Rect>,
+    refresh: Option<egui::Rect>,
+    create: Option<egui::Rect>,
+    filter: Option<egui::Rect>,
+    columns: Option<egui::Rect>,
+    table: Option<egui::Rect>,
+}
+
+impl TourAnchors {
+    /// Build the tour steps for the anchors that are currently on screen
+    fn steps(&self) -> Vec<TourStep> {
+        let callouts = [
+            (
+                self.sidebar,
+                "Directories",
+                "Every monitored .beads directory is listed here. Uncheck one to hide its issues.",
+            ),
+            (
+                self.refresh,
+                "Refresh",
+                "Reload issues from all visible directories after changing them with bd.",
+            ),
+            (
+                self.create,
+                "Create Issue",
+                "Open a form to create a new issue in any monitored directory.",
+            ),
+            (
+                self.columns,
+                "Columns",
+                "Choose which columns are shown in the issue table.",
+            ),
+            (
+                self.filter,
+                "Filter",
+                "Type to search all fields. Paste an issue id to jump straight to it.",
+            ),
+            (
+                self.table,
+                "Issue List",
+                "Click a row to open its details. Right-click headers or cells to filter values.",
+            ),
+        ];
+
+        callouts
+            .into_iter()
+            .filter_map(|(rect, title, body)| {
+                rect.map(|target_rect| TourStep {
+                    target_rect,
+                    title: title.to_string(),
+                    body: body.to_string(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Draw a small graph of an issue (centre) with its blockers (left) and
+/// dependents (right). Returns the id of a node that was clicked.
+fn draw_dependency_minimap(
+    ui: &mut egui::Ui,
+    center_id: &str,
+    blockers: &[String],
+    dependents: &[String],
+) -> Option<String> {
+    const SIZE: egui::Vec2 = egui::vec2(200.0, 100.0);
+    const NODE_SIZE: egui::Vec2 = egui::vec2(56.0, 16.0);
+    const MAX_NODES_PER_SIDE: usize = 4;
+
+    let (rect, _) = ui.allocate_exact_size(SIZE, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    let visuals = ui.visuals().clone();
+    painter.rect_filled(rect, 4.0, visuals.extreme_bg_color);
+
+    let mut clicked = None;
+
+    // Lay out up to MAX_NODES_PER_SIDE nodes evenly down one column
+    let column_nodes = |ids: &[String], x: f32| -> Vec<(String, egui::Rect)> {
+        let shown = ids.len().min(MAX_NODES_PER_SIDE);
+        let step = rect.height() / (shown as f32 + 1.0);
+        ids.iter()
+            .take(shown)
+            .enumerate()
+            .map(|(i, id)| {
+                let center = egui::pos2(x, rect.top() + step * (i as f32 + 1.0));
+                (id.clone(), egui::Rect::from_center_size(center, NODE_SIZE))
+            })
+            .collect()
+    };
+
+    let center_rect = egui::Rect::from_center_size(rect.center(), NODE_SIZE);
+    let left_nodes = column_nodes(blockers, rect.left() + NODE_SIZE.x / 2.0 + 4.0);
+    let right_nodes = column_nodes(dependents, rect.right() - NODE_SIZE.x / 2.0 - 4.0);
+
+    let arrow_stroke = egui::Stroke::new(1.0, visuals.weak_text_color());
+    for (_, node_rect) in &left_nodes {
+        let from = node_rect.right_center();
+        painter.arrow(from, center_rect.left_center() - from, arrow_stroke);
+    }
+    for (_, node_rect) in &right_nodes {
+        let from = center_rect.right_center();
+        painter.arrow(from, node_rect.left_center() - from, arrow_stroke);
+    }
+
+    let font = egui::FontId::proportional(10.0);
+    let mut draw_node = |id: &str, node_rect: egui::Rect, is_center: bool| {
+        let response = ui
+            .interact(
+                node_rect,
+                ui.id().with(("minimap_node", id)),
+                egui::Sense::click(),
+            )
+            .on_hover_text(id);
+        let fill = if is_center {
+            visuals.selection.bg_fill
+        } else if response.hovered() {
+            visuals.widgets.hovered.bg_fill
+        } else {
+            visuals.widgets.inactive.bg_fill
+        };
+        painter.rect_filled(node_rect, 3.0, fill);
+        painter.with_clip_rect(node_rect.intersect(rect)).text(
+            node_rect.center(),
+            egui::Align2::CENTER_CENTER,
+            id,
+            font.clone(),
+            visuals.text_color(),
+        );
+        if !is_center && response.clicked() {
+            clicked = Some(id.to_string());
+        }
+    };
+
+    draw_node(center_id, center_rect, true);
+    for (id, node_rect) in &left_nodes {
+        draw_node(id, *node_rect, false);
+    }
+    for (id, node_rect) in &right_nodes {
+        draw_node(id, *node_rect, false);
+    }
+
+    // Note any nodes that didn't fit
+    for (ids, x, align) in [
+        (blockers, rect.left() + 4.0, egui::Align2::LEFT_BOTTOM),
+        (dependents, rect.right() - 4.0, egui::Align2::RIGHT_BOTTOM),
+    ] {
+        if ids.len() > MAX_NODES_PER_SIDE {
+            painter.text(
+                egui::pos2(x, rect.bottom() - 1.0),
+                align,
+                format!("+{}", ids.len() - MAX_NODES_PER_SIDE),
+                font.clone(),
+                visuals.weak_text_color(),
+            );
+        }
+    }
+
+    clicked
+}
+
+const SIDEBAR_CHART_HEIGHT: f32 = 80.0;
+
+/// Small non-interactive bar chart with one labeled bar per entry
+fn draw_count_chart(ui: &mut egui::Ui, id: &str, entries: &[(&str, usize)]) {
+    let bars: Vec<egui_plot::Bar> = entries
+        .iter()
+        .enumerate()
+        .map(|(idx, (label, count))| {
+            egui_plot::Bar::new(idx as f64, *count as f64)
+                .name(label)
+                .width(0.7)
+        })
+        .collect();
+    let labels: Vec<String> = entries.iter().map(|(label, _)| label.to_string()).collect();
+
+    egui_plot::Plot::new(id)
+        .height(SIDEBAR_CHART_HEIGHT)
+        .allow_zoom(false)
+        .allow_drag(false)
+        .allow_scroll(false)
+        .allow_boxed_zoom(false)
+        .allow_double_click_reset(false)
+        .show_grid(false)
+        .show_axes([true, false])
+        .include_y(0.0)
+        .x_axis_formatter(move |mark, _range| {
+            // Only label whole-number marks, which sit under the bars
+            let idx = mark.value.round();
+            if (mark.value - idx).abs() < 1e-6 && idx >= 0.0 {
+                labels.get(idx as usize).cloned().unwrap_or_default()
+            } else {
+                String::new()
+            }
+        })
+        .show(ui, |plot_ui| {
+            plot_ui.bar_chart(egui_plot::BarChart::new(bars));
+        });
+}
+
+// Aggregates shown next to a group header
+#[derive(Clone, Copy, Debug, Default)]
+struct GroupStats {
+    count: usize,
+    avg_priority: f32,
+    blocked: usize,
+    in_progress: usize,
+    closed: usize,
+}
+
+fn compute_group_stats(issues: &[&IssueDisplay]) -> GroupStats {
+    let mut stats = GroupStats {
+        count: issues.len(),
+        ..Default::default()
+    };
+    for display in issues {
+        match display.issue.status.as_str() {
+            "closed" => stats.closed += 1,
+            "in_progress" => stats.in_progress += 1,
+            _ => {}
+        }
+        if display.issue.status != "closed" && display.blockers_count > 0 {
+            stats.blocked += 1;
+        }
+    }
+    if !issues.is_empty() {
+        let total: i32 = issues.iter().map(|display| display.issue.priority).sum();
+        stats.avg_priority = total as f32 / issues.len() as f32;
+    }
+    stats
+}
+
+// Sprint board columns in workflow order; other readiness values follow alphabetically
+const SPRINT_BOARD_COLUMNS: [&str; 5] = ["ready", "stale_open", "blocked", "in_progress", "closed"];
+
+/// Board columns (readiness values) that have at least one issue
+fn sprint_board_columns(issues: &[IssueDisplay]) -> Vec<String> {
+    let present: HashSet<&str> = issues.iter().map(|d| d.readiness.as_str()).collect();
+    let mut columns: Vec<String> = SPRINT_BOARD_COLUMNS
+        .iter()
+        .filter(|column| present.contains(*column))
+        .map(|column| column.to_string())
+        .collect();
+    let mut others: Vec<String> = present
+        .into_iter()
+        .filter(|readiness| !SPRINT_BOARD_COLUMNS.contains(readiness))
+        .map(String::from)
+        .collect();
+    others.sort();
+    columns.extend(others);
+    columns
+}
+
+/// Board lanes, one per assignee with unassigned issues last
+fn sprint_board_lanes(issues: &[IssueDisplay]) -> Vec<Option<String>> {
+    let mut lanes: Vec<Option<String>> = issues
+        .iter()
+        .filter_map(|display| display.issue.assignee.clone())
+        .map(Some)
+        .collect();
+    lanes.sort();
+    lanes.dedup();
+    lanes.push(None);
+    lanes
+}
+
+/// Issues grouped as [column][lane][issue], following sprint_board_columns and
+/// sprint_board_lanes; issues keep their order within a cell
+fn build_sprint_board(issues: &[IssueDisplay]) -> Vec<Vec<Vec<&IssueDisplay>>> {
+    let columns = sprint_board_columns(issues);
+    let lanes = sprint_board_lanes(issues);
+    let mut board = vec![vec![Vec::new(); lanes.len()]; columns.len()];
+    for display in issues {
+        let column = columns.iter().position(|c| *c == display.readiness);
+        let lane = lanes.iter().position(|l| *l == display.issue.assignee);
+        if let (Some(column), Some(lane)) = (column, lane) {
+            board[column][lane].push(display);
+        }
+    }
+    board
+}
+
+/// Issue count, against the limit when one is set; over the limit shows in the error color
+fn draw_wip_badge(ui: &mut egui::Ui, count: usize, limit: Option<usize>, tokens: &ThemeTokens) {
+    let (text, color) = match limit {
+        Some(limit) if count > limit => (format!("{}/{}", count, limit), tokens.error),
+        Some(limit) => (format!("{}/{}", count, limit), tokens.priority_avg),
+        None => (count.to_string(), tokens.priority_avg),
+    };
+    egui::Frame::none()
+        .fill(color.gamma_multiply(0.25))
+        .rounding(4.0)
+        .inner_margin(egui::Margin::symmetric(4.0, 0.0))
+        .show(ui, |ui| {
+            ui.label(egui::RichText::new(text).small().color(color));
+        })
+        .response
+        .on_hover_text("Work in progress (limit from config when set)");
+}
+
+/// Small coloured badges for a group's aggregates; zero counts are left out
+fn draw_group_stats(ui: &mut egui::Ui, stats: &GroupStats, tokens: &ThemeTokens) {
+    let badge = |ui: &mut egui::Ui, text: String, color: egui::Color32, hover: &str| {
+        egui::Frame::none()
+            .fill(color.gamma_multiply(0.25))
+            .rounding(4.0)
+            .inner_margin(egui::Margin::symmetric(4.0, 0.0))
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new(text).small().color(color));
+            })
+            .response
+            .on_hover_text(hover);
+    };
+
+    if stats.count > 0 {
+        let avg = format!("P{:.1}", stats.avg_priority);
+        badge(ui, avg, tokens.priority_avg, "Average priority");
+    }
+    for (count, color, hover) in [
+        (stats.blocked, tokens.blocked, "Blocked"),
+        (stats.in_progress, tokens.in_progress, "In progress"),
+        (stats.closed, tokens.closed, "Closed"),
+    ] {
+        if count > 0 {
+            badge(ui, count.to_string(), color, hover);
+        }
+    }
+}
+
+/// The ready issue to work on next: highest priority, most recently updated first
+fn find_next_action(issues: &[IssueDisplay]) -> Option<&IssueDisplay> {
+    issues
+        .iter()
+        .filter(|display| display.readiness == "ready")
+        .min_by(|a, b| {
+            a.issue
+                .priority
+                .cmp(&b.issue.priority)
+                .then_with(|| b.updated_at.cmp(&a.updated_at))
+        })
+}
+
+/// matrix[row][col] is true when issues[row] depends on issues[col]
+fn build_dependency_matrix(ids: &[String], cache: &SnapshotCache) -> Vec<Vec<bool>> {
+    let index: HashMap<&str, usize> = ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (id.as_str(), i))
+        .collect();
+    let mut matrix = vec![vec![false; ids.len()]; ids.len()];
+    // Dependencies are only present on full issues; one not fetched yet has an empty row
+    for (row, id) in ids.iter().enumerate() {
+        let Some(issue) = cache.cached_issue(id) else {
+            continue;
+        };
+        for dep in &issue.dependencies {
+            if let Some(&col) = index.get(dep.id.as_str()) {
+                matrix[row][col] = true;
+            }
+        }
+    }
+    matrix
+}
+
+const BREADCRUMB_MAX_CRUMBS: usize = 4;
+
+/// Breadcrumb entries for the history followed by the current issue. Each entry is
+/// the history position it navigates back to (None for "..." and the current issue)
+/// and its label. Ids no longer in the issue list are left out.
+fn breadcrumb_crumbs(
+    history: &[String],
+    current_id: &str,
+    issues: &[Issue],
+) -> Vec<(Option<usize>, String)> {
+    let short = |id: &str| id.chars().take(8).collect::<String>();
+    let start = history.len().saturating_sub(BREADCRUMB_MAX_CRUMBS - 1);
+    let mut crumbs = Vec::new();
+    if start > 0 {
+        crumbs.push((None, "...".to_string()));
+    }
+    for (pos, id) in history.iter().enumerate().skip(start) {
+        if issues.iter().any(|issue| &issue.id == id) {
+            crumbs.push((Some(pos), short(id)));
+        }
+    }
+    crumbs.push((None, short(current_id)));
+    crumbs
+}
+
+fn render_breadcrumb(history: &[String], current_id: &str, issues: &[Issue]) -> String {
+    breadcrumb_crumbs(history, current_id, issues)
+        .into_iter()
+        .map(|(_, label)| label)
+        .collect::<Vec<_>>()
+        .join(" › ")
+}
+
+/// Number of issues reachable from id through dependents_map, i.e. everything it
+/// blocks directly or indirectly
+fn compute_transitive_dependents(id: &str, dependents_map: &HashMap<String, Vec<String>>) -> usize {
+    let mut visited: HashSet<&str> = HashSet::from([id]);
+    let mut queue: VecDeque<&str> = VecDeque::from([id]);
+    while let Some(current) = queue.pop_front() {
+        for dependent in dependents_map.get(current).into_iter().flatten() {
+            if visited.insert(dependent.as_str()) {
+                queue.push_back(dependent.as_str());
+            }
+        }
+    }
+    visited.len() - 1
+}
+
+/// Open issue not updated within config.stale_open_days
+fn is_stale_open(issue: &Issue, config: &AppConfig) -> bool {
+    let (Some(days), Some(updated)) = (config.stale_open_days, parse_timestamp(&issue.updated_at))
+    else {
+        return false;
+    };
+    issue.status == "open" && chrono::Utc::now() - chrono::Duration::days(days as i64) > updated
+}
+
+/// Known issue ids mentioned in free text, in order of first mention. Anything shaped
+/// like PREFIX-SUFFIX is a candidate; only ids in known_ids are kept.
+fn extract_issue_refs(text: &str, known_ids: &HashSet<String>) -> Vec<String> {
+    static ISSUE_REF: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    let pattern = ISSUE_REF
+        .get_or_init(|| regex::Regex::new(r"\b[A-Za-z][A-Za-z0-9]*-[A-Za-z0-9]+\b").unwrap());
+
+    let mut refs: Vec<String> = Vec::new();
+    for found in pattern.find_iter(text) {
+        let id = found.as_str();
+        if known_ids.contains(id) && !refs.iter().any(|r| r == id) {
+            refs.push(id.to_string());
+        }
+    }
+    refs
+}
+
+/// Tags carried by every issue whose id is in ids, sorted
+fn compute_common_tags(ids: &HashSet<String>, issues: &[Issue]) -> Vec<String> {
+    let mut selected = issues.iter().filter(|issue| ids.contains(&issue.id));
+    let Some(first) = selected.next() else {
+        return Vec::new();
+    };
+    let mut common: Vec<String> = first.tags.clone();
+    for issue in selected {
+        common.retain(|tag| issue.tags.contains(tag));
+    }
+    common.sort();
+    common.dedup();
+    common
+}
+
+/// Menu labels and clipboard text for copying an issue's id and title
+fn issue_copy_variants(issue: &Issue) -> [(&'static str, String); 3] {
+    [
+        ("Copy ID", issue.id.clone()),
+        ("Copy title", issue.title.clone()),
+        ("Copy id: title", format!("{}: {}", issue.id, issue.title)),
+    ]
+}
+
+/// Native save dialog for an export, or None if the user cancelled
+fn pick_export_path(default_name: &str, filter_name: &str, extension: &str) -> Option<PathBuf> {
+    rfd::FileDialog::new()
+        .set_file_name(default_name)
+        .add_filter(filter_name, &[extension])
+        .save_file()
+}
+
+/// Issue `step` rows away from the selection in the visible list, clamped to its
+/// edges. A selection that isn't visible (or none) starts from the top going down
+/// and from the bottom going up
+fn step_selection(
+    filtered: &[IssueDisplay],
+    selected: Option<usize>,
+    step: isize,
+) -> Option<usize> {
+    let last = filtered.len().checked_sub(1)?;
+    let pos = selected.and_then(|idx| filtered.iter().position(|d| d.original_idx == idx));
+    let new_pos = match pos {
+        Some(pos) => pos.saturating_add_signed(step).min(last),
+        None if step > 0 => 0,
+        None => last,
+    };
+    Some(filtered[new_pos].original_idx)
+}
+
+/// Filter and cardinality value of the Notes column; the length is display-only
+fn notes_filter_value(notes_len: Option<usize>) -> &'static str {
+    if notes_len.is_some() {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+/// Map of issue id -> ids of the issues that depend on it, read from the full issues
+/// in the cache (loading any that aren't cached yet)
+fn build_dependents_map(
+    issues: &[Issue],
+    cache: &mut SnapshotCache,
+) -> HashMap<String, Vec<String>> {
+    let mut dependents_map: HashMap<String, Vec<String>> = HashMap::with_capacity(issues.len());
+
+    // We need to load full issue details to get dependencies
+    for issue in issues {
+        if let Ok(full_issue) = cache.get_issue_ref(&issue.id) {
+            // For each dependency (blocker), add this issue as a dependent
+            for dep in &full_issue.dependencies {
+                dependents_map
+                    .entry(dep.id.clone())
+                    .or_default()
+                    .push(issue.id.clone());
+            }
+        }
+    }
+    dependents_map
+}
+
+/// Count non-closed issues per assignee
+fn compute_assignee_workloads(issues: &[Issue]) -> HashMap<String, usize> {
+    let mut workloads = HashMap::new();
+    for issue in issues {
+        if issue.status == "closed" {
+            continue;
+        }
+        if let Some(assignee) = &issue.assignee {
+            *workloads.entry(assignee.clone()).or_insert(0) += 1;
+        }
+    }
+    workloads
+}
+
+/// Every tag in use with the number of issues carrying it, most used first
+fn compute_tag_frequencies(issues: &[Issue]) -> Vec<(String, usize)> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for issue in issues {
+        for tag in &issue.tags {
+            *counts.entry(tag.as_str()).or_insert(0) += 1;
+        }
+    }
+    let mut sorted: Vec<(String, usize)> = counts
+        .into_iter()
+        .map(|(tag, count)| (tag.to_string(), count))
+        .collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    sorted
+}
+
+/// Split a comma-separated tag list, dropping blanks and duplicates
+fn parse_tags(text: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in text.split(',').map(str::trim) {
+        if !tag.is_empty() && !tags.iter().any(|t| t == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+/// Workloads ordered from most to least loaded, ties broken by name
+fn sorted_workloads(workloads: &HashMap<String, usize>) -> Vec<(&str, usize)> {
+    let mut sorted: Vec<(&str, usize)> = workloads
+        .iter()
+        .map(|(name, count)| (name.as_str(), *count))
+        .collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    sorted
+}
+
+/// Whether the issue is in progress and assigned to the configured current user
+fn is_my_active_issue(issue: &Issue, config: &AppConfig) -> bool {
+    match (&config.current_user, &issue.assignee) {
+        (Some(user), Some(assignee)) => {
+            issue.status == "in_progress" && !user.is_empty() && assignee == user
+        }
+        _ => false,
+    }
+}
+
+/// Number of in-progress issues assigned to user
+fn count_my_wip(issues: &[Issue], user: &str) -> usize {
+    if user.is_empty() {
+        return 0;
+    }
+    issues
+        .iter()
+        .filter(|issue| issue.status == "in_progress")
+        .filter(|issue| issue.assignee.as_deref() == Some(user))
+        .count()
+}
+
+/// Whether the issue belongs in the "My Issues" filter for the configured current user
+fn is_my_issue(issue: &Issue, config: &AppConfig) -> bool {
+    let Some(user) = config.current_user.as_deref().filter(|u| !u.is_empty()) else {
+        return false;
+    };
+    issue.assignee.as_deref() == Some(user)
+        || (config.my_issues_includes_created && issue.created_by.as_deref() == Some(user))
+}
+
+/// Find open issues that had blockers and whose blockers are now all closed
+fn find_newly_ready(issues: &[Issue], cache: &SnapshotCache) -> Vec<String> {
+    let mut ready: Vec<String> = issues
+        .iter()
+        .filter(|issue| issue.status == "open")
+        .filter(|issue| {
+            cache.cached_issue(&issue.id).is_some_and(|full| {
+                !full.dependencies.is_empty()
+                    && full.dependencies.iter().all(|dep| dep.status == "closed")
+            })
+        })
+        .map(|issue| issue.id.clone())
+        .collect();
+    ready.sort();
+    ready
+}
+
+// Struct to hold pre-computed display values for an issue
+struct IssueDisplay {
+    original_idx: usize,
+    issue: Issue,
+    readiness: String,
+    blockers_count: usize,
+    dependents_count: usize,
+    impact_count: usize,
+    // Character count of the notes, None when the issue has none
+    notes_len: Option<usize>,
+    updated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    unresolved_comments: usize,
+}
+
+impl IssueDisplay {
+    /// Current display name of the issue's directory, looked up by path so that
+    /// renames in Settings show up without waiting for a refresh
+    fn effective_directory_name<'a>(&'a self, config: &'a AppConfig) -> &'a str {
+        config.directory_name(&self.issue)
+    }
+}
+
+// Change requested from a column filter menu
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FilterAction {
+    // Flip whether one value is excluded
+    Toggle(String),
+    // Flip whether one value is in the include-only set
+    ToggleInclude(String),
+    // Remove every value and pattern filter for the column
+    ClearAll,
+    // Exclude every value except this one
+    SetOnly(String),
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Serialize, Deserialize)]
+enum SortColumn {
+    Id,
+    Directory,
+    Title,
+    Status,
+    Priority,
+    Type,
+    Assignee,
+    CreatedBy,
+    Blockers,
+    Dependents,
+    // Issues transitively blocked by this one
+    TransitiveImpact,
+    // Whether the issue has notes
+    Notes,
+    DueDate,
+    UpdatedAt,
+}
+
+// Height at which the detail panel's description editor starts scrolling
+const DESCRIPTION_MAX_HEIGHT: f32 = 240.0;
+
+// Most matches the "Add blocker" picker lists at once; searching narrows the rest
+const BLOCKER_PICKER_LIMIT: usize = 50;
+
+// Columns written by Export CSV, regardless of which are shown in the table
+const CSV_EXPORT_COLUMNS: [SortColumn; 9] = [
+    SortColumn::Id,
+    SortColumn::Directory,
+    SortColumn::Title,
+    SortColumn::Status,
+    SortColumn::Priority,
+    SortColumn::Type,
+    SortColumn::Assignee,
+    SortColumn::Blockers,
+    SortColumn::Dependents,
+];
+
+// How long the "Copied!" toast stays up
+const COPIED_TOAST_SECS: f64 = 1.5;
+
+// How long the sorted column header flashes after a view switch changes the sort
+const RESORT_HIGHLIGHT_SECS: f64 = 0.6;
+
+// Every priority bd knows, P0 to P4; the priority range filter is off at this range
+const PRIORITY_RANGE_FULL: (i32, i32) = (0, 4);
+
+// List table columns in display order, with their header names
+const TABLE_COLUMNS: [(SortColumn, &str); 14] = [
+    (SortColumn::Id, "ID"),
+    (SortColumn::Directory, "Directory"),
+    (SortColumn::Title, "Title"),
+    (SortColumn::Status, "Status"),
+    (SortColumn::Priority, "Priority"),
+    (SortColumn::Type, "Type"),
+    (SortColumn::Assignee, "Assignee"),
+    (SortColumn::CreatedBy, "Created By"),
+    (SortColumn::Blockers, "Blockers"),
+    (SortColumn::Dependents, "Dependents"),
+    (SortColumn::TransitiveImpact, "Impact"),
+    (SortColumn::Notes, "Notes"),
+    (SortColumn::DueDate, "Due Date"),
+    (SortColumn::UpdatedAt, "Updated"),
+];
+
+impl Default for BeadUiApp {
+    fn default() -> Self {
+        // Load config from file
+        let mut config = AppConfig::load();
+        config.expand_directory_globs();
+
+        // Auto-add current working directory if not already present
+        if let Ok(cwd) = std::env::current_dir() {
+            let cwd_exists = config.directories.iter().any(|d| d.path == cwd);
+
+            if !cwd_exists {
+                // Add PWD to config as visible by default
+                config.directories.push(DirectoryConfig {
+                    path: cwd,
+                    visible: true,
+                    display_name: String::new(), // Will be computed later
+                    custom_display_name: false,
+                    is_glob: false,
+                    is_glob_child: false,
+                });
+
+                // Compute display names for all directories
+                config.compute_display_names();
+
+                // Save the updated config
+                let _ = config.save();
+            }
+        }
+
+        Self::with_config(config)
+    }
+}
+
+impl BeadUiApp {
+    /// App for the directories in config, with its sort and filters restored; the first
+    /// load starts right away. Nothing is written back to disk
+    fn with_config(config: AppConfig) -> Self {
+        // Restore the column filters left last session; the defaults only apply until
+        // some have been saved
+        let column_filters = config
+            .column_filters
+            .clone()
+            .unwrap_or_else(|| config.default_column_filters());
+        let sort_keys = config.sort_keys.clone();
+
+        // Find the first visible directory index for default creation
+        let first_visible_idx = config
+            .directories
+            .iter()
+            .position(|d| d.visible)
+            .unwrap_or(0);
+
+        let mut app = Self {
+            issues: Vec::new(),
+            selected_index: None,
+            filter_text: String::new(),
+            error_message: None,
+            sort_keys,
+            user_modified_sort: false,
+            resort_highlight_until: 0.0,
+            current_issue: None,
+            original_issue: None,
+            edit_modified: false,
+            save_message: None,
+            preset_name_input: String::new(),
+            window_title: WINDOW_TITLE.to_string(),
+            pending_window_geometry: None,
+            window_geometry_changed_at: 0.0,
+            window_size_clamped: false,
+            hovered_row: None,
+            split_ratio: 0.5, // Start with 50/50 split
+            column_filters,
+            column_visibility: HashMap::from([
+                (SortColumn::Id, true),
+                (SortColumn::Directory, true),
+                (SortColumn::Title, true),
+                (SortColumn::Status, true),
+                (SortColumn::Priority, true),
+                (SortColumn::Type, true),
+                (SortColumn::Assignee, true),
+                (SortColumn::CreatedBy, false),
+                (SortColumn::Blockers, true),
+                (SortColumn::Dependents, true),
+                (SortColumn::TransitiveImpact, true),
+                (SortColumn::Notes, false),
+                (SortColumn::DueDate, false),
+                (SortColumn::UpdatedAt, true),
+            ]),
+            dependents_map: HashMap::new(),
+            impact_counts: HashMap::new(),
+            dependents_map_dirty: true,
+            snapshot_cache: SnapshotCache::new(config.cache_max_entries),
+            bd_pool: Arc::new(BdPool::new(4)),
+            refresh_receivers: Vec::new(),
+            loading: false,
+            add_directory_error: None,
+            directory_errors: HashMap::new(),
+            bd_available: true,
+            bd_version: None,
+            user_labels: load_user_labels(),
+            #[cfg(feature = "tray")]
+            tray: None,
+            config,
+            show_create_dialog: false,
+            add_directory_requested: false,
+            create_title: String::new(),
+            create_description: String::new(),
+            create_type: "task".to_string(),
+            create_priority: 2,
+            create_assignee: String::new(),
+            create_tags: String::new(),
+            create_directory_index: first_visible_idx,
+            create_template_index: None,
+            add_blocker_text: String::new(),
+            pending_blocker_removal: None,
+            filter_pattern_text: String::new(),
+            scroll_to_selected: false,
+            pending_paste_navigation: None,
+            flash_row: None,
+            tour: None,
+            tour_anchors: TourAnchors::default(),
+            newly_ready: Vec::new(),
+            pending_mark_ready: None,
+            pending_selection: None,
+            pending_delete: None,
+            multi_selected: HashSet::new(),
+            show_bulk_dialog: false,
+            bulk_add_tag: String::new(),
+            bulk_remove_tag: String::new(),
+            pending_status_change: None,
+            status_change_reason: String::new(),
+            summary_stats: SummaryStats::default(),
+            cycle_time_stats: CycleTimeStats::default(),
+            burn_estimate: None,
+            assignee_workloads: HashMap::new(),
+            all_tags: Vec::new(),
+            edit_tags_text: String::new(),
+            new_custom_field_key: String::new(),
+            description_preview: true,
+            description_preview_id: None,
+            markdown_cache: CommonMarkCache::default(),
+            show_settings: false,
+            show_stats: false,
+            week_filter: None,
+            priority_range: PRIORITY_RANGE_FULL,
+            my_issues_filter: false,
+            view_mode: ViewMode::default(),
+            swimlane_drag: None,
+            hovered_column: None,
+            hovered_column_span: egui::Rangef::NOTHING,
+            header_cell_rects: Vec::new(),
+            hovered_resize_col: None,
+            copy_markdown_requested: false,
+            dependency_matrix: None,
+            nav_history: Vec::new(),
+            detail_scroll_positions: HashMap::new(),
+            detail_scroll_restore: None,
+            pending_undo: None,
+            undo_deadline: 0.0,
+            copied_toast: None,
+            health_warnings: Vec::new(),
+            theme_tokens: ThemeTokens::default(),
+        };
+        app.health_warnings = BdClient::check_health(&app.config.directories);
+        if !app.config.has_seen_tour {
+            app.tour = Some(TourState {
+                step: 0,
+                steps: Vec::new(),
+            });
+        }
+        app.refresh();
+        app
+    }
+
+    fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        // Configure fonts and styles for better system appearance
+        Self::setup_custom_fonts(cc);
+        Self::default()
+    }
+
+    /// Shown in place of the issue views when bd cannot be run
+    fn show_bd_missing(&mut self, ui: &mut egui::Ui) {
+        ui.vertical_centered(|ui| {
+            ui.add_space((ui.available_height() / 4.0).max(12.0));
+            ui.heading("The bd command-line tool was not found");
+            ui.add_space(8.0);
+            ui.label(format!(
+                "beadui reads and edits issues through the bd CLI, but `{} --version` could not be run.",
+                bd_binary()
+            ));
+            ui.label("Install bd and make sure it is on your PATH (or set BEADUI_BD_BIN), then refresh.");
+            ui.add_space(8.0);
+            ui.hyperlink_to("bd installation instructions", BD_INSTALL_URL);
+            ui.add_space(8.0);
+            if ui.button("Refresh").clicked() {
+                self.refresh();
+            }
+        });
+    }
+
+    fn load_system_fonts(cc: &eframe::CreationContext<'_>) {
+        let mut fonts = egui::FontDefinitions::default();
+
+        // Try to load system UI font
+        let system_source = SystemSource::new();
+
+        // Try to find the system UI font based on platform
+        let ui_font_result = if cfg!(target_os = "macos") {
+            // On macOS, try system UI font (which will be San Francisco on modern macOS)
+            system_source.select_best_match(&[FamilyName::SansSerif], &Properties::new())
+        } else if cfg!(target_os = "windows") {
+            // On Windows, try Segoe UI
+            system_source
+                .select_best_match(
+                    &[FamilyName::Title("Segoe UI".to_string())],
+                    &Properties::new(),
+                )
+                .or_else(|_| {
+                    system_source.select_best_match(&[FamilyName::SansSerif], &Properties::new())
+                })
+        } else {
+            // On Linux, try common UI fonts
+            system_source
+                .select_best_match(
+                    &[FamilyName::Title("Ubuntu".to_string())],
+                    &Properties::new(),
+                )
+                .or_else(|_| {
+                    system_source.select_best_match(
+                        &[FamilyName::Title("Cantarell".to_string())],
+                        &Properties::new(),
+                    )
+                })
+                .or_else(|_| {
+                    system_source.select_best_match(&[FamilyName::SansSerif], &Properties::new())
+                })
+        };
+
+        // Load the system font if found
+        if let Ok(handle) = ui_font_result {
+            if let Ok(font) = handle.load() {
+                if let Some(font_data) = font.copy_font_data() {
+                    fonts.font_data.insert(
+                        "system_ui".to_owned(),
+                        egui::FontData::from_owned(font_data.to_vec()),
+                    );
+
+                    // Set system UI font as the first proportional font
+                    fonts
+                        .families
+                        .entry(egui::FontFamily::Proportional)
+                        .or_default()
+                        .insert(0, "system_ui".to_owned());
+                }
+            }
+        }
+
+        // Load system monospace font
+        let mono_font_result =
+            system_source.select_best_match(&[FamilyName::Monospace], &Properties::new());
+
+        if let Ok(handle) = mono_font_result {
+            if let Ok(font) = handle.load() {
+                if let Some(font_data) = font.copy_font_data() {
+                    fonts.font_data.insert(
+                        "system_mono".to_owned(),
+                        egui::FontData::from_owned(font_data.to_vec()),
+                    );
+
+                    fonts
+                        .families
+                        .entry(egui::FontFamily::Monospace)
+                        .or_default()
+                        .insert(0, "system_mono".to_owned());
+                }
+            }
+        }
+
+        cc.egui_ctx.set_fonts(fonts);
+    }
+
+    fn setup_custom_fonts(cc: &eframe::CreationContext<'_>) {
+        // Load system fonts first
+        Self::load_system_fonts(cc);
+
+        // Set up better font sizing that matches system UI conventions
+        let mut style = (*cc.egui_ctx.style()).clone();
+
+        // Configure text styles with appropriate sizes for a native look
+        // These sizes work well on macOS and other platforms
+        style.text_styles = [
+            (
+                egui::TextStyle::Small,
+                egui::FontId::new(11.0, egui::FontFamily::Proportional),
+            ),
+            (
+                egui::TextStyle::Body,
+                egui::FontId::new(13.0, egui::FontFamily::Proportional),
+            ),
+            (
+                egui::TextStyle::Button,
+                egui::FontId::new(13.0, egui::FontFamily::Proportional),
+            ),
+            (
+                egui::TextStyle::Heading,
+                egui::FontId::new(17.0, egui::FontFamily::Proportional),
+            ),
+            (
+                egui::TextStyle::Monospace,
+                egui::FontId::new(12.0, egui::FontFamily::Monospace),
+            ),
+        ]
+        .into();
+
+        // Adjust spacing for a cleaner look
+        style.spacing.item_spacing = egui::vec2(8.0, 6.0);
+        style.spacing.button_padding = egui::vec2(8.0, 4.0);
+        style.spacing.window_margin = egui::Margin::same(8.0);
+
+        cc.egui_ctx.set_style(style);
+    }
+
+    fn compute_dependents_map(&mut self) {
+        self.dependents_map = build_dependents_map(&self.issues, &mut self.snapshot_cache);
+        // One search per issue here, rather than one per issue every frame
+        self.impact_counts = self
+            .issues
+            .iter()
+            .map(|issue| {
+                let count = compute_transitive_dependents(&issue.id, &self.dependents_map);
+                (issue.id.clone(), count)
+            })
+            .collect();
+    }
+
+    /// Rebuild dependents_map if issues were reloaded since it was last computed
+    fn ensure_dependents_map(&mut self) {
+        if self.dependents_map_dirty {
+            self.compute_dependents_map();
+            self.dependents_map_dirty = false;
+        }
+    }
+
+    /// Start loading issues from all visible directories on a background thread; a
+    /// newer refresh supersedes one still in flight
+    fn refresh(&mut self) {
+        clear_db_file_cache();
+        // Callers have usually just changed the selected issue; read it back from bd
+        // rather than showing the cached copy until the refresh lands
+        if let Some(issue) = self.selected_index.and_then(|idx| self.issues.get(idx)) {
+            self.snapshot_cache.invalidate(&issue.id);
+        }
+        let directories = self.config.directories.clone();
+        let pool = Arc::clone(&self.bd_pool);
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let bd_version = BdClient::version();
+            let (issues, errors) = BdClient::list_issues_from_all(&directories);
+            let details = fetch_issue_details(&issues, &pool);
+            let _ = sender.send(RefreshResult {
+                directory: None,
+                bd_version,
+                issues,
+                details,
+                errors,
+            });
+        });
+
+        // Reloads every directory, so single-directory refreshes still in flight are moot
+        self.refresh_receivers = vec![receiver];
+        self.loading = true;
+        self.error_message = None;
+    }
+
+    /// Apply every background refresh that has finished, in the order they were started
+    fn poll_refresh(&mut self) {
+        let mut finished = Vec::new();
+        let mut loader_exited = false;
+        self.refresh_receivers
+            .retain(|receiver| match receiver.try_recv() {
+                Ok(result) => {
+                    finished.push(result);
+                    false
+                }
+                Err(mpsc::TryRecvError::Empty) => true,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    loader_exited = true;
+                    false
+                }
+            });
+        self.loading = !self.refresh_receivers.is_empty();
+
+        if loader_exited {
+            self.error_message = Some("Refresh failed: loader thread exited".to_string());
+        }
+        for result in finished {
+            match result.directory.clone() {
+                Some(dir_config) => self.apply_directory_refresh(dir_config, result),
+                None => self.apply_refresh(result),
+            }
+        }
+    }
+
+    fn apply_refresh(&mut self, result: RefreshResult) {
+        self.bd_available = result.bd_version.is_ok();
+        self.bd_version = result.bd_version.ok();
+
+        // Clear the snapshot cache on refresh
+        self.snapshot_cache.clear();
+        // The open issue is reloaded below and should stay where it was scrolled to
+        let open_id = self.current_issue.as_ref().map(|issue| issue.id.clone());
+        self.detail_scroll_positions
+            .retain(|id, _| Some(id) == open_id.as_ref());
+
+        // The new list is ordered differently, so track the selection by id
+        let (selected_id, pending_id) = self.selection_ids();
+        self.issues = result.issues;
+        for issue in &mut self.issues {
+            issue.user_label_color = self.user_labels.get(&issue.id).cloned();
+        }
+
+        // Register all issue sources in the cache
+        for dir_config in &self.config.directories {
+            if dir_config.visible {
+                for issue in &self.issues {
+                    if issue.source_directory == dir_config.display_name
+                        || (dir_config.display_name.is_empty()
+                            && issue.source_directory
+                                == dir_config
+                                    .path
+                                    .file_name()
+                                    .and_then(|n| n.to_str())
+                                    .unwrap_or(""))
+                    {
+                        self.snapshot_cache.register_issue_source(
+                            &issue.id,
+                            &issue.source_directory,
+                            Some(dir_config.path.clone()),
+                        );
+                    }
+                }
+            }
+        }
+
+        for (id, issue) in result.details {
+            self.snapshot_cache.insert_issue(id, issue);
+        }
+        self.directory_errors = result.errors.into_iter().collect();
+        // Show the refreshed copy of the open issue, unless it has unsaved edits
+        if !self.edit_modified {
+            self.current_issue = None;
+        }
+        self.restore_selection(selected_id, pending_id);
+
+        self.recompute_derived_state();
+
+        if let Some(column) = self.config.auto_sort_on_refresh {
+            if !self.user_modified_sort {
+                self.sort_keys = vec![(column, false)];
+            }
+        }
+        self.user_modified_sort = false;
+
+        #[cfg(feature = "tray")]
+        self.update_tray();
+    }
+
+    /// Start reloading one directory's issues on a background thread, leaving the other
+    /// directories' issues and cache alone
+    fn refresh_directory(&mut self, dir_index: usize) {
+        let Some(dir_config) = self.config.directories.get(dir_index).cloned() else {
+            return;
+        };
+        let pool = Arc::clone(&self.bd_pool);
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let bd_version = BdClient::version();
+            let source_name = dir_config.source_name();
+            let (issues, errors) = match BdClient::list_issues(Some(&dir_config.path), &source_name)
+            {
+                Ok(issues) => (issues, Vec::new()),
+                Err(e) => (Vec::new(), vec![(source_name, e)]),
+            };
+            let details = fetch_issue_details(&issues, &pool);
+            let _ = sender.send(RefreshResult {
+                directory: Some(dir_config),
+                bd_version,
+                issues,
+                details,
+                errors,
+            });
+        });
+
+        self.refresh_receivers.push(receiver);
+        self.loading = true;
+    }
+
+    /// Replace one directory's issues with those from a finished refresh_directory
+    fn apply_directory_refresh(&mut self, dir_config: DirectoryConfig, result: RefreshResult) {
+        self.bd_available = result.bd_version.is_ok();
+        self.bd_version = result.bd_version.ok();
+
+        let source_name = dir_config.source_name();
+        if let Some((_, e)) = result.errors.into_iter().next() {
+            self.error_message = Some(format!("Failed to refresh {}: {}", source_name, e));
+            self.directory_errors.insert(source_name, e);
+            return;
+        }
+        self.directory_errors.remove(&source_name);
+        let mut new_issues = result.issues;
+
+        // Indices shift when the directory's issues are replaced, so track the selection by id
+        let (selected_id, pending_id) = self.selection_ids();
+
+        // Matched by path: a rename since the last load changed the display name
+        let cache = &mut self.snapshot_cache;
+        self.issues.retain(|issue| {
+            let keep = issue.source_path != dir_config.path;
+            if !keep {
+                cache.invalidate(&issue.id);
+            }
+            keep
+        });
+        for issue in &mut new_issues {
+            issue.user_label_color = self.user_labels.get(&issue.id).cloned();
+            self.snapshot_cache.register_issue_source(
+                &issue.id,
+                &issue.source_directory,
+                Some(dir_config.path.clone()),
+            );
+        }
+        self.issues.append(&mut new_issues);
+        for (id, issue) in result.details {
+            self.snapshot_cache.insert_issue(id, issue);
+        }
+        // Show the refreshed copy of the open issue, unless it has unsaved edits
+        if !self.edit_modified
+            && self
+                .current_issue
+                .as_ref()
+                .is_some_and(|issue| issue.source_path == dir_config.path)
+        {
+            self.current_issue = None;
+        }
+
+        self.restore_selection(selected_id, pending_id);
+        self.recompute_derived_state();
+    }
+
+    /// Ids of the selected issue and of the selection waiting on the unsaved changes
+    /// prompt, for restore_selection once self.issues has been replaced
+    fn selection_ids(&self) -> (Option<String>, Option<Option<String>>) {
+        let id_at = |idx: Option<usize>| {
+            idx.and_then(|idx| self.issues.get(idx))
+                .map(|issue| issue.id.clone())
+        };
+        (
+            id_at(self.selected_index),
+            self.pending_selection.map(id_at),
+        )
+    }
+
+    /// Point the selection back at the issues named by selection_ids; an issue that is
+    /// gone leaves nothing selected
+    fn restore_selection(
+        &mut self,
+        selected_id: Option<String>,
+        pending_id: Option<Option<String>>,
+    ) {
+        let position = |id: String| self.issues.iter().position(|issue| issue.id == id);
+        self.selected_index = selected_id.and_then(position);
+        self.pending_selection = pending_id.map(|id| id.and_then(position));
+        if self.selected_index.is_none() {
+            // Unsaved edits belonged to an issue that is no longer listed
+            self.current_issue = None;
+            self.edit_modified = false;
+        }
+    }
+
+    /// Everything computed from the loaded issues: dependency links, stats and tags
+    fn recompute_derived_state(&mut self) {
+        // Rebuilt on demand by ensure_dependents_map and show_matrix_view
+        self.dependents_map_dirty = true;
+        self.dependency_matrix = None;
+        // Only the details fetched with the issues are read; a miss here would run bd
+        // show on the UI thread
+        self.newly_ready = find_newly_ready(&self.issues, &self.snapshot_cache);
+        self.summary_stats =
+            compute_summary_stats(&self.issues, &self.snapshot_cache, &self.config);
+        self.cycle_time_stats = compute_cycle_time_stats(&self.issues);
+        self.burn_estimate = compute_burn_estimate(&self.issues);
+        self.assignee_workloads = compute_assignee_workloads(&self.issues);
+        self.all_tags = compute_tag_frequencies(&self.issues);
+    }
+
+    /// Push the current badge count and recent issues to the tray icon
+    #[cfg(feature = "tray")]
+    fn update_tray(&mut self) {
+        if self.tray.is_none() {
+            return;
+        }
+
+        let issues = self.issues.clone();
+        let count = issues
+            .iter()
+            .filter(|issue| issue.status == "in_progress")
+            .filter(|issue| self.get_blockers_count(&issue.id) == 0)
+            .count();
+
+        let mut recent: Vec<&Issue> = issues.iter().collect();
+        recent.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        let recent: Vec<(String, String)> = recent
+            .into_iter()
+            .take(5)
+            .map(|issue| (issue.id.clone(), issue.title.clone()))
+            .collect();
+
+        if let Some(tray) = self.tray.as_mut() {
+            tray.update(count, &recent);
+        }
+    }
+
+    /// Create or drop the tray icon to match the config and handle its menu clicks
+    #[cfg(feature = "tray")]
+    fn poll_tray(&mut self, ctx: &egui::Context) {
+        #[cfg(target_os = "linux")]
+        while gtk::events_pending() {
+            gtk::main_iteration_do(false);
+        }
+
+        if self.config.systray_enabled && self.tray.is_none() {
+            match Tray::new() {
+                Ok(tray) => {
+                    self.tray = Some(tray);
+                    self.update_tray();
+                }
+                Err(e) => {
+                    self.error_message = Some(e);
+                    self.config.systray_enabled = false;
+                }
+            }
+        } else if !self.config.systray_enabled {
+            self.tray = None;
+        }
+
+        let Some(tray) = &self.tray else {
+            return;
+        };
+        let mut refresh_requested = false;
+        // Applied after the loop, since request_selection needs all of self
+        let mut select = None;
+        while let Ok(event) = tray_icon::menu::MenuEvent::receiver().try_recv() {
+            let focus = |ctx: &egui::Context| {
+                ctx.send_viewport_cmd(egui::ViewportCommand::Visible(true));
+                ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+                ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+            };
+            if event.id == tray.show_id {
+                focus(ctx);
+            } else if event.id == tray.capture_id {
+                // The create dialog doubles as quick capture
+                self.show_create_dialog = true;
+                focus(ctx);
+            } else if event.id == tray.refresh_id {
+                refresh_requested = true;
+            } else if let Some((_, issue_id)) = tray
+                .recent_ids
+                .iter()
+                .find(|(menu_id, _)| *menu_id == event.id)
+            {
+                if let Some(idx) = self.issues.iter().position(|i| &i.id == issue_id) {
+                    select = Some(idx);
+                }
+                focus(ctx);
+            }
+        }
+        if let Some(idx) = select {
+            self.request_selection(Some(idx));
+            self.scroll_to_selected = true;
+        }
+        if refresh_requested {
+            self.refresh();
+        }
+
+        // Menu clicks arrive while the window may be idle
+        ctx.request_repaint_after(std::time::Duration::from_millis(250));
+    }
+
+    /// Save the window's size and position once it has stopped changing for a moment
+    fn track_window_geometry(&mut self, ctx: &egui::Context) {
+        let (inner, outer, monitor, now) = ctx.input(|i| {
+            let viewport = i.viewport();
+            (
+                viewport.inner_rect,
+                viewport.outer_rect,
+                viewport.monitor_size,
+                i.time,
+            )
+        });
+        let (Some(inner), Some(outer)) = (inner, outer) else {
+            return;
+        };
+
+        // Only the monitor's size is known, not where it sits, so the restored position is
+        // left alone (clamping it would pull windows off secondary monitors) and only a
+        // size saved on a larger monitor is shrunk to fit
+        if !self.window_size_clamped {
+            let Some(monitor) = monitor else {
+                return;
+            };
+            self.window_size_clamped = true;
+            let fitted = inner.size().min(monitor);
+            if fitted != inner.size() {
+                ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(fitted));
+                return;
+            }
+        }
+
+        let geometry = (inner.size(), outer.min);
+        let saved = match (
+            self.config.window_width,
+            self.config.window_height,
+            self.config.window_x,
+            self.config.window_y,
+        ) {
+            (Some(w), Some(h), Some(x), Some(y)) => Some((egui::vec2(w, h), egui::pos2(x, y))),
+            _ => None,
+        };
+        if saved == Some(geometry) {
+            self.pending_window_geometry = None;
+            return;
+        }
+        if self.pending_window_geometry != Some(geometry) {
+            self.pending_window_geometry = Some(geometry);
+            self.window_geometry_changed_at = now;
+        }
+        if now - self.window_geometry_changed_at < WINDOW_GEOMETRY_SAVE_DELAY_SECS {
+            ctx.request_repaint_after(std::time::Duration::from_secs_f64(
+                WINDOW_GEOMETRY_SAVE_DELAY_SECS,
+            ));
+            return;
+        }
+
+        self.pending_window_geometry = None;
+        self.config.window_width = Some(geometry.0.x);
+        self.config.window_height = Some(geometry.0.y);
+        self.config.window_x = Some(geometry.1.x);
+        self.config.window_y = Some(geometry.1.y);
+        let _ = self.config.save();
+    }
+
+    /// Append a WIP marker to the window title while I have issues in progress
+    fn update_window_title(&mut self, ctx: &egui::Context) {
+        let wip_count = self
+            .config
+            .current_user
+            .as_deref()
+            .map_or(0, |user| count_my_wip(&self.issues, user));
+        let title = if wip_count > 0 {
+            format!("{} ● WIP", WINDOW_TITLE)
+        } else {
+            WINDOW_TITLE.to_string()
+        };
+        if title != self.window_title {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Title(title.clone()));
+            self.window_title = title;
+        }
+    }
+
+    fn get_blockers_count(&mut self, issue_id: &str) -> usize {
+        // Get full issue to count active blockers (dependencies that are not closed)
+        if let Ok(full_issue) = self.snapshot_cache.get_issue_ref(issue_id) {
+            full_issue
+                .dependencies
+                .iter()
+                .filter(|dep| dep.status != "closed")
+                .count()
+        } else {
+            0
+        }
+    }
+
+    fn get_dependents_count(&self, issue_id: &str) -> usize {
+        self.dependents_map
+            .get(issue_id)
+            .map(|v| v.len())
+            .unwrap_or(0)
+    }
+
+    fn get_impact_count(&self, issue_id: &str) -> usize {
+        self.impact_counts.get(issue_id).copied().unwrap_or(0)
+    }
+
+    fn get_readiness(&mut self, issue: &Issue) -> String {
+        // Compute readiness based on status and blockers
+        match issue.status.as_str() {
+            "closed" => "closed".to_string(),
+            "in_progress" => "in_progress".to_string(),
+            _ => {
+                // For open issues, check if they're blocked
+                let blockers_count = self.get_blockers_count(&issue.id);
+                if blockers_count > 0 {
+                    "blocked".to_string()
+                } else if is_stale_open(issue, &self.config) {
+                    "stale_open".to_string()
+                } else {
+                    "ready".to_string()
+                }
+            }
+        }
+    }
+
+    /// Switch to the sort last used in the current view mode, unless the user
+    /// already picked one this session
+    fn apply_view_mode_sort(&mut self, now: f64) {
+        if self.user_modified_sort {
+            return;
+        }
+        let defaults = &self.config.view_mode_sort_defaults;
+        if let Some(&(column, ascending)) = defaults.get(self.view_mode.name()) {
+            if self.sort_keys != [(column, ascending)] {
+                self.sort_keys = vec![(column, ascending)];
+                self.resort_highlight_until = now + RESORT_HIGHLIGHT_SECS;
+            }
+        }
+    }
+
+    fn get_column_value(&mut self, issue: &Issue, column: SortColumn) -> String {
+        match column {
+            SortColumn::Id => issue.id.clone(),
+            SortColumn::Directory => self.config.directory_name(issue).to_string(),
+            SortColumn::Title => issue.title.clone(),
+            SortColumn::Status => self.get_readiness(issue),
+            SortColumn::Priority => format!("P{}", issue.priority),
+            SortColumn::Type => issue.issue_type.clone(),
+            SortColumn::Assignee => issue.assignee.clone().unwrap_or_else(|| "-".to_string()),
+            SortColumn::CreatedBy => issue.created_by.clone().unwrap_or_else(|| "-".to_string()),
+            SortColumn::Blockers => self.get_blockers_count(&issue.id).to_string(),
+            SortColumn::Dependents => self.get_dependents_count(&issue.id).to_string(),
+            SortColumn::TransitiveImpact => self.get_impact_count(&issue.id).to_string(),
+            SortColumn::Notes => notes_filter_value(self.get_notes_len(issue)).to_string(),
+            SortColumn::DueDate => format_due_date(issue),
+            SortColumn::UpdatedAt => format_updated_at(issue),
+        }
+    }
+
+    /// Write the filtered, sorted issue list to a CSV file picked by the user
+    fn export_csv(&mut self) {
+        let Some(path) = pick_export_path("issues.csv", "CSV", "csv") else {
+            return;
+        };
+        let columns = CSV_EXPORT_COLUMNS.map(|column| {
+            let name = TABLE_COLUMNS
+                .iter()
+                .find(|(c, _)| *c == column)
+                .map_or("", |(_, name)| *name);
+            (column, name)
+        });
+        self.ensure_dependents_map();
+        let filtered = self.filtered_and_sorted_issues();
+        let headers: Vec<&str> = columns.iter().map(|(_, name)| *name).collect();
+        let rows: Vec<Vec<String>> = filtered
+            .iter()
+            .map(|display| {
+                columns
+                    .iter()
+                    .map(|(column, _)| self.get_column_value(&display.issue, *column))
+                    .collect()
+            })
+            .collect();
+        if let Err(e) = fs::write(&path, export::to_csv(&headers, &rows)) {
+            self.error_message = Some(format!("Failed to export {}: {}", path.display(), e));
+        }
+    }
+
+    /// Write the filtered issues, as loaded and in display order, to a JSON file picked by
+    /// the user; source_directory keeps multi-directory exports attributable
+    fn export_json(&mut self) {
+        let Some(path) = pick_export_path("issues.json", "JSON", "json") else {
+            return;
+        };
+        let issues: Vec<&Issue> = self
+            .filtered_and_sorted_issues()
+            .iter()
+            .filter_map(|display| self.issues.get(display.original_idx))
+            .collect();
+        let result = serde_json::to_string_pretty(&issues)
+            .map_err(|e| e.to_string())
+            .and_then(|json| fs::write(&path, json).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            self.error_message = Some(format!("Failed to export {}: {}", path.display(), e));
+        }
+    }
+
+    /// Notes length from the full issue when it can be loaded, since the list output
+    /// may leave notes out
+    fn get_notes_len(&mut self, issue: &Issue) -> Option<usize> {
+        let notes = match self.snapshot_cache.get_issue_ref(&issue.id) {
+            Ok(full) => full.notes.as_deref(),
+            Err(_) => issue.notes.as_deref(),
+        };
+        notes.filter(|n| !n.is_empty()).map(|n| n.chars().count())
+    }
+
+    fn get_column_cardinality(&mut self, column: SortColumn) -> usize {
+        self.column_values(column).len()
+    }
+
+    /// Distinct values of a column across all loaded issues, sorted
+    fn column_values(&mut self, column: SortColumn) -> Vec<String> {
+        let mut unique_values = HashSet::new();
+        for issue in &self.issues.clone() {
+            unique_values.insert(self.get_column_value(issue, column));
+        }
+        let mut values: Vec<String> = unique_values.into_iter().collect();
+        values.sort();
+        values
+    }
+
+    fn apply_filter_action(&mut self, column: SortColumn, action: FilterAction) {
+        match action {
+            FilterAction::Toggle(value) => {
+                self.column_filters
+                    .entry(column)
+                    .or_default()
+                    .toggle_exclude(value);
+            }
+            FilterAction::ToggleInclude(value) => {
+                self.column_filters
+                    .entry(column)
+                    .or_default()
+                    .toggle_include(value);
+            }
+            FilterAction::ClearAll => {
+                self.column_filters.remove(&column);
+            }
+            FilterAction::SetOnly(value) => {
+                let others = self
+                    .column_values(column)
+                    .into_iter()
+                    .filter(|other| *other != value)
+                    .collect();
+                self.column_filters
+                    .insert(column, ColumnFilter::new_with_excluded(others));
+            }
+        }
+    }
+
+    fn filtered_and_sorted_issues(&mut self) -> Vec<IssueDisplay> {
+        // Dependents and Impact are the only table columns derived from dependents_map
+        let needs_dependents = [SortColumn::Dependents, SortColumn::TransitiveImpact]
+            .iter()
+            .any(|column| {
+                *self.column_visibility.get(column).unwrap_or(&true)
+                    || self.sort_keys.iter().any(|(key, _)| key == column)
+                    || self.column_filters.contains_key(column)
+            });
+        if needs_dependents {
+            self.ensure_dependents_map();
+        }
+        let search = SearchQuery::parse(&self.filter_text);
+        let horizon_cutoff = self
+            .config
+            .time_horizon_days
+            .map(|days| chrono::Utc::now() - chrono::Duration::days(days as i64));
+
+        // Clone issues before iterating to avoid borrow checker issues
+        let issues_clone = self.issues.clone();
+
+        // Pre-compute values that require cache access and clone issues
+        let mut filtered: Vec<IssueDisplay> = issues_clone
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, issue)| {
+                // Issues with an unreadable timestamp stay visible
+                let updated_at = parse_timestamp(&issue.updated_at);
+                if let (Some(cutoff), Some(updated)) = (horizon_cutoff, updated_at) {
+                    if updated < cutoff {
+                        return None;
+                    }
+                }
+
+                if self.my_issues_filter && !is_my_issue(issue, &self.config) {
+                    return None;
+                }
+
+                if let Some(week) = self.week_filter {
+                    if !week.matches(&issue.created_at) && !week.matches(&issue.updated_at) {
+                        return None;
+                    }
+                }
+
+                let (min_priority, max_priority) = self.priority_range;
+                if !(min_priority..=max_priority).contains(&issue.priority) {
+                    return None;
+                }
+
+                // Pre-compute values needed for filtering and sorting
+                let readiness = self.get_readiness(issue);
+                let blockers_count = self.get_blockers_count(&issue.id);
+                let dependents_count = self.get_dependents_count(&issue.id);
+                let impact_count = self.get_impact_count(&issue.id);
+                let notes_len = self.get_notes_len(issue);
+                // Comments only come with the full issue
+                let unresolved_comments = self
+                    .snapshot_cache
+                    .get_issue_ref(&issue.id)
+                    .map(unresolved_comment_count)
+                    .unwrap_or(0);
+
+                // Apply text search filter - search through all visible fields including computed ones
+                if let Some(search) = &search {
+                    let values = search_values(issue, &readiness, blockers_count, dependents_count);
+                    if !search.matches(&values) {
+                        return None;
+                    }
+                }
+
+                // Apply column filters
+                for (column, column_filter) in &self.column_filters {
+                    let value = match column {
+                        SortColumn::Id => issue.id.clone(),
+                        SortColumn::Directory => self.config.directory_name(issue).to_string(),
+                        SortColumn::Title => issue.title.clone(),
+                        SortColumn::Status => readiness.clone(),
+                        SortColumn::Priority => format!("P{}", issue.priority),
+                        SortColumn::Type => issue.issue_type.clone(),
+                        SortColumn::Assignee => {
+                            issue.assignee.clone().unwrap_or_else(|| "-".to_string())
+                        }
+                        SortColumn::CreatedBy => {
+                            issue.created_by.clone().unwrap_or_else(|| "-".to_string())
+                        }
+                        SortColumn::Blockers => blockers_count.to_string(),
+                        SortColumn::Dependents => dependents_count.to_string(),
+                        SortColumn::TransitiveImpact => impact_count.to_string(),
+                        SortColumn::Notes => notes_filter_value(notes_len).to_string(),
+                        SortColumn::DueDate => format_due_date(issue),
+                        SortColumn::UpdatedAt => format_updated_at(issue),
+                    };
+                    if column_filter.is_filtered(&value) {
+                        return None;
+                    }
+                }
+
+                Some(IssueDisplay {
+                    original_idx: idx,
+                    issue: issue.clone(),
+                    readiness,
+                    blockers_count,
+                    dependents_count,
+                    impact_count,
+                    notes_len,
+                    updated_at,
+                    unresolved_comments,
+                })
+            })
+            .collect();
+
+        let config = &self.config;
+        let compare_column = |a: &IssueDisplay, b: &IssueDisplay, column: SortColumn| {
+            match column {
+                SortColumn::Id => a.issue.id.cmp(&b.issue.id),
+                SortColumn::Directory => config
+                    .directory_name(&a.issue)
+                    .cmp(config.directory_name(&b.issue)),
+                SortColumn::Title => a.issue.title.cmp(&b.issue.title),
+                SortColumn::Status => a.readiness.cmp(&b.readiness),
+                SortColumn::Priority => a.issue.priority.cmp(&b.issue.priority),
+                SortColumn::Type => a.issue.issue_type.cmp(&b.issue.issue_type),
+                SortColumn::Assignee => a
+                    .issue
+                    .assignee
+                    .as_ref()
+                    .unwrap_or(&String::new())
+                    .cmp(b.issue.assignee.as_ref().unwrap_or(&String::new())),
+                SortColumn::CreatedBy => a
+                    .issue
+                    .created_by
+                    .as_ref()
+                    .unwrap_or(&String::new())
+                    .cmp(b.issue.created_by.as_ref().unwrap_or(&String::new())),
+                SortColumn::Blockers => a.blockers_count.cmp(&b.blockers_count),
+                SortColumn::Dependents => a.dependents_count.cmp(&b.dependents_count),
+                SortColumn::TransitiveImpact => a.impact_count.cmp(&b.impact_count),
+                // Issues without notes first, then by length
+                SortColumn::Notes => a.notes_len.cmp(&b.notes_len),
+                SortColumn::DueDate => {
+                    // Issues without a due date sort after those with one
+                    let a_due = a.issue.due_date.as_deref().and_then(parse_due_date);
+                    let b_due = b.issue.due_date.as_deref().and_then(parse_due_date);
+                    a_due
+                        .is_none()
+                        .cmp(&b_due.is_none())
+                        .then(a_due.cmp(&b_due))
+                }
+                SortColumn::UpdatedAt => a.updated_at.cmp(&b.updated_at),
+            }
+        };
+        let compare = |a: &IssueDisplay, b: &IssueDisplay| {
+            // First sort key that tells the two apart decides
+            let cmp = self
+                .sort_keys
+                .iter()
+                .map(|&(column, ascending)| {
+                    let cmp = compare_column(a, b, column);
+                    if ascending {
+                        cmp
+                    } else {
+                        cmp.reverse()
+                    }
+                })
+                .find(|cmp| cmp.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal);
+            // Most recently updated first among otherwise equal issues
+            cmp.then_with(|| b.updated_at.cmp(&a.updated_at))
+        };
+        if self.config.sort_stable {
+            // Remaining ties keep load order so rows don't jump around between refreshes
+            filtered.sort_by(|a, b| compare(a, b).then(a.original_idx.cmp(&b.original_idx)));
+        } else {
+            filtered.sort_unstable_by(compare);
+        }
+
+        filtered
+    }
+
+    fn show_sidebar(&mut self, ctx: &egui::Context) {
+        let mut config_changed = false;
+        let mut add_directory_clicked = std::mem::take(&mut self.add_directory_requested);
+        let mut refresh_directory = None;
+        let mut remove_directory = None;
+        // Removing the only directory would leave nothing to show
+        let can_remove_directory = self.config.directories.len() > 1;
+
+        let sidebar_panel = match self.config.sidebar_position {
+            SidebarPosition::Left => egui::SidePanel::left("directories_sidebar"),
+            SidebarPosition::Right => egui::SidePanel::right("directories_sidebar"),
+        };
+        let sidebar_response = sidebar_panel
+            .resizable(true)
+            .default_width(200.0)
+            .show_animated(ctx, !self.config.sidebar_collapsed, |ui| {
+                ui.heading("Directories");
+                ui.separator();
+
+                // Show list of directories with checkboxes
+                for (idx, dir) in self.config.directories.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        let mut visible = dir.visible;
+                        if ui.checkbox(&mut visible, &dir.display_name).changed() {
+                            dir.visible = visible;
+                            config_changed = true;
+                        }
+                        if let Some(error) = self.directory_errors.get(&dir.source_name()) {
+                            ui.colored_label(self.theme_tokens.error, "⚠")
+                                .on_hover_text(format!("Could not load issues: {}", error.trim()));
+                        }
+                        if dir.visible
+                            && ui
+                                .small_button("↺")
+                                .on_hover_text("Refresh this directory only")
+                                .clicked()
+                        {
+                            refresh_directory = Some(idx);
+                        }
+                        // Entries expanded from a glob come back on the next launch
+                        if !dir.is_glob_child
+                            && ui
+                                .add_enabled(can_remove_directory, egui::Button::new("✕").small())
+                                .on_hover_text("Stop monitoring this directory")
+                                .on_disabled_hover_text("At least one directory is required")
+                                .clicked()
+                        {
+                            remove_directory = Some(idx);
+                        }
+                    });
+                }
+
+                ui.separator();
+
+                // Add directory button
+                if ui.button("+ Add Directory…").clicked() {
+                    add_directory_clicked = true;
+                }
+                if let Some(error) = &self.add_directory_error {
+                    ui.colored_label(self.theme_tokens.error, error);
+                }
+
+                ui.separator();
+
+                // Health overview of the combined visible issues
+                let stats = self.summary_stats;
+                ui.label(egui::RichText::new("By priority").small());
+                let priority_entries: Vec<(&str, usize)> = ["P0", "P1", "P2", "P3", "P4"]
+                    .into_iter()
+                    .zip(stats.by_priority)
+                    .collect();
+                draw_count_chart(ui, "sidebar_priority_chart", &priority_entries);
+                ui.label(egui::RichText::new("By readiness").small());
+                draw_count_chart(
+                    ui,
+                    "sidebar_readiness_chart",
+                    &[
+                        ("ready", stats.ready()),
+                        ("blocked", stats.blocked),
+                        ("in_progress", stats.in_progress),
+                        ("closed", stats.closed),
+                    ],
+                );
+            });
+        self.tour_anchors.sidebar = sidebar_response.map(|r| r.response.rect);
+
+        // Handle add directory button click
+        if add_directory_clicked {
+            if let Some(folder) = rfd::FileDialog::new().pick_folder() {
+                self.add_directory_error = None;
+                // Validate that the directory contains .beads/ subdirectory
+                let mut beads_path = folder.clone();
+                beads_path.push(".beads");
+
+                if beads_path.exists() && beads_path.is_dir() {
+                    // Check if this directory is not already in the config
+                    let already_exists = self.config.directories.iter().any(|d| d.path == folder);
+
+                    if !already_exists {
+                        // Add the directory to config
+                        self.config.directories.push(DirectoryConfig {
+                            path: folder,
+                            visible: true,
+                            display_name: String::new(), // Will be computed
+                            custom_display_name: false,
+                            is_glob: false,
+                            is_glob_child: false,
+                        });
+
+                        // Compute display names
+                        self.config.compute_display_names();
+
+                        config_changed = true;
+                    } else {
+                        self.add_directory_error = Some("Directory already added".to_string());
+                    }
+                } else {
+                    self.add_directory_error = Some(
+                        "Selected directory does not contain a .beads/ subdirectory".to_string(),
+                    );
+                }
+            }
+        }
+
+        if let Some(idx) = remove_directory {
+            let removed = self.config.directories.remove(idx);
+            let selected_in_removed = self
+                .selected_index
+                .and_then(|idx| self.issues.get(idx))
+                .is_some_and(|issue| issue.source_path == removed.path);
+            if selected_in_removed {
+                self.selected_index = None;
+                self.current_issue = None;
+                self.edit_modified = false;
+            }
+            self.directory_errors.remove(&removed.source_name());
+            // Indices after the removed one shifted down
+            self.create_directory_index = self
+                .config
+                .directories
+                .iter()
+                .position(|d| d.visible)
+                .unwrap_or(0);
+            self.config.compute_display_names();
+            config_changed = true;
+        }
+
+        // Save config if anything changed
+        if config_changed {
+            let _ = self.config.save();
+            // Refresh to reload issues with new visibility settings
+            self.refresh();
+        } else if let Some(idx) = refresh_directory {
+            self.refresh_directory(idx);
+        }
+    }
+
+    fn show_list_view(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // Show sidebar first (so it's on the left)
+        self.show_sidebar(ctx);
+
+        // The visible rows, shared by the next-action chip and the table; dropped
+        // whenever something below changes the filters
+        let mut filtered: Option<Vec<IssueDisplay>> = None;
+
+        // Header panel
+        egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
+            // Add extra vertical spacing for better visual padding
+            ui.add_space(2.0);
+
+            ui.horizontal(|ui| {
+                // Sidebar toggle button - the arrow points the way the panel will move
+                let sidebar_button_text =
+                    match (self.config.sidebar_position, self.config.sidebar_collapsed) {
+                        (SidebarPosition::Left, true) | (SidebarPosition::Right, false) => "▶",
+                        (SidebarPosition::Left, false) | (SidebarPosition::Right, true) => "◀",
+                    };
+                if ui.button(sidebar_button_text).clicked() {
+                    self.config.sidebar_collapsed = !self.config.sidebar_collapsed;
+                    let _ = self.config.save();
+                    self.refresh();
+                }
+
+                let mut refresh_response = ui.button("Refresh");
+                if let Some(version) = &self.bd_version {
+                    refresh_response = refresh_response.on_hover_text(version);
+                }
+                self.tour_anchors.refresh = Some(refresh_response.rect);
+                if refresh_response.clicked() {
+                    self.refresh();
+                }
+                if self.loading {
+                    ui.spinner().on_hover_text("Loading issues…");
+                }
+                ui.separator();
+                let create_response = ui.button("+ Create Issue");
+                self.tour_anchors.create = Some(create_response.rect);
+                if create_response.clicked() {
+                    self.show_create_dialog = true;
+                }
+                if ui
+                    .button("Export CSV")
+                    .on_hover_text("Save the issues as currently filtered and sorted")
+                    .clicked()
+                {
+                    self.export_csv();
+                }
+                if ui
+                    .button("Export JSON")
+                    .on_hover_text("Save the filtered issues in full, in the current order")
+                    .clicked()
+                {
+                    self.export_json();
+                }
+                if ui.button("⚙ Settings").clicked() {
+                    self.show_settings = !self.show_settings;
+                }
+                if ui.button("📊 Stats").clicked() {
+                    self.show_stats = !self.show_stats;
+                }
+                ui.separator();
+                let previous_view_mode = self.view_mode;
+                ui.selectable_value(&mut self.view_mode, ViewMode::List, "☰ List");
+                ui.selectable_value(&mut self.view_mode, ViewMode::Swimlane, "Swimlanes");
+                ui.selectable_value(&mut self.view_mode, ViewMode::Matrix, "Matrix");
+                ui.selectable_value(&mut self.view_mode, ViewMode::SprintBoard, "Sprint Board");
+                ui.selectable_value(&mut self.view_mode, ViewMode::Board, "Board");
+                if self.view_mode != previous_view_mode {
+                    self.apply_view_mode_sort(ui.input(|i| i.time));
+                }
+
+                ui.separator();
+                ui.label("Last:");
+                for (days, label) in [(Some(7), "7d"), (Some(30), "30d"), (None, "All")] {
+                    if ui
+                        .selectable_label(self.config.time_horizon_days == days, label)
+                        .clicked()
+                    {
+                        self.config.time_horizon_days = days;
+                        let _ = self.config.save();
+                    }
+                }
+
+                ui.separator();
+                if self.config.current_user.is_some() {
+                    ui.toggle_value(&mut self.my_issues_filter, "My Issues");
+                }
+                for (week, label) in [
+                    (WeekFilter::ThisWeek, "This Week"),
+                    (WeekFilter::LastWeek, "Last Week"),
+                ] {
+                    let active = self.week_filter == Some(week);
+                    let text = if active {
+                        egui::RichText::new(label).strong()
+                    } else {
+                        egui::RichText::new(label)
+                    };
+                    if ui
+                        .selectable_label(active, text)
+                        .on_hover_text("Issues created or updated in that ISO week (Mon–Sun)")
+                        .clicked()
+                    {
+                        self.week_filter = if active { None } else { Some(week) };
+                    }
+                }
+
+                ui.separator();
+                self.show_priority_range_filter(ui);
+
+                let wip_count = self
+                    .config
+                    .current_user
+                    .as_deref()
+                    .map_or(0, |user| count_my_wip(&self.issues, user));
+                if wip_count > 0 {
+                    ui.separator();
+                    // Alternate colors once a second to draw the eye
+                    let time = ui.input(|i| i.time);
+                    let color = if time as i64 % 2 == 0 {
+                        self.theme_tokens.in_progress
+                    } else {
+                        self.theme_tokens.warning
+                    };
+                    let badge = egui::Button::new(
+                        egui::RichText::new(format!("● WIP ({})", wip_count))
+                            .color(color)
+                            .strong(),
+                    )
+                    .frame(false);
+                    if ui
+                        .add(badge)
+                        .on_hover_text("Show my issues, most recently updated first")
+                        .clicked()
+                    {
+                        self.my_issues_filter = true;
+                        self.sort_keys = vec![(SortColumn::UpdatedAt, false)];
+                        self.user_modified_sort = true;
+                    }
+                    let until_toggle = std::time::Duration::from_secs_f64(1.0 - time.fract());
+                    ui.ctx().request_repaint_after(until_toggle);
+                }
+
+                if !self.newly_ready.is_empty() {
+                    ui.separator();
+                    if ui
+                        .button(format!("Mark All Newly Ready ({})", self.newly_ready.len()))
+                        .on_hover_text(
+                            "Move open issues whose blockers are all closed to in_progress",
+                        )
+                        .clicked()
+                    {
+                        self.pending_mark_ready = Some(self.newly_ready.clone());
+                    }
+                }
+
+                if !self.multi_selected.is_empty() {
+                    ui.separator();
+                    if ui
+                        .button(format!("Bulk Update ({})", self.multi_selected.len()))
+                        .on_hover_text("Ctrl/Cmd+click rows to change the selection")
+                        .clicked()
+                    {
+                        self.show_bulk_dialog = true;
+                    }
+                    if ui
+                        .small_button("✕")
+                        .on_hover_text("Clear selection")
+                        .clicked()
+                    {
+                        self.multi_selected.clear();
+                        self.show_bulk_dialog = false;
+                    }
+                }
+
+                // Add filter on the right side of the same line
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let filter_response = ui
+                        .text_edit_singleline(&mut self.filter_text)
+                        .on_hover_text(
+                            "Matches any field. Prefix words with a field to narrow them \
+                             (type:bug status:ready assignee:alice priority:1), \
+                             or start with / for a regex",
+                        );
+                    self.tour_anchors.filter = Some(filter_response.rect);
+                    if filter_response.changed() {
+                        let pasted = ui
+                            .input(|i| i.events.iter().any(|e| matches!(e, egui::Event::Paste(_))));
+                        self.pending_paste_navigation = if pasted {
+                            Some(ui.input(|i| i.time))
+                        } else {
+                            None
+                        };
+                    }
+                    ui.label("Filter:");
+
+                    ui.menu_button("Presets", |ui| {
+                        let mut apply_preset = None;
+                        for (name, filters) in &self.config.filter_presets {
+                            if ui.button(name).clicked() {
+                                apply_preset = Some(filters.clone());
+                                ui.close_menu();
+                            }
+                        }
+                        if let Some(filters) = apply_preset {
+                            self.column_filters = filters;
+                        }
+                        if !self.config.filter_presets.is_empty() {
+                            ui.separator();
+                        }
+
+                        ui.horizontal(|ui| {
+                            ui.add(
+                                egui::TextEdit::singleline(&mut self.preset_name_input)
+                                    .hint_text("preset name")
+                                    .desired_width(120.0),
+                            );
+                            let name = self.preset_name_input.trim().to_string();
+                            if ui
+                                .add_enabled(!name.is_empty(), egui::Button::new("Save"))
+                                .on_hover_text("Save the current column filters under this name")
+                                .clicked()
+                            {
+                                self.config
+                                    .filter_presets
+                                    .insert(name, self.column_filters.clone());
+                                self.preset_name_input.clear();
+                                let _ = self.config.save();
+                            }
+                        });
+                        if ui.button("Use Current Filters as Default").clicked() {
+                            self.config.default_filters = Some(self.column_filters.clone());
+                            let _ = self.config.save();
+                            ui.close_menu();
+                        }
+                        if ui.button("Reset to Default Filters").clicked() {
+                            self.column_filters = self.config.default_column_filters();
+                            ui.close_menu();
+                        }
+                    });
+
+                    // Columns visibility menu
+                    let columns_menu = ui.menu_button("Columns", |ui| {
+                        let mut toggle_column = None;
+
+                        for (column, name) in TABLE_COLUMNS {
+                            let is_visible = self.column_visibility.get(&column).copied().unwrap_or(true);
+                            let mut visible = is_visible;
+
+                            if ui.checkbox(&mut visible, name).changed() {
+                                toggle_column = Some(column);
+                            }
+                        }
+
+                        // Apply toggle after iteration
+                        if let Some(col) = toggle_column {
+                            let current = self.column_visibility.get(&col).copied().unwrap_or(true);
+                            // Don't allow hiding the last visible column
+                            let visible_count = self.column_visibility.values().filter(|&&v| v).count();
+                            if current && visible_count > 1 {
+                                self.column_visibility.insert(col, false);
+                            } else if !current {
+                                self.column_visibility.insert(col, true);
+                            }
+                        }
+                    });
+                    self.tour_anchors.columns = Some(columns_menu.response.rect);
+                });
+            });
+
+            // Summary line with a small completion bar
+            let stats = self.summary_stats;
+            ui.horizontal(|ui| {
+                let closed_fraction = if stats.total > 0 {
+                    stats.closed as f32 / stats.total as f32
+                } else {
+                    0.0
+                };
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} total issues • {} closed ({:.0}%) • {} in progress • {} blocked",
+                        stats.total,
+                        stats.closed,
+                        closed_fraction * 100.0,
+                        stats.in_progress,
+                        stats.blocked
+                    ))
+                    .small(),
+                );
+
+                let (bar_rect, _) =
+                    ui.allocate_exact_size(egui::vec2(120.0, 6.0), egui::Sense::hover());
+                let painter = ui.painter();
+                painter.rect_filled(bar_rect, 3.0, ui.visuals().extreme_bg_color);
+                let mut filled_rect = bar_rect;
+                filled_rect.set_width(bar_rect.width() * closed_fraction);
+                painter.rect_filled(filled_rect, 3.0, ui.visuals().selection.bg_fill);
+
+                let visible = filtered.get_or_insert_with(|| self.filtered_and_sorted_issues());
+                if let Some(next) = find_next_action(visible) {
+                    ui.separator();
+                    let chip = egui::Button::new(
+                        egui::RichText::new(format!(
+                            "▶ Next: {} (P{}) {}",
+                            next.issue.id, next.issue.priority, next.issue.title
+                        ))
+                        .small(),
+                    )
+                    .rounding(8.0);
+                    if ui
+                        .add(chip)
+                        .on_hover_text("Highest-priority ready issue")
+                        .clicked()
+                    {
+                        self.request_selection(Some(next.original_idx));
+                        self.scroll_to_selected = true;
+                    }
+                }
+
+                if stats.stale_open > 0 {
+                    ui.separator();
+                    if ui
+                        .small_button("Triage Stale")
+                        .on_hover_text("Show only open issues with no recent updates")
+                        .clicked()
+                    {
+                        self.column_filters.clear();
+                        self.apply_filter_action(
+                            SortColumn::Status,
+                            FilterAction::SetOnly("stale_open".to_string()),
+                        );
+                        filtered = None;
+                    }
+                }
+            });
+
+            if let Some(ref error) = self.error_message {
+                ui.colored_label(self.theme_tokens.error, error);
+            }
+
+            // Add extra vertical spacing at bottom for symmetry
+            ui.add_space(2.0);
+        });
+
+        self.show_health_banner(ctx);
+        if self.apply_paste_navigation(ctx) {
+            filtered = None;
+        }
+        let filtered = filtered.unwrap_or_else(|| self.filtered_and_sorted_issues());
+
+        let mut new_sort_by = None;
+        let mut new_selected = None;
+        let mut new_hovered_row = None;
+        let mut filter_action: Option<(SortColumn, FilterAction)> = None;
+        // Replaces every column filter with the result of this action
+        let mut exclusive_filter: Option<(SortColumn, FilterAction)> = None;
+        let mut hide_column_request: Option<SortColumn> = None;
+
+        // Use CentralPanel for the resizable split view
+        let central_response = egui::CentralPanel::default().show(ctx, |ui| {
+            if !self.bd_available {
+                return self.show_bd_missing(ui);
+            }
+            match self.view_mode {
+                ViewMode::List => {}
+                ViewMode::Swimlane => return self.show_swimlane_view(ui),
+                ViewMode::Matrix => return self.show_matrix_view(ui),
+                ViewMode::SprintBoard => return self.show_sprint_board_view(ui),
+                ViewMode::Board => return self.show_board_view(ui),
+            }
+
+            let available_height = ui.available_height();
+
+            // Only show split if an issue is selected
+            if self.selected_index.is_some() {
+                // Calculate list height based on split ratio (min 150px, max available - 150px)
+                let min_panel_height = 150.0;
+                let list_height = (available_height * self.split_ratio)
+                    .max(min_panel_height)
+                    .min(available_height - min_panel_height);
+
+                // Separator/divider (draggable)
+                let separator_height = 12.0;
+
+                // List panel - clip it to end before the separator
+                let list_rect = egui::Rect::from_min_size(
+                    ui.cursor().min,
+                    egui::vec2(ui.available_width(), list_height),
+                );
+                // Create a tighter clip rect that stops before the separator
+                let list_clip_rect = egui::Rect::from_min_size(
+                    ui.cursor().min,
+                    egui::vec2(ui.available_width(), list_height - separator_height),
+                );
+                let mut list_ui = ui.new_child(
+                    egui::UiBuilder::new()
+                        .max_rect(list_rect)
+                        .layout(egui::Layout::top_down(egui::Align::LEFT)),
+                );
+                list_ui.set_clip_rect(list_clip_rect);
+
+                self.show_list_table(
+                    &mut list_ui,
+                    &filtered,
+                    &mut new_sort_by,
+                    &mut new_selected,
+                    &mut new_hovered_row,
+                    &mut filter_action,
+                    &mut exclusive_filter,
+                    &mut hide_column_request,
+                    Some(list_height - separator_height),
+                );
+                let separator_rect = egui::Rect::from_min_size(
+                    egui::pos2(list_rect.min.x, list_rect.max.y),
+                    egui::vec2(ui.available_width(), separator_height),
+                );
+
+                let separator_id = ui.id().with("split_separator");
+                let separator_response =
+                    ui.interact(separator_rect, separator_id, egui::Sense::drag());
+
+                // Draw separator with vertical padding
+                let separator_color =
+                    if separator_response.hovered() || separator_response.dragged() {
+                        ui.visuals().widgets.active.bg_fill
+                    } else {
+                        ui.visuals().widgets.inactive.bg_fill
+                    };
+                let top_padding = 2.0;
+                let _bottom_padding = 6.0;
+                let visual_height = 3.0; // Thin visible line
+
+                let visual_rect = egui::Rect::from_min_size(
+                    egui::pos2(separator_rect.min.x, separator_rect.min.y + top_padding),
+                    egui::vec2(separator_rect.width(), visual_height),
+                );
+                ui.painter().rect_filled(visual_rect, 0.0, separator_color);
+
+                // Change cursor on hover
+                if separator_response.hovered() {
+                    ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeVertical);
+                }
+
+                // Handle dragging
+                if separator_response.dragged() {
+                    if let Some(pointer_pos) = ui.ctx().pointer_latest_pos() {
+                        let new_list_height = pointer_pos.y - list_rect.min.y;
+                        self.split_ratio = (new_list_height / available_height)
+                            .max(min_panel_height / available_height)
+                            .min((available_height - min_panel_height) / available_height);
+                    }
+                }
+
+                // Detail panel
+                let detail_rect = egui::Rect::from_min_size(
+                    egui::pos2(list_rect.min.x, separator_rect.max.y),
+                    egui::vec2(
+                        ui.available_width(),
+                        available_height - list_height - separator_height,
+                    ),
+                );
+                let mut detail_ui = ui.new_child(
+                    egui::UiBuilder::new()
+                        .max_rect(detail_rect)
+                        .layout(egui::Layout::top_down(egui::Align::LEFT)),
+                );
+                detail_ui.set_clip_rect(detail_rect);
+
+                if let Some(idx) = self.selected_index {
+                    if let Some(issue) = self.issues.get(idx) {
+                        let issue_id = issue.id.clone();
+                        self.show_detail_view_split(ctx, &mut detail_ui, &issue_id);
+                    }
+                }
+            } else {
+                // No issue selected - show list only
+                self.show_list_table(
+                    ui,
+                    &filtered,
+                    &mut new_sort_by,
+                    &mut new_selected,
+                    &mut new_hovered_row,
+                    &mut filter_action,
+                    &mut exclusive_filter,
+                    &mut hide_column_request,
+                    None,
+                );
+            }
+        });
+
+        self.tour_anchors.table = Some(central_response.response.rect);
+
+        // Apply changes after borrowing ends
+        if let Some(sort_col) = new_sort_by {
+            self.user_modified_sort = true;
+            let add_key = ctx.input(|i| i.modifiers.shift);
+            self.click_sort_column(sort_col, add_key);
+            // View defaults only remember the primary key
+            self.config
+                .view_mode_sort_defaults
+                .insert(self.view_mode.name().to_string(), self.sort_keys[0]);
+            let _ = self.config.save();
+        }
+
+        if let Some(selected) = new_selected {
+            let toggle_id = selected
+                .filter(|_| ctx.input(|i| i.modifiers.command))
+                .and_then(|idx| self.issues.get(idx))
+                .map(|issue| issue.id.clone());
+            if let Some(id) = toggle_id {
+                if !self.multi_selected.remove(&id) {
+                    self.multi_selected.insert(id);
+                }
+            } else {
+                self.request_selection(selected);
+                self.multi_selected.clear();
+            }
+        }
+
+        if let Some(hovered) = new_hovered_row {
+            self.hovered_row = hovered;
+        } else {
+            self.hovered_row = None;
+        }
+
+        // Apply filter change if requested
+        if let Some((column, action)) = filter_action {
+            self.apply_filter_action(column, action);
+        }
+
+        if let Some((column, action)) = exclusive_filter {
+            self.column_filters.clear();
+            self.apply_filter_action(column, action);
+        }
+
+        // Handle column hide request
+        if let Some(column) = hide_column_request {
+            let visible_count = self.column_visibility.values().filter(|&&v| v).count();
+            // Don't allow hiding the last visible column
+            if visible_count > 1 {
+                self.column_visibility.insert(column, false);
+            }
+        }
+
+        // Keyboard navigation moves through the visible rows in their current order,
+        // unless a text field has focus
+        let previous_selection = self.selected_index;
+        let step = if ctx.wants_keyboard_input() {
+            0
+        } else {
+            ctx.input(|i| {
+                let down = i.key_pressed(egui::Key::ArrowDown) as isize;
+                let up = i.key_pressed(egui::Key::ArrowUp) as isize;
+                down - up
+            })
+        };
+        if step != 0 {
+            let filtered = self.filtered_and_sorted_issues();
+            if let Some(idx) = step_selection(&filtered, self.selected_index, step) {
+                self.request_selection(Some(idx));
+            }
+        }
+
+        // Escape deselects, unless a text field or popup has it
+        if self.selected_index.is_some()
+            && !ctx.wants_keyboard_input()
+            && !ctx.memory(|m| m.any_popup_open())
+            && ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Escape))
+        {
+            self.request_selection(None);
+        }
+
+        // Ctrl+W closes the issue open in the detail panel, unless a text field has focus
+        if self.selected_index.is_some()
+            && !ctx.wants_keyboard_input()
+            && ctx.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::W))
+        {
+            self.close_selected_issue(ctx);
+        }
+
+        if self.selected_index != previous_selection {
+            // Bring the newly selected row into view on the next frame
+            self.scroll_to_selected = true;
+            ctx.request_repaint();
+        }
+    }
+
+    /// Change the selected row, first asking whether to discard unsaved detail edits
+    fn request_selection(&mut self, selected: Option<usize>) {
+        if self.edit_modified && selected != self.selected_index {
+            self.pending_selection = Some(selected);
+        } else {
+            self.selected_index = selected;
+        }
+    }
+
+    /// Navigate to an issue whose id was pasted into the filter box, once the
+    /// paste has settled for a short debounce period. Returns whether it did,
+    /// which also clears the filter text
+    fn apply_paste_navigation(&mut self, ctx: &egui::Context) -> bool {
+        const PASTE_DEBOUNCE_SECS: f64 = 0.2;
+
+        let Some(pasted_at) = self.pending_paste_navigation else {
+            return false;
+        };
+
+        let now = ctx.input(|i| i.time);
+        let elapsed = now - pasted_at;
+        if elapsed < PASTE_DEBOUNCE_SECS {
+            ctx.request_repaint_after(std::time::Duration::from_secs_f64(
+                PASTE_DEBOUNCE_SECS - elapsed,
+            ));
+            return false;
+        }
+        self.pending_paste_navigation = None;
+
+        let needle = self.filter_text.trim().to_string();
+        let filtered = self.filtered_and_sorted_issues();
+        let matches: Vec<usize> = filtered
+            .iter()
+            .filter(|d| d.issue.id == needle)
+            .map(|d| d.original_idx)
+            .collect();
+
+        if let [original_idx] = matches[..] {
+            self.request_selection(Some(original_idx));
+            self.filter_text.clear();
+            self.scroll_to_selected = true;
+            self.flash_row = Some((original_idx, now));
+            return true;
+        }
+        false
+    }
+
+    /// Header click: a plain click sorts by that column alone (flipping it when it is
+    /// already the primary key); shift-click adds it as the next key, or flips its
+    /// direction when it is already one
+    fn click_sort_column(&mut self, column: SortColumn, add_key: bool) {
+        let existing = self.sort_keys.iter().position(|(key, _)| *key == column);
+        match (add_key, existing) {
+            (true, Some(pos)) => self.sort_keys[pos].1 = !self.sort_keys[pos].1,
+            (true, None) => self.sort_keys.push((column, true)),
+            (false, Some(0)) => self.sort_keys = vec![(column, !self.sort_keys[0].1)],
+            (false, _) => self.sort_keys = vec![(column, true)],
+        }
+    }
+
+    /// Save the sort and column filters to config whenever they differ from what was saved
+    fn persist_view_state(&mut self) {
+        let unchanged = self.config.sort_keys == self.sort_keys
+            && self.config.column_filters.as_ref() == Some(&self.column_filters);
+        if unchanged {
+            return;
+        }
+        self.config.sort_keys = self.sort_keys.clone();
+        self.config.column_filters = Some(self.column_filters.clone());
+        let _ = self.config.save();
+    }
+
+    /// Drop the search text and every column, time and quick filter
+    fn clear_all_filters(&mut self) {
+        self.filter_text.clear();
+        self.column_filters.clear();
+        self.config.time_horizon_days = None;
+        self.week_filter = None;
+        self.priority_range = PRIORITY_RANGE_FULL;
+        self.my_issues_filter = false;
+        let _ = self.config.save();
+    }
+
+    /// Min and max priority combos; raising the min past the max (or the reverse)
+    /// drags the other bound along
+    fn show_priority_range_filter(&mut self, ui: &mut egui::Ui) {
+        let (mut min, mut max) = self.priority_range;
+        let (full_min, full_max) = PRIORITY_RANGE_FULL;
+        ui.label("Priority:");
+        egui::ComboBox::from_id_salt("priority_range_min")
+            .width(48.0)
+            .selected_text(format!("P{}", min))
+            .show_ui(ui, |ui| {
+                for p in full_min..=full_max {
+                    ui.selectable_value(&mut min, p, format!("P{}", p));
+                }
+            })
+            .response
+            .on_hover_text("Highest priority shown (min)");
+        ui.label("–");
+        egui::ComboBox::from_id_salt("priority_range_max")
+            .width(48.0)
+            .selected_text(format!("P{}", max))
+            .show_ui(ui, |ui| {
+                for p in full_min..=full_max {
+                    ui.selectable_value(&mut max, p, format!("P{}", p));
+                }
+            })
+            .response
+            .on_hover_text("Lowest priority shown (max)");
+
+        if min != self.priority_range.0 {
+            max = max.max(min);
+        } else if max != self.priority_range.1 {
+            min = min.min(max);
+        }
+        self.priority_range = (min, max);
+
+        if self.priority_range != PRIORITY_RANGE_FULL
+            && ui
+                .small_button("✕")
+                .on_hover_text("Show all priorities")
+                .clicked()
+        {
+            self.priority_range = PRIORITY_RANGE_FULL;
+        }
+    }
+
+    /// Shown in place of the table when there are no rows, telling apart an empty
+    /// database from filters that hide everything
+    fn show_empty_state(&mut self, ui: &mut egui::Ui) {
+        const EMPTY_ART: &str = "  .-------.\n  | o   o |\n  |   ~   |\n  '-------'";
+        ui.vertical_centered(|ui| {
+            ui.add_space((ui.available_height() / 4.0).max(12.0));
+            ui.label(egui::RichText::new(EMPTY_ART).monospace().weak());
+            ui.add_space(8.0);
+            if self.issues.is_empty() && self.loading {
+                ui.spinner();
+                ui.heading("Loading issues…");
+            } else if self.issues.is_empty() && !self.directory_errors.is_empty() {
+                ui.heading("Issues could not be loaded");
+                let mut errors: Vec<_> = self.directory_errors.iter().collect();
+                errors.sort();
+                for (source_name, error) in errors {
+                    ui.colored_label(
+                        self.theme_tokens.error,
+                        format!("{}: {}", source_name, error.trim()),
+                    );
+                }
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Retry").clicked() {
+                        self.refresh();
+                    }
+                    if ui.button("Open Settings").clicked() {
+                        self.show_settings = true;
+                    }
+                });
+            } else if self.issues.is_empty() {
+                ui.heading("No issues found");
+                ui.label("None of the monitored directories have any issues yet.");
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Create Your First Issue").clicked() {
+                        self.show_create_dialog = true;
+                    }
+                    if ui.button("Add a Directory").clicked() {
+                        self.add_directory_requested = true;
+                    }
+                });
+            } else {
+                ui.heading("No issues match your filter");
+                ui.add_space(8.0);
+                if ui.button("Clear Filters").clicked() {
+                    self.clear_all_filters();
+                }
+            }
+        });
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn show_list_table(
+        &mut self,
+        ui: &mut egui::Ui,
+        filtered: &[IssueDisplay],
+        new_sort_by: &mut Option<SortColumn>,
+        new_selected: &mut Option<Option<usize>>,
+        new_hovered_row: &mut Option<Option<usize>>,
+        filter_action: &mut Option<(SortColumn, FilterAction)>,
+        exclusive_filter: &mut Option<(SortColumn, FilterAction)>,
+        hide_column_request: &mut Option<SortColumn>,
+        max_height: Option<f32>,
+    ) {
+        if filtered.is_empty() {
+            self.show_empty_state(ui);
+            return;
+        }
+
+        // Pre-compute cardinalities to avoid borrow checker issues in context menus
+        let id_cardinality = self.get_column_cardinality(SortColumn::Id);
+        let directory_cardinality = self.get_column_cardinality(SortColumn::Directory);
+        let title_cardinality = self.get_column_cardinality(SortColumn::Title);
+        let status_cardinality = self.get_column_cardinality(SortColumn::Status);
+        let priority_cardinality = self.get_column_cardinality(SortColumn::Priority);
+        let type_cardinality = self.get_column_cardinality(SortColumn::Type);
+        let assignee_cardinality = self.get_column_cardinality(SortColumn::Assignee);
+        let created_by_cardinality = self.get_column_cardinality(SortColumn::CreatedBy);
+        let blockers_cardinality = self.get_column_cardinality(SortColumn::Blockers);
+        let dependents_cardinality = self.get_column_cardinality(SortColumn::Dependents);
+        let impact_cardinality = self.get_column_cardinality(SortColumn::TransitiveImpact);
+        let notes_cardinality = self.get_column_cardinality(SortColumn::Notes);
+        let due_date_cardinality = self.get_column_cardinality(SortColumn::DueDate);
+        let updated_cardinality = self.get_column_cardinality(SortColumn::UpdatedAt);
+        let mut due_date_change: Option<(usize, Option<chrono::NaiveDate>)> = None;
+        let mut label_color_change: Option<(usize, Option<egui::Color32>)> = None;
+        let mut terminal_path: Option<PathBuf> = None;
+
+        // Full workload breakdown shown when hovering any Assignee cell
+        let workload_tooltip = {
+            let mut lines = vec!["Open issues per assignee:".to_string()];
+            lines.extend(
+                sorted_workloads(&self.assignee_workloads)
+                    .into_iter()
+                    .map(|(name, count)| format!("{}: {}", name, count)),
+            );
+            lines.join("\n")
+        };
+
+        // Wrap table in ScrollArea to ensure proper clipping at boundaries
+        let mut scroll_area = egui::ScrollArea::vertical().id_salt("list_table_scroll");
+
+        // Apply max_height if provided (used in split view to prevent overdraw)
+        if let Some(height) = max_height {
+            scroll_area = scroll_area.max_height(height);
+        }
+
+        // The Directory column adds no information when only one directory is visible
+        let visible_directory_count = self.config.directories.iter().filter(|d| d.visible).count();
+        let show_directory_column =
+            !(self.config.auto_hide_single_directory_column && visible_directory_count == 1);
+
+        scroll_area.show(ui, |ui| {
+            // Calculate Title column width based on available space and visible columns
+            let mut fixed_columns_width = 0.0;
+            if *self.column_visibility.get(&SortColumn::Id).unwrap_or(&true) {
+                fixed_columns_width += 100.0;
+            }
+            if show_directory_column
+                && *self
+                    .column_visibility
+                    .get(&SortColumn::Directory)
+                    .unwrap_or(&true)
+            {
+                fixed_columns_width += 120.0;
+            }
+            if *self
+                .column_visibility
+                .get(&SortColumn::Status)
+                .unwrap_or(&true)
+            {
+                fixed_columns_width += 100.0;
+            }
+            if *self
+                .column_visibility
+                .get(&SortColumn::Priority)
+                .unwrap_or(&true)
+            {
+                fixed_columns_width += 70.0;
+            }
+            if *self
+                .column_visibility
+                .get(&SortColumn::Type)
+                .unwrap_or(&true)
+            {
+                fixed_columns_width += 100.0;
+            }
+            if *self
+                .column_visibility
+                .get(&SortColumn::Assignee)
+                .unwrap_or(&true)
+            {
+                fixed_columns_width += 120.0;
+            }
+            if *self
+                .column_visibility
+                .get(&SortColumn::CreatedBy)
+                .unwrap_or(&true)
+            {
+                fixed_columns_width += 120.0;
+            }
+            if *self
+                .column_visibility
+                .get(&SortColumn::Blockers)
+                .unwrap_or(&true)
+            {
+                fixed_columns_width += 80.0;
+            }
+            if *self
+                .column_visibility
+                .get(&SortColumn::Dependents)
+                .unwrap_or(&true)
+            {
+                fixed_columns_width += 80.0;
+            }
+            if *self
+                .column_visibility
+                .get(&SortColumn::TransitiveImpact)
+                .unwrap_or(&true)
+            {
+                fixed_columns_width += 70.0;
+            }
+            if *self
+                .column_visibility
+                .get(&SortColumn::Notes)
+                .unwrap_or(&true)
+            {
+                fixed_columns_width += 80.0;
+            }
+            if *self
+                .column_visibility
+                .get(&SortColumn::DueDate)
+                .unwrap_or(&true)
+            {
+                fixed_columns_width += 100.0;
+            }
+            if *self
+                .column_visibility
+                .get(&SortColumn::UpdatedAt)
+                .unwrap_or(&true)
+            {
+                fixed_columns_width += 80.0;
+            }
+
+            const SPACING_BUFFER: f32 = 70.0; // Account for table padding, column spacing, and scrollbar
+            let available_width = ui.available_width();
+            let table_width = (available_width - SPACING_BUFFER).max(100.0);
+            let title_width = (available_width - fixed_columns_width - SPACING_BUFFER).max(100.0);
+
+            // Default widths in table order; hidden columns get zero width
+            let column_order = [
+                (SortColumn::Id, 100.0),
+                (SortColumn::Directory, 120.0),
+                (SortColumn::Title, title_width),
+                (SortColumn::Status, 100.0),
+                (SortColumn::Priority, 70.0),
+                (SortColumn::Type, 100.0),
+                (SortColumn::Assignee, 120.0),
+                (SortColumn::CreatedBy, 120.0),
+                (SortColumn::Blockers, 80.0),
+                (SortColumn::Dependents, 80.0),
+                (SortColumn::TransitiveImpact, 70.0),
+                (SortColumn::Notes, 80.0),
+                (SortColumn::DueDate, 100.0),
+                (SortColumn::UpdatedAt, 80.0),
+            ];
+            let visible: Vec<bool> = column_order
+                .iter()
+                .map(|(column, _)| *self.column_visibility.get(column).unwrap_or(&true))
+                .collect();
+            let saved_widths = if self.config.column_width_fractions.len() == column_order.len() {
+                denormalize_column_widths(&self.config.column_width_fractions, table_width)
+            } else {
+                Vec::new()
+            };
+            let widths: Vec<f32> = column_order
+                .iter()
+                .enumerate()
+                .map(|(i, (_, default_width))| match saved_widths.get(i) {
+                    _ if !visible[i] => 0.0,
+                    Some(&width) if width > 0.0 => width,
+                    _ => *default_width,
+                })
+                .collect();
+            let table_column = |i: usize| {
+                if visible[i] {
+                    Column::initial(widths[i]).at_least(20.0).clip(true)
+                } else {
+                    Column::exact(0.0).resizable(false)
+                }
+            };
+            // Column indices actually added to the table, for reading widths back
+            let table_slots: Vec<usize> = (0..column_order.len())
+                .filter(|&i| i != 1 || show_directory_column)
+                .collect();
+            let mut rendered_widths: Vec<f32> = Vec::new();
+
+            // Fade out the highlight on a row reached by auto-navigation
+            const FLASH_SECS: f64 = 0.8;
+            let now = ui.input(|i| i.time);
+            let flash = self.flash_row.and_then(|(idx, start)| {
+                let elapsed = now - start;
+                (elapsed < FLASH_SECS).then(|| (idx, (1.0 - elapsed / FLASH_SECS) as f32))
+            });
+            if flash.is_some() {
+                ui.ctx().request_repaint();
+            } else {
+                self.flash_row = None;
+            }
+            let flash_color = self.theme_tokens.selected_bg;
+            let hover_fill = self.theme_tokens.hover_bg;
+            let my_active_fill = self.config.my_active_issue_fill();
+
+            // Set again by sortable_header_ui while a header button is hovered
+            self.hovered_column = None;
+            self.header_cell_rects.clear();
+
+            let mut table = TableBuilder::new(ui)
+                .striped(true)
+                .resizable(true)
+                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                // Start from fresh widths when the viewport or visible columns change
+                .id_salt(("issue_table", table_width.round() as i32, &visible))
+                .column(table_column(0)); // ID
+            if show_directory_column {
+                table = table.column(table_column(1)); // Directory
+            }
+            if self.scroll_to_selected {
+                self.scroll_to_selected = false;
+                if let Some(pos) = self
+                    .selected_index
+                    .and_then(|idx| filtered.iter().position(|d| d.original_idx == idx))
+                {
+                    table = table.scroll_to_row(pos, Some(egui::Align::Center));
+                }
+            }
+            table
+                .column(table_column(2)) // Title
+                .column(table_column(3)) // Status
+                .column(table_column(4)) // Priority
+                .column(table_column(5)) // Type
+                .column(table_column(6)) // Assignee
+                .column(table_column(7)) // Created By
+                .column(table_column(8)) // Blockers
+                .column(table_column(9)) // Dependents
+                .column(table_column(10)) // Impact
+                .column(table_column(11)) // Notes
+                .column(table_column(12)) // Due Date
+                .column(table_column(13)) // Updated
+                .header(25.0, |mut header| {
+                    header.col(|ui| {
+                        if self.sortable_header_ui(
+                            ui,
+                            "ID",
+                            SortColumn::Id,
+                            id_cardinality,
+                            filter_action,
+                            hide_column_request,
+                        ) {
+                            *new_sort_by = Some(SortColumn::Id);
+                        }
+                    });
+                    if show_directory_column {
+                        header.col(|ui| {
+                            if self.sortable_header_ui(
+                                ui,
+                                "Directory",
+                                SortColumn::Directory,
+                                directory_cardinality,
+                                filter_action,
+                                hide_column_request,
+                            ) {
+                                *new_sort_by = Some(SortColumn::Directory);
+                            }
+                            let directory_filter_active = self
+                                .column_filters
+                                .get(&SortColumn::Directory)
+                                .is_some_and(|f| f.has_active_filters());
+                            if directory_filter_active
+                                && ui
+                                    .small_button("✕")
+                                    .on_hover_text("Clear directory filter")
+                                    .clicked()
+                            {
+                                *filter_action =
+                                    Some((SortColumn::Directory, FilterAction::ClearAll));
+                            }
+                        });
+                    }
+                    header.col(|ui| {
+                        if self.sortable_header_ui(
+                            ui,
+                            "Title",
+                            SortColumn::Title,
+                            title_cardinality,
+                            filter_action,
+                            hide_column_request,
+                        ) {
+                            *new_sort_by = Some(SortColumn::Title);
+                        }
+                    });
+                    header.col(|ui| {
+                        if self.sortable_header_ui(
+                            ui,
+                            "Status",
+                            SortColumn::Status,
+                            status_cardinality,
+                            filter_action,
+                            hide_column_request,
+                        ) {
+                            *new_sort_by = Some(SortColumn::Status);
+                        }
+                    });
+                    header.col(|ui| {
+                        if self.sortable_header_ui(
+                            ui,
+                            "Priority",
+                            SortColumn::Priority,
+                            priority_cardinality,
+                            filter_action,
+                            hide_column_request,
+                        ) {
+                            *new_sort_by = Some(SortColumn::Priority);
+                        }
+                    });
+                    header.col(|ui| {
+                        if self.sortable_header_ui(
+                            ui,
+                            "Type",
+                            SortColumn::Type,
+                            type_cardinality,
+                            filter_action,
+                            hide_column_request,
+                        ) {
+                            *new_sort_by = Some(SortColumn::Type);
+                        }
+                    });
+                    header.col(|ui| {
+                        if self.sortable_header_ui(
+                            ui,
+                            "Assignee",
+                            SortColumn::Assignee,
+                            assignee_cardinality,
+                            filter_action,
+                            hide_column_request,
+                        ) {
+                            *new_sort_by = Some(SortColumn::Assignee);
+                        }
+                    });
+                    header.col(|ui| {
+                        if self.sortable_header_ui(
+                            ui,
+                            "Created By",
+                            SortColumn::CreatedBy,
+                            created_by_cardinality,
+                            filter_action,
+                            hide_column_request,
+                        ) {
+                            *new_sort_by = Some(SortColumn::CreatedBy);
+                        }
+                    });
+                    header.col(|ui| {
+                        if self.sortable_header_ui(
+                            ui,
+                            "Blockers",
+                            SortColumn::Blockers,
+                            blockers_cardinality,
+                            filter_action,
+                            hide_column_request,
+                        ) {
+                            *new_sort_by = Some(SortColumn::Blockers);
+                        }
+                    });
+                    header.col(|ui| {
+                        if self.sortable_header_ui(
+                            ui,
+                            "Dependents",
+                            SortColumn::Dependents,
+                            dependents_cardinality,
+                            filter_action,
+                            hide_column_request,
+                        ) {
+                            *new_sort_by = Some(SortColumn::Dependents);
+                        }
+                    });
+                    header.col(|ui| {
+                        if self.sortable_header_ui(
+                            ui,
+                            "Impact",
+                            SortColumn::TransitiveImpact,
+                            impact_cardinality,
+                            filter_action,
+                            hide_column_request,
+                        ) {
+                            *new_sort_by = Some(SortColumn::TransitiveImpact);
+                        }
+                    });
+                    header.col(|ui| {
+                        if self.sortable_header_ui(
+                            ui,
+                            "Notes",
+                            SortColumn::Notes,
+                            notes_cardinality,
+                            filter_action,
+                            hide_column_request,
+                        ) {
+                            *new_sort_by = Some(SortColumn::Notes);
+                        }
+                    });
+                    header.col(|ui| {
+                        if self.sortable_header_ui(
+                            ui,
+                            "Due Date",
+                            SortColumn::DueDate,
+                            due_date_cardinality,
+                            filter_action,
+                            hide_column_request,
+                        ) {
+                            *new_sort_by = Some(SortColumn::DueDate);
+                        }
+                    });
+                    header.col(|ui| {
+                        if self.sortable_header_ui(
+                            ui,
+                            "Updated",
+                            SortColumn::UpdatedAt,
+                            updated_cardinality,
+                            filter_action,
+                            hide_column_request,
+                        ) {
+                            *new_sort_by = Some(SortColumn::UpdatedAt);
+                        }
+                    });
+                })
+                .body(|mut body| {
+                    rendered_widths = body.widths().to_vec();
+                    let body_clip_rect = body.ui_mut().clip_rect();
+                    let column_overlay = egui::Painter::new(
+                        body.ui_mut().ctx().clone(),
+                        body.ui_mut().layer_id(),
+                        body_clip_rect,
+                    );
+                    let hovered_column_span = self.hovered_column.map(|_| self.hovered_column_span);
+
+                    // Find the column boundary under the pointer, if it is on the header row
+                    const RESIZE_GRAB_PX: f32 = 4.0;
+                    let boundary_offset = body.ui_mut().spacing().item_spacing.x / 2.0;
+                    let pointer = body.ui_mut().ctx().pointer_hover_pos();
+                    self.hovered_resize_col = pointer.and_then(|pos| {
+                        self.header_cell_rects.iter().position(|rect| {
+                            let boundary = rect.right() + boundary_offset;
+                            rect.width() >= 1.0
+                                && rect.y_range().contains(pos.y)
+                                && (pos.x - boundary).abs() <= RESIZE_GRAB_PX
+                        })
+                    });
+                    let resize_line = self.hovered_resize_col.map(|col| {
+                        let x = self.header_cell_rects[col].right() + boundary_offset;
+                        let top = self.header_cell_rects[col].top();
+                        [egui::pos2(x, top), egui::pos2(x, body_clip_rect.bottom())]
+                    });
+                    // Wrapped titles make rows as tall as their title needs
+                    let title_width = table_slots
+                        .iter()
+                        .position(|&i| i == 2)
+                        .and_then(|slot| rendered_widths.get(slot).copied())
+                        .unwrap_or(title_width);
+                    let row_heights: Vec<f32> = filtered
+                        .iter()
+                        .map(|display| {
+                            if !self.config.wrap_titles {
+                                return 20.0;
+                            }
+                            let galley = egui::WidgetText::from(&display.issue.title).into_galley(
+                                body.ui_mut(),
+                                Some(egui::TextWrapMode::Wrap),
+                                title_width,
+                                egui::TextStyle::Body,
+                            );
+                            (galley.size().y + 4.0).max(20.0)
+                        })
+                        .collect();
+                    body.heterogeneous_rows(row_heights.into_iter(), |mut row| {
+                        let row_index = row.index();
+                        if let Some(display) = filtered.get(row_index) {
+                            let original_idx = display.original_idx;
+                            let issue = &display.issue;
+                            let is_selected = self.selected_index == Some(original_idx);
+                            let is_row_hovered = self.hovered_row == Some(original_idx);
+
+                            // Hover wins over everything; my active issues tint unselected rows
+                            let row_fill = if is_row_hovered {
+                                Some(hover_fill)
+                            } else if !is_selected && is_my_active_issue(issue, &self.config) {
+                                Some(my_active_fill)
+                            } else if !is_selected && display.readiness == "stale_open" {
+                                Some(self.theme_tokens.stale_open_bg)
+                            } else {
+                                None
+                            };
+
+                            row.set_selected(
+                                is_selected || self.multi_selected.contains(&issue.id),
+                            );
+
+                            let mut any_cell_hovered = false;
+
+                            row.col(|ui| {
+                                let available_size = ui.available_size();
+                                let (id, rect) = ui.allocate_space(available_size);
+                                let response = ui.interact(rect, id, egui::Sense::click());
+
+                                if response.hovered() {
+                                    any_cell_hovered = true;
+                                }
+
+                                if let Some(fill) = row_fill {
+                                    ui.painter().rect_filled(rect, 0.0, fill);
+                                }
+
+                                let mut child_ui = ui.new_child(
+                                    egui::UiBuilder::new()
+                                        .max_rect(rect)
+                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                                );
+                                child_ui.set_clip_rect(rect);
+                                child_ui.add(egui::Label::new(&issue.id).selectable(false));
+
+                                if response.clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                                if response.double_clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                                // Not useful for filtering, so the menu only copies
+                                response.context_menu(|ui| {
+                                    for (label, text) in issue_copy_variants(issue) {
+                                        if ui.button(label).clicked() {
+                                            self.copy_to_clipboard(ui.ctx(), text);
+                                            ui.close_menu();
+                                        }
+                                    }
+                                });
+                            });
+
+                            // Directory column
+                            if show_directory_column {
+                                row.col(|ui| {
+                                    let available_size = ui.available_size();
+                                    let (id, rect) = ui.allocate_space(available_size);
+                                    let response = ui.interact(rect, id, egui::Sense::click());
+
+                                    if response.hovered() {
+                                        any_cell_hovered = true;
+                                    }
+
+                                    if let Some(fill) = row_fill {
+                                        ui.painter().rect_filled(rect, 0.0, fill);
+                                    }
+
+                                    let mut child_ui =
+                                        ui.new_child(egui::UiBuilder::new().max_rect(rect).layout(
+                                            egui::Layout::left_to_right(egui::Align::Center),
+                                        ));
+                                    child_ui.set_clip_rect(rect);
+                                    let directory_name =
+                                        display.effective_directory_name(&self.config);
+                                    child_ui
+                                        .add(egui::Label::new(directory_name).selectable(false));
+
+                                    // Filters match on the name the issue was loaded with
+                                    let directory_value = issue.source_directory.clone();
+                                    let directory_name = directory_name.to_string();
+
+                                    // Clicking is a quick filter rather than a selection: show only
+                                    // this directory, or clear the directory filter if one is active
+                                    let directory_filter_active = self
+                                        .column_filters
+                                        .get(&SortColumn::Directory)
+                                        .is_some_and(|f| f.has_active_filters());
+                                    let hover_text = if directory_filter_active {
+                                        "Click to show all directories".to_string()
+                                    } else {
+                                        format!("Click to show only {}", directory_name)
+                                    };
+                                    let response = response.on_hover_text(hover_text);
+                                    if response.clicked() {
+                                        *filter_action = Some((
+                                            SortColumn::Directory,
+                                            if directory_filter_active {
+                                                FilterAction::ClearAll
+                                            } else {
+                                                FilterAction::SetOnly(directory_value.clone())
+                                            },
+                                        ));
+                                    }
+
+                                    response.context_menu(|ui| {
+                                        if directory_cardinality > 20 {
+                                            ui.label(format!(
+                                                "⚠ High cardinality ({} values)",
+                                                directory_cardinality
+                                            ));
+                                            ui.label("Filtering not available");
+                                        } else {
+                                            let current_filter =
+                                                self.column_filters.get(&SortColumn::Directory);
+                                            let is_filtered = current_filter
+                                                .map(|f| f.is_filtered(&directory_value))
+                                                .unwrap_or(false);
+
+                                            if ui
+                                                .button(if is_filtered {
+                                                    format!("✓ Include \"{}\"", directory_name)
+                                                } else {
+                                                    format!("✗ Exclude \"{}\"", directory_name)
+                                                })
+                                                .clicked()
+                                            {
+                                                *filter_action = Some((
+                                                    SortColumn::Directory,
+                                                    FilterAction::Toggle(directory_value.clone()),
+                                                ));
+                                                ui.close_menu();
+                                            }
+                                        }
+
+                                        if ui
+                                            .button("Show only issues from this directory")
+                                            .clicked()
+                                        {
+                                            *filter_action = Some((
+                                                SortColumn::Directory,
+                                                FilterAction::SetOnly(directory_value.clone()),
+                                            ));
+                                            ui.close_menu();
+                                        }
+                                    });
+                                });
+                            }
+
+                            row.col(|ui| {
+                                let available_size = ui.available_size();
+                                let (id, rect) = ui.allocate_space(available_size);
+                                let response = ui.interact(rect, id, egui::Sense::click());
+
+                                if response.hovered() {
+                                    any_cell_hovered = true;
+                                }
+
+                                if let Some(fill) = row_fill {
+                                    ui.painter().rect_filled(rect, 0.0, fill);
+                                }
+
+                                let mut child_ui = ui.new_child(
+                                    egui::UiBuilder::new()
+                                        .max_rect(rect)
+                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                                );
+                                child_ui.set_clip_rect(rect);
+                                if display.unresolved_comments > 0 {
+                                    child_ui.label("💬");
+                                    draw_count_badge(&mut child_ui, display.unresolved_comments)
+                                        .on_hover_text("Unresolved comments");
+                                }
+                                let response = if self.config.wrap_titles {
+                                    child_ui.add(
+                                        egui::Label::new(&issue.title).wrap().selectable(false),
+                                    );
+                                    response
+                                } else {
+                                    let shown = truncate_with_ellipsis(
+                                        &issue.title,
+                                        self.config.title_max_chars,
+                                    );
+                                    let truncated = shown != issue.title;
+                                    child_ui.add(egui::Label::new(shown).selectable(false));
+                                    if truncated {
+                                        response.on_hover_text(&issue.title)
+                                    } else {
+                                        response
+                                    }
+                                };
+
+                                if response.clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                                if response.double_clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                                // Title has no filter menu; it carries the personal label color instead
+                                response.context_menu(|ui| {
+                                    let current = issue
+                                        .user_label_color
+                                        .as_deref()
+                                        .and_then(|hex| egui::Color32::from_hex(hex).ok());
+                                    ui.menu_button("Set Label Color", |ui| {
+                                        let mut color =
+                                            current.unwrap_or(self.theme_tokens.label_default);
+                                        if egui::color_picker::color_picker_color32(
+                                            ui,
+                                            &mut color,
+                                            egui::color_picker::Alpha::Opaque,
+                                        ) {
+                                            label_color_change = Some((original_idx, Some(color)));
+                                        }
+                                    });
+                                    if current.is_some() && ui.button("Clear Label Color").clicked()
+                                    {
+                                        label_color_change = Some((original_idx, None));
+                                        ui.close_menu();
+                                    }
+                                    if !issue.source_path.as_os_str().is_empty() {
+                                        ui.separator();
+                                        if ui.button("Open Terminal Here").clicked() {
+                                            terminal_path = Some(issue.source_path.clone());
+                                            ui.close_menu();
+                                        }
+                                    }
+                                });
+                            });
+
+                            row.col(|ui| {
+                                let available_size = ui.available_size();
+                                let (id, rect) = ui.allocate_space(available_size);
+                                let response = ui.interact(rect, id, egui::Sense::click());
+
+                                if response.hovered() {
+                                    any_cell_hovered = true;
+                                }
+
+                                if let Some(fill) = row_fill {
+                                    ui.painter().rect_filled(rect, 0.0, fill);
+                                }
+
+                                let mut child_ui = ui.new_child(
+                                    egui::UiBuilder::new()
+                                        .max_rect(rect)
+                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                                );
+                                child_ui.set_clip_rect(rect);
+                                let status_text = &display.readiness;
+                                child_ui.add(egui::Label::new(status_text).selectable(false));
+
+                                if response.clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                                if response.double_clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+
+                                let status_value = status_text.clone();
+                                response.context_menu(|ui| {
+                                    if status_cardinality > 20 {
+                                        ui.label(format!(
+                                            "⚠ High cardinality ({} values)",
+                                            status_cardinality
+                                        ));
+                                        ui.label("Filtering not available");
+                                    } else {
+                                        let current_filter =
+                                            self.column_filters.get(&SortColumn::Status);
+                                        let is_filtered = current_filter
+                                            .map(|f| f.is_filtered(&status_value))
+                                            .unwrap_or(false);
+
+                                        if ui
+                                            .button(if is_filtered {
+                                                format!("✓ Include \"{}\"", status_value)
+                                            } else {
+                                                format!("✗ Exclude \"{}\"", status_value)
+                                            })
+                                            .clicked()
+                                        {
+                                            *filter_action = Some((
+                                                SortColumn::Status,
+                                                FilterAction::Toggle(status_value.clone()),
+                                            ));
+                                            ui.close_menu();
+                                        }
+                                    }
+                                });
+                            });
+
+                            row.col(|ui| {
+                                let available_size = ui.available_size();
+                                let (id, rect) = ui.allocate_space(available_size);
+                                let response = ui.interact(rect, id, egui::Sense::click());
+
+                                if response.hovered() {
+                                    any_cell_hovered = true;
+                                }
+
+                                if let Some(fill) = row_fill {
+                                    ui.painter().rect_filled(rect, 0.0, fill);
+                                }
+
+                                let mut child_ui = ui.new_child(
+                                    egui::UiBuilder::new()
+                                        .max_rect(rect)
+                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                                );
+                                child_ui.set_clip_rect(rect);
+                                let priority_text = format!("P{}", issue.priority);
+                                child_ui.add(egui::Label::new(&priority_text).selectable(false));
+
+                                if response.clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                                if response.double_clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+
+                                let priority_value = priority_text.clone();
+                                response.context_menu(|ui| {
+                                    if priority_cardinality > 20 {
+                                        ui.label(format!(
+                                            "⚠ High cardinality ({} values)",
+                                            priority_cardinality
+                                        ));
+                                        ui.label("Filtering not available");
+                                    } else {
+                                        let current_filter =
+                                            self.column_filters.get(&SortColumn::Priority);
+                                        let is_filtered = current_filter
+                                            .map(|f| f.is_filtered(&priority_value))
+                                            .unwrap_or(false);
+
+                                        if ui
+                                            .button(if is_filtered {
+                                                format!("✓ Include \"{}\"", priority_value)
+                                            } else {
+                                                format!("✗ Exclude \"{}\"", priority_value)
+                                            })
+                                            .clicked()
+                                        {
+                                            *filter_action = Some((
+                                                SortColumn::Priority,
+                                                FilterAction::Toggle(priority_value.clone()),
+                                            ));
+                                            ui.close_menu();
+                                        }
+                                    }
+                                });
+                            });
+
+                            row.col(|ui| {
+                                let available_size = ui.available_size();
+                                let (id, rect) = ui.allocate_space(available_size);
+                                let response = ui.interact(rect, id, egui::Sense::click());
+
+                                if response.hovered() {
+                                    any_cell_hovered = true;
+                                }
+
+                                if let Some(fill) = row_fill {
+                                    ui.painter().rect_filled(rect, 0.0, fill);
+                                }
+
+                                let mut child_ui = ui.new_child(
+                                    egui::UiBuilder::new()
+                                        .max_rect(rect)
+                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                                );
+                                child_ui.set_clip_rect(rect);
+                                child_ui.add(egui::Label::new(&issue.issue_type).selectable(false));
+
+                                if response.clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                                if response.double_clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+
+                                let type_value = issue.issue_type.clone();
+                                response.context_menu(|ui| {
+                                    if type_cardinality > 20 {
+                                        ui.label(format!(
+                                            "⚠ High cardinality ({} values)",
+                                            type_cardinality
+                                        ));
+                                        ui.label("Filtering not available");
+                                    } else {
+                                        let current_filter =
+                                            self.column_filters.get(&SortColumn::Type);
+                                        let is_filtered = current_filter
+                                            .map(|f| f.is_filtered(&type_value))
+                                            .unwrap_or(false);
+
+                                        if ui
+                                            .button(if is_filtered {
+                                                format!("✓ Include \"{}\"", type_value)
+                                            } else {
+                                                format!("✗ Exclude \"{}\"", type_value)
+                                            })
+                                            .clicked()
+                                        {
+                                            *filter_action = Some((
+                                                SortColumn::Type,
+                                                FilterAction::Toggle(type_value.clone()),
+                                            ));
+                                            ui.close_menu();
+                                        }
+                                    }
+                                });
+                            });
+
+                            row.col(|ui| {
+                                let available_size = ui.available_size();
+                                let (id, rect) = ui.allocate_space(available_size);
+                                let response = ui.interact(rect, id, egui::Sense::click());
+
+                                if response.hovered() {
+                                    any_cell_hovered = true;
+                                }
+
+                                if let Some(fill) = row_fill {
+                                    ui.painter().rect_filled(rect, 0.0, fill);
+                                }
+
+                                let mut child_ui = ui.new_child(
+                                    egui::UiBuilder::new()
+                                        .max_rect(rect)
+                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                                );
+                                child_ui.set_clip_rect(rect);
+                                let assignee_text =
+                                    issue.assignee.as_ref().unwrap_or(&"-".to_string()).clone();
+                                // Filters match the raw value; only the label is shortened
+                                let shown_assignee = match &issue.assignee {
+                                    Some(raw) => format_assignee(raw, self.config.assignee_display),
+                                    None => assignee_text.clone(),
+                                };
+                                let assignee_label = match issue
+                                    .assignee
+                                    .as_ref()
+                                    .and_then(|a| self.assignee_workloads.get(a))
+                                {
+                                    Some(count) => format!("{} ({})", shown_assignee, count),
+                                    None => shown_assignee,
+                                };
+                                child_ui.add(egui::Label::new(&assignee_label).selectable(false));
+
+                                let response = response.on_hover_text(&workload_tooltip);
+
+                                if response.clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                                if response.double_clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+
+                                let assignee_value = assignee_text.clone();
+                                response.context_menu(|ui| {
+                                    if assignee_cardinality > 20 {
+                                        ui.label(format!(
+                                            "⚠ High cardinality ({} values)",
+                                            assignee_cardinality
+                                        ));
+                                        ui.label("Filtering not available");
+                                    } else {
+                                        let current_filter =
+                                            self.column_filters.get(&SortColumn::Assignee);
+                                        let is_filtered = current_filter
+                                            .map(|f| f.is_filtered(&assignee_value))
+                                            .unwrap_or(false);
+
+                                        if ui
+                                            .button(if is_filtered {
+                                                format!("✓ Include \"{}\"", assignee_value)
+                                            } else {
+                                                format!("✗ Exclude \"{}\"", assignee_value)
+                                            })
+                                            .clicked()
+                                        {
+                                            *filter_action = Some((
+                                                SortColumn::Assignee,
+                                                FilterAction::Toggle(assignee_value.clone()),
+                                            ));
+                                            ui.close_menu();
+                                        }
+                                    }
+
+                                    ui.separator();
+                                    if ui
+                                        .button(format!("Show only issues by {}", assignee_value))
+                                        .clicked()
+                                    {
+                                        *exclusive_filter = Some((
+                                            SortColumn::Assignee,
+                                            FilterAction::SetOnly(assignee_value.clone()),
+                                        ));
+                                        ui.close_menu();
+                                    }
+                                    if ui
+                                        .button(format!("Show issues NOT by {}", assignee_value))
+                                        .clicked()
+                                    {
+                                        // Adds to the current filters; only "Show only" resets them
+                                        *filter_action = Some((
+                                            SortColumn::Assignee,
+                                            FilterAction::Toggle(assignee_value.clone()),
+                                        ));
+                                        ui.close_menu();
+                                    }
+                                });
+                            });
+
+                            // Created By column
+                            row.col(|ui| {
+                                let available_size = ui.available_size();
+                                let (id, rect) = ui.allocate_space(available_size);
+                                let response = ui.interact(rect, id, egui::Sense::click());
+
+                                if response.hovered() {
+                                    any_cell_hovered = true;
+                                }
+
+                                if let Some(fill) = row_fill {
+                                    ui.painter().rect_filled(rect, 0.0, fill);
+                                }
+
+                                let mut child_ui = ui.new_child(
+                                    egui::UiBuilder::new()
+                                        .max_rect(rect)
+                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                                );
+                                child_ui.set_clip_rect(rect);
+                                let created_by_value =
+                                    issue.created_by.clone().unwrap_or_else(|| "-".to_string());
+                                let shown_created_by = match &issue.created_by {
+                                    Some(raw) => format_assignee(raw, self.config.assignee_display),
+                                    None => created_by_value.clone(),
+                                };
+                                child_ui.add(egui::Label::new(shown_created_by).selectable(false));
+
+                                if response.clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                                if response.double_clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+
+                                response.context_menu(|ui| {
+                                    if created_by_cardinality > 20 {
+                                        ui.label(format!(
+                                            "⚠ High cardinality ({} values)",
+                                            created_by_cardinality
+                                        ));
+                                        ui.label("Filtering not available");
+                                    } else {
+                                        let is_filtered = self
+                                            .column_filters
+                                            .get(&SortColumn::CreatedBy)
+                                            .map(|f| f.is_filtered(&created_by_value))
+                                            .unwrap_or(false);
+
+                                        if ui
+                                            .button(if is_filtered {
+                                                format!("✓ Include \"{}\"", created_by_value)
+                                            } else {
+                                                format!("✗ Exclude \"{}\"", created_by_value)
+                                            })
+                                            .clicked()
+                                        {
+                                            *filter_action = Some((
+                                                SortColumn::CreatedBy,
+                                                FilterAction::Toggle(created_by_value.clone()),
+                                            ));
+                                            ui.close_menu();
+                                        }
+                                    }
+
+                                    ui.separator();
+                                    if ui
+                                        .button(format!(
+                                            "Show only issues created by {}",
+                                            created_by_value
+                                        ))
+                                        .clicked()
+                                    {
+                                        *exclusive_filter = Some((
+                                            SortColumn::CreatedBy,
+                                            FilterAction::SetOnly(created_by_value.clone()),
+                                        ));
+                                        ui.close_menu();
+                                    }
+                                });
+                            });
+
+                            // Blockers column
                             row.col(|ui| {
                                 let available_size = ui.available_size();
                                 let (id, rect) = ui.allocate_space(available_size);
                                 let response = ui.interact(rect, id, egui::Sense::click());
 
-                                if response.hovered() {
-                                    any_cell_hovered = true;
-                                }
+                                if response.hovered() {
+                                    any_cell_hovered = true;
+                                }
+
+                                if let Some(fill) = row_fill {
+                                    ui.painter().rect_filled(rect, 0.0, fill);
+                                }
+
+                                let mut child_ui = ui.new_child(
+                                    egui::UiBuilder::new()
+                                        .max_rect(rect)
+                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                                );
+                                child_ui.set_clip_rect(rect);
+                                let blockers_count = display.blockers_count;
+                                child_ui.add(
+                                    egui::Label::new(blockers_count.to_string()).selectable(false),
+                                );
+
+                                if response.clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                                if response.double_clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                            });
+
+                            // Dependents column
+                            row.col(|ui| {
+                                let available_size = ui.available_size();
+                                let (id, rect) = ui.allocate_space(available_size);
+                                let response = ui.interact(rect, id, egui::Sense::click());
+
+                                if response.hovered() {
+                                    any_cell_hovered = true;
+                                }
+
+                                if let Some(fill) = row_fill {
+                                    ui.painter().rect_filled(rect, 0.0, fill);
+                                }
+
+                                let mut child_ui = ui.new_child(
+                                    egui::UiBuilder::new()
+                                        .max_rect(rect)
+                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                                );
+                                child_ui.set_clip_rect(rect);
+                                let dependents_count = display.dependents_count;
+                                child_ui.add(
+                                    egui::Label::new(dependents_count.to_string())
+                                        .selectable(false),
+                                );
+
+                                if response.clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                                if response.double_clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                            });
+
+                            // Impact column - issues transitively blocked by this one
+                            row.col(|ui| {
+                                let available_size = ui.available_size();
+                                let (id, rect) = ui.allocate_space(available_size);
+                                let response = ui.interact(rect, id, egui::Sense::click());
+
+                                if response.hovered() {
+                                    any_cell_hovered = true;
+                                }
+
+                                if let Some(fill) = row_fill {
+                                    ui.painter().rect_filled(rect, 0.0, fill);
+                                }
+
+                                let mut child_ui = ui.new_child(
+                                    egui::UiBuilder::new()
+                                        .max_rect(rect)
+                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                                );
+                                child_ui.set_clip_rect(rect);
+                                child_ui.add(
+                                    egui::Label::new(display.impact_count.to_string())
+                                        .selectable(false),
+                                );
+
+                                if response.clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                                if response.double_clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                            });
+
+                            // Notes column - "yes (length)" or "no"
+                            row.col(|ui| {
+                                let available_size = ui.available_size();
+                                let (id, rect) = ui.allocate_space(available_size);
+                                let response = ui.interact(rect, id, egui::Sense::click());
+
+                                if response.hovered() {
+                                    any_cell_hovered = true;
+                                }
+
+                                if let Some(fill) = row_fill {
+                                    ui.painter().rect_filled(rect, 0.0, fill);
+                                }
+
+                                let mut child_ui = ui.new_child(
+                                    egui::UiBuilder::new()
+                                        .max_rect(rect)
+                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                                );
+                                child_ui.set_clip_rect(rect);
+                                let notes_text = match display.notes_len {
+                                    Some(len) => format!("yes ({})", len),
+                                    None => "no".to_string(),
+                                };
+                                child_ui.add(egui::Label::new(notes_text).selectable(false));
+
+                                if response.clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                                if response.double_clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                            });
+
+                            // Due Date column - clicking opens a date picker popover
+                            row.col(|ui| {
+                                let available_size = ui.available_size();
+                                let (id, rect) = ui.allocate_space(available_size);
+                                let response = ui.interact(rect, id, egui::Sense::click());
+
+                                if response.hovered() {
+                                    any_cell_hovered = true;
+                                }
+
+                                if let Some(fill) = row_fill {
+                                    ui.painter().rect_filled(rect, 0.0, fill);
+                                }
+
+                                let mut child_ui = ui.new_child(
+                                    egui::UiBuilder::new()
+                                        .max_rect(rect)
+                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                                );
+                                child_ui.set_clip_rect(rect);
+                                child_ui.add(
+                                    egui::Label::new(format_due_date(issue)).selectable(false),
+                                );
+
+                                let popup_id = ui.make_persistent_id(("due_date_popup", &issue.id));
+                                if response.clicked() {
+                                    ui.memory_mut(|m| m.toggle_popup(popup_id));
+                                }
+                                egui::popup::popup_below_widget(
+                                    ui,
+                                    popup_id,
+                                    &response,
+                                    egui::PopupCloseBehavior::CloseOnClickOutside,
+                                    |ui| {
+                                        // An unset date opens the picker on today
+                                        let mut date = issue
+                                            .due_date
+                                            .as_deref()
+                                            .and_then(parse_due_date)
+                                            .or_else(|| Some(chrono::Local::now().date_naive()));
+                                        if ui.add(CalendarWidget::new(&mut date)).changed() {
+                                            due_date_change = Some((original_idx, date));
+                                            ui.memory_mut(|m| m.close_popup());
+                                        }
+                                    },
+                                );
+                            });
+
+                            // Updated column
+                            row.col(|ui| {
+                                let available_size = ui.available_size();
+                                let (id, rect) = ui.allocate_space(available_size);
+                                let response = ui.interact(rect, id, egui::Sense::click());
+
+                                if response.hovered() {
+                                    any_cell_hovered = true;
+                                }
+
+                                if let Some(fill) = row_fill {
+                                    ui.painter().rect_filled(rect, 0.0, fill);
+                                }
+
+                                let mut child_ui = ui.new_child(
+                                    egui::UiBuilder::new()
+                                        .max_rect(rect)
+                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                                );
+                                child_ui.set_clip_rect(rect);
+                                child_ui.add(
+                                    egui::Label::new(format_updated_at(issue)).selectable(false),
+                                );
+
+                                let response = response.on_hover_text(&issue.updated_at);
+                                if response.clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                                if response.double_clicked() {
+                                    *new_selected = Some(Some(original_idx));
+                                }
+                            });
+
+                            if any_cell_hovered {
+                                *new_hovered_row = Some(Some(original_idx));
+                            }
+
+                            if let Some(color) = issue
+                                .user_label_color
+                                .as_deref()
+                                .and_then(|hex| egui::Color32::from_hex(hex).ok())
+                            {
+                                let row_response = row.response();
+                                egui::Painter::new(
+                                    row_response.ctx.clone(),
+                                    row_response.layer_id,
+                                    body_clip_rect,
+                                )
+                                .rect_filled(
+                                    egui::Rect::from_min_size(
+                                        row_response.rect.min,
+                                        egui::vec2(4.0, row_response.rect.height()),
+                                    ),
+                                    0.0,
+                                    color,
+                                );
+                            }
+
+                            if let Some((flash_idx, strength)) = flash {
+                                if flash_idx == original_idx {
+                                    let row_response = row.response();
+                                    egui::Painter::new(
+                                        row_response.ctx.clone(),
+                                        row_response.layer_id,
+                                        body_clip_rect,
+                                    )
+                                    .rect_filled(
+                                        row_response.rect,
+                                        0.0,
+                                        flash_color.gamma_multiply(strength * 0.6),
+                                    );
+                                }
+                            }
+                        }
+                    });
+                    // Tint the whole column under a hovered header
+                    if let Some(span) = hovered_column_span {
+                        column_overlay.rect_filled(
+                            egui::Rect::from_x_y_ranges(span, body_clip_rect.y_range()),
+                            0.0,
+                            hover_fill.gamma_multiply(0.3),
+                        );
+                    }
+                    // Show where the resize handle is, across header and rows
+                    if let Some(line) = resize_line {
+                        let stroke = egui::Stroke::new(1.5, self.theme_tokens.resize_handle);
+                        column_overlay
+                            .with_clip_rect(column_overlay.ctx().screen_rect())
+                            .line_segment(line, stroke);
+                    }
+                });
+
+            // Remember widths the user dragged, once the drag is released
+            if rendered_widths.len() == table_slots.len() && !ui.input(|i| i.pointer.any_down()) {
+                let mut current = widths.clone();
+                for (slot, width) in table_slots.iter().zip(&rendered_widths) {
+                    current[*slot] = *width;
+                }
+                let changed = current
+                    .iter()
+                    .zip(&widths)
+                    .any(|(a, b)| (a - b).abs() > 0.5);
+                if changed {
+                    self.config.column_width_fractions =
+                        normalize_column_widths(&current, table_width);
+                    let _ = self.config.save();
+                }
+            }
+        }); // Close ScrollArea
+
+        if let Some((idx, date)) = due_date_change {
+            self.set_due_date(idx, date);
+        }
+        if let Some((idx, color)) = label_color_change {
+            self.set_user_label_color(idx, color);
+        }
+        if let Some(path) = terminal_path {
+            if let Err(e) = open_terminal(&path) {
+                self.error_message = Some(format!("Failed to open terminal: {}", e));
+            }
+        }
+
+        if std::mem::take(&mut self.copy_markdown_requested) {
+            // Same columns and rows as currently shown in the table
+            let columns: Vec<(SortColumn, &str)> = TABLE_COLUMNS
+                .into_iter()
+                .filter(|(column, _)| *self.column_visibility.get(column).unwrap_or(&true))
+                .filter(|(column, _)| show_directory_column || *column != SortColumn::Directory)
+                .collect();
+            let headers: Vec<&str> = columns.iter().map(|(_, name)| *name).collect();
+            let rows: Vec<Vec<String>> = filtered
+                .iter()
+                .map(|display| {
+                    columns
+                        .iter()
+                        .map(|(column, _)| self.get_column_value(&display.issue, *column))
+                        .collect()
+                })
+                .collect();
+            let table = export::to_gfm_table(&headers, &rows);
+            self.copy_to_clipboard(&ui.ctx().clone(), table);
+        }
+    }
+
+    fn show_matrix_view(&mut self, ui: &mut egui::Ui) {
+        const CELL: f32 = 16.0;
+        const LABEL_SIZE: f32 = 90.0;
+
+        let ids: Vec<String> = self
+            .filtered_and_sorted_issues()
+            .into_iter()
+            .map(|display| display.issue.id)
+            .collect();
+        if ids.is_empty() {
+            ui.label("No issues to show");
+            return;
+        }
+        // Only rebuilt when a refresh, filter or sort changes the issues shown
+        if self
+            .dependency_matrix
+            .as_ref()
+            .is_none_or(|(matrix_ids, _)| *matrix_ids != ids)
+        {
+            let matrix = build_dependency_matrix(&ids, &self.snapshot_cache);
+            self.dependency_matrix = Some((ids, matrix));
+        }
+        let Some((ids, matrix)) = &self.dependency_matrix else {
+            return;
+        };
+
+        egui::ScrollArea::both()
+            .id_salt("matrix_scroll")
+            .show(ui, |ui| {
+                let n = ids.len() as f32;
+                let size = egui::vec2(LABEL_SIZE + n * CELL, LABEL_SIZE + n * CELL);
+                let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+                let painter = ui.painter_at(rect);
+                let visuals = ui.visuals();
+                let font = egui::FontId::monospace(10.0);
+                let grid = rect.min + egui::vec2(LABEL_SIZE, LABEL_SIZE);
+                let cell_rect = |row: usize, col: usize| {
+                    egui::Rect::from_min_size(
+                        grid + egui::vec2(col as f32 * CELL, row as f32 * CELL),
+                        egui::vec2(CELL, CELL),
+                    )
+                    .shrink(1.0)
+                };
+
+                for (i, id) in ids.iter().enumerate() {
+                    let offset = i as f32 * CELL + CELL / 2.0;
+                    painter.text(
+                        egui::pos2(grid.x - 4.0, grid.y + offset),
+                        egui::Align2::RIGHT_CENTER,
+                        id,
+                        font.clone(),
+                        visuals.text_color(),
+                    );
+                    // Column labels read bottom-to-top above the grid
+                    let galley =
+                        painter.layout_no_wrap(id.clone(), font.clone(), visuals.text_color());
+                    let label_pos =
+                        egui::pos2(grid.x + offset - galley.size().y / 2.0, grid.y - 4.0);
+                    painter.add(
+                        egui::epaint::TextShape::new(label_pos, galley, visuals.text_color())
+                            .with_angle(-std::f32::consts::FRAC_PI_2),
+                    );
+                }
+
+                for (row, deps) in matrix.iter().enumerate() {
+                    for (col, &depends) in deps.iter().enumerate() {
+                        let fill = if depends {
+                            visuals.selection.bg_fill
+                        } else if row == col {
+                            visuals.widgets.inactive.bg_fill
+                        } else {
+                            visuals.faint_bg_color
+                        };
+                        painter.rect_filled(cell_rect(row, col), 2.0, fill);
+                    }
+                }
+
+                let hovered_cell = response.hover_pos().and_then(|pos| {
+                    let offset = pos - grid;
+                    let (row, col) = ((offset.y / CELL).floor(), (offset.x / CELL).floor());
+                    (row >= 0.0 && col >= 0.0 && row < n && col < n)
+                        .then_some((row as usize, col as usize))
+                });
+                if let Some((row, col)) = hovered_cell {
+                    painter.rect_stroke(cell_rect(row, col), 2.0, visuals.selection.stroke);
+                    let text = if matrix[row][col] {
+                        format!("{} depends on {}", ids[row], ids[col])
+                    } else {
+                        format!("{} does not depend on {}", ids[row], ids[col])
+                    };
+                    response.on_hover_text(text);
+                }
+            });
+    }
+
+    fn show_swimlane_view(&mut self, ui: &mut egui::Ui) {
+        let filtered = self.filtered_and_sorted_issues();
+
+        // One lane per assignee, with unassigned issues at the bottom
+        let mut lanes: Vec<Option<String>> = filtered
+            .iter()
+            .filter_map(|display| display.issue.assignee.clone())
+            .map(Some)
+            .collect();
+        lanes.sort();
+        lanes.dedup();
+        lanes.push(None);
+
+        let theme_tokens = self.theme_tokens;
+        let pointer_pos = ui.ctx().pointer_latest_pos();
+        let dragging_id = self
+            .swimlane_drag
+            .as_ref()
+            .map(|card| card.issue_id.clone());
+        let selected_idx = self.selected_index;
+        let mut drag_started = None;
+        let mut hovered_lane: Option<Option<String>> = None;
+        let mut new_selected = None;
+
+        egui::ScrollArea::vertical()
+            .id_salt("swimlane_scroll")
+            .show(ui, |ui| {
+                for lane in &lanes {
+                    let lane_issues: Vec<&IssueDisplay> = filtered
+                        .iter()
+                        .filter(|display| display.issue.assignee == *lane)
+                        .collect();
+                    let lane_rect = egui::Frame::group(ui.style())
+                        .show(ui, |ui| {
+                            ui.set_width(ui.available_width());
+                            let stats = compute_group_stats(&lane_issues);
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new(format!(
+                                        "{} ({})",
+                                        lane.as_deref().unwrap_or("(Unassigned)"),
+                                        stats.count
+                                    ))
+                                    .strong(),
+                                );
+                                draw_group_stats(ui, &stats, &theme_tokens);
+                            });
+                            ui.horizontal_wrapped(|ui| {
+                                for display in &lane_issues {
+                                    let issue = &display.issue;
+                                    let is_dragged =
+                                        dragging_id.as_deref() == Some(issue.id.as_str());
+                                    // The dragged card stays behind as a faded ghost
+                                    let card_rect = ui
+                                        .scope(|ui| {
+                                            if is_dragged {
+                                                ui.multiply_opacity(0.35);
+                                            }
+                                            draw_swimlane_card(
+                                                ui,
+                                                &issue.id,
+                                                &issue.title,
+                                                selected_idx == Some(display.original_idx),
+                                            )
+                                        })
+                                        .inner;
+                                    let response = ui.interact(
+                                        card_rect,
+                                        ui.id().with(("swimlane_card", &issue.id)),
+                                        egui::Sense::click_and_drag(),
+                                    );
+                                    if response.drag_started() {
+                                        let grab_offset = response
+                                            .interact_pointer_pos()
+                                            .map(|pos| pos - card_rect.min)
+                                            .unwrap_or_default();
+                                        drag_started = Some(SwimlaneCard {
+                                            original_idx: display.original_idx,
+                                            issue_id: issue.id.clone(),
+                                            title: issue.title.clone(),
+                                            from_assignee: issue.assignee.clone(),
+                                            grab_offset,
+                                        });
+                                    }
+                                    if response.clicked() {
+                                        new_selected = Some(display.original_idx);
+                                    }
+                                }
+                            });
+                        })
+                        .response
+                        .rect;
+
+                    // Highlight the lane the dragged card would drop into
+                    if dragging_id.is_some()
+                        && pointer_pos.is_some_and(|pos| lane_rect.contains(pos))
+                    {
+                        let selection = ui.visuals().selection;
+                        ui.painter().rect_filled(
+                            lane_rect,
+                            4.0,
+                            selection.bg_fill.gamma_multiply(0.25),
+                        );
+                        ui.painter().rect_stroke(lane_rect, 4.0, selection.stroke);
+                        hovered_lane = Some(lane.clone());
+                    }
+                }
+            });
+
+        if let Some(card) = drag_started {
+            self.swimlane_drag = Some(card);
+        }
+
+        if let Some(card) = &self.swimlane_drag {
+            // Card following the pointer
+            if let Some(pos) = pointer_pos {
+                egui::Area::new(egui::Id::new("swimlane_drag_card"))
+                    .order(egui::Order::Tooltip)
+                    .fixed_pos(pos - card.grab_offset)
+                    .interactable(false)
+                    .show(ui.ctx(), |ui| {
+                        draw_swimlane_card(ui, &card.issue_id, &card.title, true);
+                    });
+            }
+
+            if ui.input(|i| i.pointer.any_released()) {
+                let card = self.swimlane_drag.take().expect("drag in progress");
+                if let Some(target) = hovered_lane.filter(|target| *target != card.from_assignee) {
+                    self.set_assignee(card.original_idx, target);
+                }
+            }
+        }
+
+        if let Some(idx) = new_selected {
+            self.request_selection(Some(idx));
+        }
+    }
+
+    fn show_board_view(&mut self, ui: &mut egui::Ui) {
+        let filtered = self.filtered_and_sorted_issues();
+        let columns = sprint_board_columns(&filtered);
+
+        let selected_idx = self.selected_index;
+        let mut new_selected = None;
+
+        egui::ScrollArea::horizontal()
+            .id_salt("board_scroll")
+            .show(ui, |ui| {
+                ui.horizontal_top(|ui| {
+                    for column in &columns {
+                        let column_issues: Vec<&IssueDisplay> = filtered
+                            .iter()
+                            .filter(|display| display.readiness == *column)
+                            .collect();
+                        egui::Frame::group(ui.style()).show(ui, |ui| {
+                            ui.set_width(SWIMLANE_CARD_WIDTH + 12.0);
+                            ui.vertical(|ui| {
+                                ui.horizontal(|ui| {
+                                    ui.label(egui::RichText::new(column).strong());
+                                    draw_count_badge(ui, column_issues.len());
+                                });
+                                ui.separator();
+                                egui::ScrollArea::vertical()
+                                    .id_salt(("board_column", column))
+                                    .show(ui, |ui| {
+                                        for display in &column_issues {
+                                            let card_rect = draw_swimlane_card(
+                                                ui,
+                                                &display.issue.id,
+                                                &display.issue.title,
+                                                selected_idx == Some(display.original_idx),
+                                            );
+                                            let response = ui.interact(
+                                                card_rect,
+                                                ui.id().with(("board_card", &display.issue.id)),
+                                                egui::Sense::click(),
+                                            );
+                                            if response.clicked() {
+                                                new_selected = Some(display.original_idx);
+                                            }
+                                        }
+                                    });
+                            });
+                        });
+                    }
+                });
+            });
+
+        if let Some(idx) = new_selected {
+            self.request_selection(Some(idx));
+        }
+    }
+
+    fn show_sprint_board_view(&mut self, ui: &mut egui::Ui) {
+        let filtered = self.filtered_and_sorted_issues();
+        let columns = sprint_board_columns(&filtered);
+        let lanes = sprint_board_lanes(&filtered);
+        let board = build_sprint_board(&filtered);
+
+        let theme_tokens = self.theme_tokens;
+        let selected_idx = self.selected_index;
+        let mut new_selected = None;
+
+        egui::ScrollArea::both()
+            .id_salt("sprint_board_scroll")
+            .show(ui, |ui| {
+                egui::Grid::new("sprint_board_grid")
+                    .striped(true)
+                    .spacing([8.0, 8.0])
+                    .show(ui, |ui| {
+                        ui.label("");
+                        for (col, column) in columns.iter().enumerate() {
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new(column).strong());
+                                let count = board[col].iter().map(Vec::len).sum();
+                                let limit = self.config.wip_limits.get(column).copied();
+                                draw_wip_badge(ui, count, limit, &theme_tokens);
+                            });
+                        }
+                        ui.end_row();
+
+                        for (lane_idx, lane) in lanes.iter().enumerate() {
+                            ui.label(
+                                egui::RichText::new(lane.as_deref().unwrap_or("(Unassigned)"))
+                                    .strong(),
+                            );
+                            for cell in board.iter().map(|lanes| &lanes[lane_idx]) {
+                                ui.vertical(|ui| {
+                                    ui.set_min_width(SWIMLANE_CARD_WIDTH + 12.0);
+                                    if !cell.is_empty() {
+                                        let limit = self.config.wip_limit_per_assignee;
+                                        draw_wip_badge(ui, cell.len(), limit, &theme_tokens);
+                                    }
+                                    for display in cell {
+                                        let card_rect = draw_swimlane_card(
+                                            ui,
+                                            &display.issue.id,
+                                            &display.issue.title,
+                                            selected_idx == Some(display.original_idx),
+                                        );
+                                        let response = ui.interact(
+                                            card_rect,
+                                            ui.id().with(("sprint_card", &display.issue.id)),
+                                            egui::Sense::click(),
+                                        );
+                                        if response.clicked() {
+                                            new_selected = Some(display.original_idx);
+                                        }
+                                    }
+                                });
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        if let Some(idx) = new_selected {
+            self.request_selection(Some(idx));
+        }
+    }
+
+    fn set_user_label_color(&mut self, idx: usize, color: Option<egui::Color32>) {
+        let Some(issue) = self.issues.get_mut(idx) else {
+            return;
+        };
+        issue.user_label_color = color.map(|c| c.to_hex());
+        match &issue.user_label_color {
+            Some(hex) => self.user_labels.insert(issue.id.clone(), hex.clone()),
+            None => self.user_labels.remove(&issue.id),
+        };
+        if let Err(e) = save_user_labels(&self.user_labels) {
+            self.error_message = Some(e);
+        }
+    }
+
+    fn set_assignee(&mut self, idx: usize, assignee: Option<String>) {
+        let Some(issue) = self.issues.get_mut(idx) else {
+            return;
+        };
+
+        let db_path = self
+            .snapshot_cache
+            .issue_sources
+            .get(&issue.id)
+            .and_then(|(_, path)| path.clone());
+
+        let value = assignee.as_deref().unwrap_or("");
+        match BdClient::update_issue(&issue.id, "assignee", value, db_path.as_ref()) {
+            Ok(_) => {
+                issue.assignee = assignee;
+                let issue_id = issue.id.clone();
+                let assignee = issue.assignee.clone();
+                // Keep the cached and open copies in sync with the list, and the
+                // save baseline too so a later save doesn't write the old assignee back
+                self.snapshot_cache.invalidate(&issue_id);
+                if let Some(original) = self.original_issue.as_mut() {
+                    if original.id == issue_id {
+                        original.assignee = assignee.clone();
+                    }
+                }
+                if let Some(current) = self.current_issue.as_mut() {
+                    if current.id == issue_id {
+                        current.assignee = assignee;
+                    }
+                }
+                self.assignee_workloads = compute_assignee_workloads(&self.issues);
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to reassign issue: {}", e));
+            }
+        }
+    }
+
+    fn set_due_date(&mut self, idx: usize, date: Option<chrono::NaiveDate>) {
+        let Some(issue) = self.issues.get_mut(idx) else {
+            return;
+        };
+        let date_str = date
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .unwrap_or_default();
+
+        let db_path = self
+            .snapshot_cache
+            .issue_sources
+            .get(&issue.id)
+            .and_then(|(_, path)| path.clone());
+
+        match BdClient::update_issue(&issue.id, "due_date", &date_str, db_path.as_ref()) {
+            Ok(_) => {
+                issue.due_date = date.map(|_| date_str);
+                let issue_id = issue.id.clone();
+                let due_date = issue.due_date.clone();
+                // Keep the cached and open copies in sync with the list
+                self.snapshot_cache.invalidate(&issue_id);
+                if let Some(current) = self.current_issue.as_mut() {
+                    if current.id == issue_id {
+                        current.due_date = due_date;
+                    }
+                }
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to set due date: {}", e));
+            }
+        }
+    }
+
+    fn sortable_header_ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        label: &str,
+        column: SortColumn,
+        cardinality: usize,
+        filter_action: &mut Option<(SortColumn, FilterAction)>,
+        hide_column: &mut Option<SortColumn>,
+    ) -> bool {
+        let mut text = label.to_string();
+
+        // Add filter indicator if column has active filters: • for exclusions, ◉ once an
+        // include-only set is in play
+        let mut indicator = None;
+        let mut lines = Vec::new();
+        if let Some(filter) = self.column_filters.get(&column) {
+            if !filter.included_values.is_empty() {
+                indicator = Some("◉");
+            } else if filter.has_active_filters() {
+                indicator = Some("•");
+            }
+            for (heading, values) in [
+                ("Including only", &filter.included_values),
+                ("Excluding", &filter.excluded_values),
+            ] {
+                if !values.is_empty() {
+                    let mut values: Vec<&str> = values.iter().map(String::as_str).collect();
+                    values.sort();
+                    lines.push(format!("{}: {}", heading, values.join(", ")));
+                }
+            }
+        }
+        // The top panel's priority range filters this column too
+        if column == SortColumn::Priority && self.priority_range != PRIORITY_RANGE_FULL {
+            let (min, max) = self.priority_range;
+            indicator.get_or_insert("•");
+            lines.push(format!("Range: P{}–P{}", min, max));
+        }
+        if let Some(indicator) = indicator {
+            text = format!("{} {}", text, indicator);
+        }
+        let filter_summary = (!lines.is_empty()).then(|| lines.join("\n"));
+
+        // Add sort indicator if this is a sort column, numbered by priority once there
+        // are several keys
+        let sort_pos = self.sort_keys.iter().position(|(key, _)| *key == column);
+        if let Some(pos) = sort_pos {
+            let arrow = if self.sort_keys[pos].1 { "▲" } else { "▼" };
+            if self.sort_keys.len() > 1 {
+                text = format!("{} {}{}", text, arrow, pos + 1);
+            } else {
+                text = format!("{} {}", text, arrow);
+            }
+        }
+
+        if sort_pos == Some(0) {
+            let remaining = self.resort_highlight_until - ui.input(|i| i.time);
+            if remaining > 0.0 {
+                let fade = (remaining / RESORT_HIGHLIGHT_SECS) as f32;
+                ui.painter().rect_filled(
+                    ui.max_rect(),
+                    2.0,
+                    self.theme_tokens.selected_bg.gamma_multiply(fade),
+                );
+                ui.ctx().request_repaint();
+            }
+        }
+
+        self.header_cell_rects.push(ui.max_rect());
+        let mut button_response = ui.button(text);
+        if let Some(summary) = filter_summary {
+            button_response = button_response.on_hover_text(summary);
+        }
+        let clicked = button_response.clicked();
+        if button_response.hovered() {
+            self.hovered_column = Some(column);
+            self.hovered_column_span = ui.max_rect().x_range();
+        }
+
+        // Skip value filter menu for ID and Title columns (always high cardinality)
+        let skip_filter_menu = matches!(column, SortColumn::Id | SortColumn::Title);
+
+        // Pre-compute values outside the closure to avoid borrow issues
+        let values: Vec<String> = if !skip_filter_menu && cardinality <= 20 {
+            let issues_clone = self.issues.clone();
+            let mut vals: Vec<String> = issues_clone
+                .iter()
+                .map(|issue| self.get_column_value(issue, column))
+                .collect::<std::collections::HashSet<_>>()
+                .into_iter()
+                .collect();
+            vals.sort();
+            vals
+        } else {
+            Vec::new()
+        };
+
+        let current_filter_excluded = self
+            .column_filters
+            .get(&column)
+            .map(|f| f.excluded_values.clone())
+            .unwrap_or_default();
+        let current_filter_included = self
+            .column_filters
+            .get(&column)
+            .map(|f| f.included_values.clone())
+            .unwrap_or_default();
+        let has_active_filters = self
+            .column_filters
+            .get(&column)
+            .is_some_and(|f| f.has_active_filters());
+        let current_patterns: Vec<String> = self
+            .column_filters
+            .get(&column)
+            .map(|f| {
+                f.excluded_patterns
+                    .iter()
+                    .map(|re| re.as_str().to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut pattern_to_add: Option<regex::Regex> = None;
+        let mut pattern_to_remove: Option<String> = None;
+
+        // Add context menu to header for filter management
+        button_response.context_menu(|ui| {
+            if !skip_filter_menu {
+                ui.label(format!("{} Column Filters", label));
+                ui.separator();
+
+                if cardinality > 20 {
+                    ui.label(format!("⚠ High cardinality ({} values)", cardinality));
+                    ui.label("Filtering not available");
+                } else {
+                    for value in &values {
+                        let is_excluded = current_filter_excluded.contains(value);
+                        let is_included = current_filter_included.contains(value);
+
+                        ui.horizontal(|ui| {
+                            ui.label(value);
+                            // Radio-style: each value is excluded, included only, or neither
+                            if ui.selectable_label(is_excluded, "Exclude").clicked() {
+                                *filter_action =
+                                    Some((column, FilterAction::Toggle(value.clone())));
+                            }
+                            if ui.selectable_label(is_included, "Include only").clicked() {
+                                *filter_action =
+                                    Some((column, FilterAction::ToggleInclude(value.clone())));
+                            }
+                            if ui
+                                .small_button("only")
+                                .on_hover_text("Show Only This Value")
+                                .clicked()
+                            {
+                                *filter_action =
+                                    Some((column, FilterAction::SetOnly(value.clone())));
+                            }
+                        });
+                    }
+                }
+
+                ui.separator();
+            }
+
+            // Regex exclusion patterns work regardless of cardinality
+            ui.label("Exclude values matching:");
+            if !current_patterns.is_empty() {
+                ui.horizontal_wrapped(|ui| {
+                    for pattern in &current_patterns {
+                        if ui
+                            .small_button(format!("/{}/ ✕", pattern))
+                            .on_hover_text("Remove pattern")
+                            .clicked()
+                        {
+                            pattern_to_remove = Some(pattern.clone());
+                        }
+                    }
+                });
+            }
+
+            let compiled = if self.filter_pattern_text.is_empty() {
+                None
+            } else {
+                Some(regex::Regex::new(&self.filter_pattern_text))
+            };
+            ui.horizontal(|ui| {
+                let text_edit = ui.add(
+                    egui::TextEdit::singleline(&mut self.filter_pattern_text)
+                        .hint_text("e.g. ^BEAD-1\\d\\d$")
+                        .desired_width(160.0),
+                );
+                let is_valid = matches!(compiled, Some(Ok(_)));
+                let submitted =
+                    text_edit.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                let add_clicked = ui
+                    .add_enabled(is_valid, egui::Button::new("Add Pattern"))
+                    .clicked();
+                if add_clicked || submitted {
+                    if let Some(Ok(re)) = &compiled {
+                        pattern_to_add = Some(re.clone());
+                    }
+                }
+            });
+            if let Some(Err(e)) = &compiled {
+                ui.colored_label(self.theme_tokens.error, format!("Invalid pattern: {}", e));
+            }
+
+            // Clears values and patterns alike, so it is offered at any cardinality
+            if has_active_filters {
+                ui.separator();
+                if ui.button("Clear all filters").clicked() {
+                    *filter_action = Some((column, FilterAction::ClearAll));
+                }
+            }
+
+            ui.separator();
+            if ui.button("Copy as Markdown Table").clicked() {
+                self.copy_markdown_requested = true;
+                ui.close_menu();
+            }
+
+            // Add "Hide column" option at the bottom
+            ui.separator();
+            if ui.button("Hide column").clicked() {
+                *hide_column = Some(column);
+                ui.close_menu();
+            }
+        });
+
+        if let Some(pattern) = pattern_to_add {
+            self.column_filters
+                .entry(column)
+                .or_default()
+                .add_pattern(pattern);
+            self.filter_pattern_text.clear();
+        }
+
+        if let Some(pattern) = pattern_to_remove {
+            if let Some(filter) = self.column_filters.get_mut(&column) {
+                filter.remove_pattern(&pattern);
+            }
+        }
+
+        clicked
+    }
+
+    fn show_detail_view_split(&mut self, _ctx: &egui::Context, ui: &mut egui::Ui, issue_id: &str) {
+        // The Dependents section and minimap need it even when the columns are hidden
+        self.ensure_dependents_map();
+
+        // Load issue if not already loaded or if different issue
+        if self.current_issue.is_none()
+            || self.current_issue.as_ref().map(|i| &i.id) != Some(&issue_id.to_string())
+        {
+            match self.snapshot_cache.get_issue(issue_id) {
+                Ok(issue) => {
+                    self.edit_tags_text = issue.tags.join(", ");
+                    // Back to where this issue was last read, or the top for a new one
+                    self.detail_scroll_restore = Some(
+                        self.detail_scroll_positions
+                            .get(issue_id)
+                            .copied()
+                            .unwrap_or(0.0),
+                    );
+                    // A refresh reloads the same issue and keeps the toggle
+                    if self.description_preview_id.as_deref() != Some(issue_id) {
+                        self.description_preview = true;
+                        self.description_preview_id = Some(issue_id.to_string());
+                    }
+                    self.original_issue = Some(issue.clone());
+                    self.current_issue = Some(issue);
+                    self.edit_modified = false;
+                    self.error_message = None;
+                }
+                Err(e) => {
+                    self.error_message = Some(format!("Error loading issue: {}", e));
+                    self.current_issue = None;
+                }
+            }
+        }
+
+        let mut should_save = false;
+        let mut should_refresh = false;
+        let mut nav_to_issue_idx = None;
+        let mut blocker_to_add: Option<String> = None;
+        let mut related_to_add: Option<String> = None;
+
+        // Opening an issue any other way than a detail link starts a new trail
+        if self.nav_history.last().map(String::as_str) != Some(issue_id) {
+            self.nav_history = vec![issue_id.to_string()];
+        }
+        let history = &self.nav_history[..self.nav_history.len() - 1];
+        let mut crumb_clicked = None;
+
+        // Add spacing at top to prevent overdraw with list panel
+        ui.add_space(4.0);
+
+        if !history.is_empty() {
+            ui.horizontal(|ui| {
+                let crumbs = breadcrumb_crumbs(history, issue_id, &self.issues);
+                for (i, (pos, label)) in crumbs.into_iter().enumerate() {
+                    if i > 0 {
+                        ui.weak("›");
+                    }
+                    match pos {
+                        Some(pos) => {
+                            if ui.link(label).on_hover_text(&history[pos]).clicked() {
+                                crumb_clicked = Some(pos);
+                            }
+                        }
+                        None => {
+                            ui.weak(label);
+                        }
+                    }
+                }
+            })
+            .response
+            .on_hover_text(render_breadcrumb(history, issue_id, &self.issues));
+        }
+
+        // Header
+        let copy_variants = self.current_issue.as_ref().map(issue_copy_variants);
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(format!("Issue: {}", issue_id)).strong());
+            let copy_response = ui
+                .small_button("📋")
+                .on_hover_text("Copy ID (right-click for more)");
+            if copy_response.clicked() {
+                self.copy_to_clipboard(ui.ctx(), issue_id.to_string());
+            }
+            if let Some(variants) = copy_variants {
+                copy_response.context_menu(|ui| {
+                    for (label, text) in variants {
+                        if ui.button(label).clicked() {
+                            self.copy_to_clipboard(ui.ctx(), text);
+                            ui.close_menu();
+                        }
+                    }
+                });
+            }
+            ui.separator();
+
+            if ui.button("Refresh").clicked() {
+                should_refresh = true;
+            }
+            if let Some(path) = self
+                .current_issue
+                .as_ref()
+                .map(|issue| issue.source_path.clone())
+                .filter(|path| !path.as_os_str().is_empty())
+            {
+                if ui.button("Open Terminal Here").clicked() {
+                    if let Err(e) = open_terminal(&path) {
+                        self.error_message = Some(format!("Failed to open terminal: {}", e));
+                    }
+                }
+            }
+
+            ui.separator();
+
+            if ui.button("🗑 Delete").clicked() {
+                self.pending_delete = Some(issue_id.to_string());
+            }
+            if self.edit_modified {
+                if ui.button("💾 Save").clicked() {
+                    should_save = true;
+                }
+                ui.colored_label(self.theme_tokens.warning, "Unsaved changes");
+            } else if let Some((saved_id, summary)) = &self.save_message {
+                if saved_id == issue_id {
+                    ui.weak(summary);
+                }
+            }
+        });
+
+        if let Some(ref error) = self.error_message {
+            ui.colored_label(self.theme_tokens.error, error);
+        }
 
-                                if is_row_hovered {
-                                    ui.painter().rect_filled(
-                                        rect,
-                                        0.0,
-                                        ui.visuals().widgets.hovered.bg_fill,
-                                    );
-                                }
+        ui.separator();
 
-                                let mut child_ui = ui.new_child(
-                                    egui::UiBuilder::new()
-                                        .max_rect(rect)
-                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
-                                );
-                                child_ui.set_clip_rect(rect);
-                                let status_text = &display.readiness;
-                                child_ui.add(egui::Label::new(status_text).selectable(false));
+        // Content
+        let mut detail_scroll = egui::ScrollArea::vertical().id_salt("detail_scroll");
+        if let Some(offset) = self.detail_scroll_restore.take() {
+            detail_scroll = detail_scroll.vertical_scroll_offset(offset);
+        }
+        let scroll_output = detail_scroll.show(ui, |ui| {
+            if let Some(ref mut issue) = self.current_issue {
+                ui.horizontal(|ui| {
+                    ui.label("ID:");
+                    ui.label(&issue.id);
+                });
 
-                                if response.clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
-                                if response.double_clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
+                ui.horizontal(|ui| {
+                    ui.label("Directory:");
+                    ui.label(&issue.source_directory);
+                });
 
-                                let status_value = status_text.clone();
-                                response.context_menu(|ui| {
-                                    if status_cardinality > 20 {
-                                        ui.label(format!(
-                                            "⚠ High cardinality ({} values)",
-                                            status_cardinality
-                                        ));
-                                        ui.label("Filtering not available");
-                                    } else {
-                                        let current_filter =
-                                            self.column_filters.get(&SortColumn::Status);
-                                        let is_filtered = current_filter
-                                            .map(|f| f.is_filtered(&status_value))
-                                            .unwrap_or(false);
+                ui.horizontal(|ui| {
+                    ui.label("Title:");
+                    let title_edit =
+                        egui::TextEdit::singleline(&mut issue.title).desired_width(f32::INFINITY);
+                    if ui.add(title_edit).changed() {
+                        self.edit_modified = true;
+                    }
+                });
 
-                                        if ui
-                                            .button(if is_filtered {
-                                                format!("✓ Include \"{}\"", status_value)
-                                            } else {
-                                                format!("✗ Exclude \"{}\"", status_value)
-                                            })
-                                            .clicked()
-                                        {
-                                            *filter_toggle =
-                                                Some((SortColumn::Status, status_value.clone()));
-                                            ui.close_menu();
-                                        }
-                                    }
-                                });
-                            });
+                ui.horizontal(|ui| {
+                    ui.label("Status:");
+                    let old_status = issue.status.clone();
+                    egui::ComboBox::from_id_salt("status_combo")
+                        .selected_text(&issue.status)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut issue.status, "open".to_string(), "open");
+                            ui.selectable_value(
+                                &mut issue.status,
+                                "in_progress".to_string(),
+                                "in_progress",
+                            );
+                            ui.selectable_value(&mut issue.status, "closed".to_string(), "closed");
+                        });
+                    if issue.status != old_status {
+                        match self.config.transition_rule(&old_status, &issue.status) {
+                            Some(rule) if rule.forbidden => {
+                                self.error_message = Some(format!(
+                                    "Moving from {} to {} is not allowed",
+                                    old_status, issue.status
+                                ));
+                                issue.status = old_status;
+                            }
+                            Some(rule) if rule.requires_comment => {
+                                // Keep the old status until a reason is given
+                                let new_status =
+                                    std::mem::replace(&mut issue.status, old_status.clone());
+                                self.pending_status_change =
+                                    Some((issue.id.clone(), old_status, new_status));
+                                self.status_change_reason.clear();
+                            }
+                            _ => self.edit_modified = true,
+                        }
+                    }
+                });
 
-                            row.col(|ui| {
-                                let available_size = ui.available_size();
-                                let (id, rect) = ui.allocate_space(available_size);
-                                let response = ui.interact(rect, id, egui::Sense::click());
+                ui.horizontal(|ui| {
+                    ui.label("Priority:");
+                    let old_priority = issue.priority;
+                    egui::ComboBox::from_id_salt("priority_combo")
+                        .selected_text(format!("P{}", issue.priority))
+                        .show_ui(ui, |ui| {
+                            for p in 0..=4 {
+                                ui.selectable_value(&mut issue.priority, p, format!("P{}", p));
+                            }
+                        });
+                    if issue.priority != old_priority {
+                        self.edit_modified = true;
+                    }
+                });
 
-                                if response.hovered() {
-                                    any_cell_hovered = true;
-                                }
+                ui.horizontal(|ui| {
+                    ui.label("Type:");
+                    // Standard types, then any others in use, always including the current one
+                    let mut types: Vec<&str> = KNOWN_ISSUE_TYPES.to_vec();
+                    let mut other_types: Vec<&str> = self
+                        .issues
+                        .iter()
+                        .map(|i| i.issue_type.as_str())
+                        .chain([issue.issue_type.as_str()])
+                        .filter(|t| !KNOWN_ISSUE_TYPES.contains(t))
+                        .collect();
+                    other_types.sort();
+                    other_types.dedup();
+                    types.extend(other_types);
+                    let types: Vec<String> = types.into_iter().map(String::from).collect();
+
+                    let old_type = issue.issue_type.clone();
+                    egui::ComboBox::from_id_salt("type_combo")
+                        .selected_text(&issue.issue_type)
+                        .show_ui(ui, |ui| {
+                            for t in types {
+                                let label = t.clone();
+                                ui.selectable_value(&mut issue.issue_type, t, label);
+                            }
+                        });
+                    if issue.issue_type != old_type {
+                        self.edit_modified = true;
+                    }
+                });
 
-                                if is_row_hovered {
-                                    ui.painter().rect_filled(
-                                        rect,
-                                        0.0,
-                                        ui.visuals().widgets.hovered.bg_fill,
-                                    );
-                                }
+                ui.horizontal(|ui| {
+                    ui.label("Assignee:");
+                    if let Some(raw) = &issue.assignee {
+                        let shown = format_assignee(raw, self.config.assignee_display);
+                        if shown != *raw {
+                            ui.label(egui::RichText::new(shown).strong());
+                        }
+                    }
+                    let mut assignee_text = issue.assignee.clone().unwrap_or_default();
+                    let assignee_edit =
+                        egui::TextEdit::singleline(&mut assignee_text).desired_width(f32::INFINITY);
+                    if ui.add(assignee_edit).changed() {
+                        issue.assignee = if assignee_text.is_empty() {
+                            None
+                        } else {
+                            Some(assignee_text)
+                        };
+                        self.edit_modified = true;
+                    }
+                });
 
-                                let mut child_ui = ui.new_child(
-                                    egui::UiBuilder::new()
-                                        .max_rect(rect)
-                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
-                                );
-                                child_ui.set_clip_rect(rect);
-                                let priority_text = format!("P{}", issue.priority);
-                                child_ui.add(egui::Label::new(&priority_text).selectable(false));
+                ui.horizontal(|ui| {
+                    ui.label("Created by:");
+                    ui.label(issue.created_by.as_deref().unwrap_or("-"));
+                });
 
-                                if response.clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
-                                if response.double_clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
+                ui.horizontal(|ui| {
+                    ui.label("Tags:");
+                    let tags_edit = TagAutocomplete::new(&self.all_tags, &mut self.edit_tags_text);
+                    if ui.add(tags_edit).changed() {
+                        issue.tags = parse_tags(&self.edit_tags_text);
+                        self.edit_modified = true;
+                    }
+                });
 
-                                let priority_value = priority_text.clone();
-                                response.context_menu(|ui| {
-                                    if priority_cardinality > 20 {
-                                        ui.label(format!(
-                                            "⚠ High cardinality ({} values)",
-                                            priority_cardinality
-                                        ));
-                                        ui.label("Filtering not available");
-                                    } else {
-                                        let current_filter =
-                                            self.column_filters.get(&SortColumn::Priority);
-                                        let is_filtered = current_filter
-                                            .map(|f| f.is_filtered(&priority_value))
-                                            .unwrap_or(false);
+                ui.horizontal(|ui| {
+                    ui.label("Created:");
+                    ui.label(&issue.created_at);
+                });
 
-                                        if ui
-                                            .button(if is_filtered {
-                                                format!("✓ Include \"{}\"", priority_value)
-                                            } else {
-                                                format!("✗ Exclude \"{}\"", priority_value)
-                                            })
-                                            .clicked()
-                                        {
-                                            *filter_toggle = Some((
-                                                SortColumn::Priority,
-                                                priority_value.clone(),
-                                            ));
-                                            ui.close_menu();
-                                        }
-                                    }
-                                });
-                            });
+                if issue.status != "closed" && !self.cycle_time_stats.samples.is_empty() {
+                    if let Some(created) = parse_timestamp(&issue.created_at) {
+                        let avg = chrono::Duration::seconds(
+                            (self.cycle_time_stats.mean * 86_400.0) as i64,
+                        );
+                        ui.horizontal(|ui| {
+                            ui.label("Estimated completion:");
+                            ui.label(format!("{}", (created + avg).format("%Y-%m-%d")))
+                                .on_hover_text("Created date plus the average cycle time");
+                        });
+                    }
+                }
 
-                            row.col(|ui| {
-                                let available_size = ui.available_size();
-                                let (id, rect) = ui.allocate_space(available_size);
-                                let response = ui.interact(rect, id, egui::Sense::click());
+                ui.horizontal(|ui| {
+                    ui.label("Updated:");
+                    ui.label(&issue.updated_at);
+                });
 
-                                if response.hovered() {
-                                    any_cell_hovered = true;
+                egui::CollapsingHeader::new(format!(
+                    "Custom Fields ({})",
+                    issue.custom_fields.len()
+                ))
+                .id_salt("custom_fields_section")
+                .show(ui, |ui| {
+                    let mut keys: Vec<String> = issue.custom_fields.keys().cloned().collect();
+                    keys.sort();
+                    let mut remove_key = None;
+                    egui::Grid::new("custom_fields_grid")
+                        .num_columns(3)
+                        .show(ui, |ui| {
+                            for key in &keys {
+                                ui.label(format!("{}:", key));
+                                if let Some(value) = issue.custom_fields.get_mut(key) {
+                                    if ui.text_edit_singleline(value).changed() {
+                                        self.edit_modified = true;
+                                    }
                                 }
-
-                                if is_row_hovered {
-                                    ui.painter().rect_filled(
-                                        rect,
-                                        0.0,
-                                        ui.visuals().widgets.hovered.bg_fill,
-                                    );
+                                if ui.small_button("Remove").clicked() {
+                                    remove_key = Some(key.clone());
                                 }
+                                ui.end_row();
+                            }
+                        });
+                    if let Some(key) = remove_key {
+                        issue.custom_fields.remove(&key);
+                        self.edit_modified = true;
+                    }
 
-                                let mut child_ui = ui.new_child(
-                                    egui::UiBuilder::new()
-                                        .max_rect(rect)
-                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.new_custom_field_key)
+                                .hint_text("Field name")
+                                .desired_width(120.0),
+                        );
+                        let key = self.new_custom_field_key.trim().to_string();
+                        let can_add = !key.is_empty() && !issue.custom_fields.contains_key(&key);
+                        if ui
+                            .add_enabled(can_add, egui::Button::new("Add Custom Field"))
+                            .clicked()
+                        {
+                            issue.custom_fields.insert(key, String::new());
+                            self.new_custom_field_key.clear();
+                            self.edit_modified = true;
+                        }
+                    });
+                });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.label("Description:");
+                    ui.selectable_value(&mut self.description_preview, true, "Preview");
+                    ui.selectable_value(&mut self.description_preview, false, "Edit");
+                });
+                // Grows with the text up to about a dozen lines, then scrolls
+                egui::ScrollArea::vertical()
+                    .id_salt("description_scroll")
+                    .max_height(DESCRIPTION_MAX_HEIGHT)
+                    .show(ui, |ui| {
+                        if self.description_preview {
+                            if issue.description.trim().is_empty() {
+                                ui.weak("No description");
+                            } else {
+                                CommonMarkViewer::new().show(
+                                    ui,
+                                    &mut self.markdown_cache,
+                                    &issue.description,
                                 );
-                                child_ui.set_clip_rect(rect);
-                                child_ui.add(egui::Label::new(&issue.issue_type).selectable(false));
+                            }
+                            return;
+                        }
+                        let description_edit = egui::TextEdit::multiline(&mut issue.description)
+                            .desired_width(f32::INFINITY)
+                            .desired_rows(4)
+                            .id_source("description_edit");
+                        let description_response = ui.add(description_edit);
+                        if description_response.changed() {
+                            self.edit_modified = true;
+                            // Request focus to prevent losing it when Save button appears
+                            description_response.request_focus();
+                        }
+                    });
 
-                                if response.clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
-                                if response.double_clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
+                ui.separator();
+                ui.label("Notes:");
+                let mut notes_text = issue.notes.clone().unwrap_or_default();
+                let notes_edit = egui::TextEdit::multiline(&mut notes_text)
+                    .desired_width(f32::INFINITY)
+                    .id_source("notes_edit");
+                let notes_response = ui.add(notes_edit);
+                if notes_response.changed() {
+                    issue.notes = if notes_text.is_empty() {
+                        None
+                    } else {
+                        Some(notes_text)
+                    };
+                    self.edit_modified = true;
+                    // Request focus to prevent losing it when Save button appears
+                    notes_response.request_focus();
+                }
 
-                                let type_value = issue.issue_type.clone();
-                                response.context_menu(|ui| {
-                                    if type_cardinality > 20 {
-                                        ui.label(format!(
-                                            "⚠ High cardinality ({} values)",
-                                            type_cardinality
-                                        ));
-                                        ui.label("Filtering not available");
-                                    } else {
-                                        let current_filter =
-                                            self.column_filters.get(&SortColumn::Type);
-                                        let is_filtered = current_filter
-                                            .map(|f| f.is_filtered(&type_value))
-                                            .unwrap_or(false);
+                let checklist = parse_checklist_items(issue.notes.as_deref().unwrap_or(""));
+                if !checklist.is_empty() {
+                    let done = checklist.iter().filter(|item| item.checked).count();
+                    let progress = format!("{}/{} done", done, checklist.len());
+                    ui.label(egui::RichText::new(progress).weak());
+                    for mut item in checklist {
+                        if ui.checkbox(&mut item.checked, &item.text).changed() {
+                            let notes = issue.notes.as_deref().unwrap_or("");
+                            issue.notes =
+                                Some(set_checklist_item(notes, item.line_index, item.checked));
+                            self.edit_modified = true;
+                        }
+                    }
+                }
 
-                                        if ui
-                                            .button(if is_filtered {
-                                                format!("✓ Include \"{}\"", type_value)
-                                            } else {
-                                                format!("✗ Exclude \"{}\"", type_value)
-                                            })
-                                            .clicked()
-                                        {
-                                            *filter_toggle =
-                                                Some((SortColumn::Type, type_value.clone()));
-                                            ui.close_menu();
-                                        }
-                                    }
-                                });
-                            });
+                if !issue.comments.is_empty() {
+                    ui.separator();
+                    ui.label(format!(
+                        "Comments ({} unresolved):",
+                        unresolved_comment_count(issue)
+                    ));
+                    for comment in &mut issue.comments {
+                        ui.horizontal(|ui| {
+                            if ui.checkbox(&mut comment.resolved, "Resolve").changed() {
+                                self.edit_modified = true;
+                            }
+                            ui.label(
+                                egui::RichText::new(format!(
+                                    "{} {}",
+                                    comment.author, comment.created_at
+                                ))
+                                .small()
+                                .weak(),
+                            );
+                        });
+                        let text = egui::RichText::new(&comment.text);
+                        ui.label(if comment.resolved { text.weak() } else { text });
+                    }
+                }
 
-                            row.col(|ui| {
-                                let available_size = ui.available_size();
-                                let (id, rect) = ui.allocate_space(available_size);
-                                let response = ui.interact(rect, id, egui::Sense::click());
+                // Separate dependencies into open/in_progress and closed
+                let (open_blockers, closed_blockers): (Vec<_>, Vec<_>) = issue
+                    .dependencies
+                    .iter()
+                    .partition(|dep| dep.status != "closed");
 
-                                if response.hovered() {
-                                    any_cell_hovered = true;
+                // Mini-map of direct blockers and dependents for quick orientation
+                ui.separator();
+                let blocker_ids: Vec<String> = issue
+                    .dependencies
+                    .iter()
+                    .map(|dep| dep.id.clone())
+                    .collect();
+                let dependent_ids = self
+                    .dependents_map
+                    .get(&issue.id)
+                    .cloned()
+                    .unwrap_or_default();
+                if let Some(clicked_id) =
+                    draw_dependency_minimap(ui, &issue.id, &blocker_ids, &dependent_ids)
+                {
+                    if let Some(idx) = self.issues.iter().position(|i| i.id == clicked_id) {
+                        nav_to_issue_idx = Some(idx);
+                    }
+                }
+
+                // Always show Blockers section (issues that must be completed before this one)
+                ui.separator();
+                ui.label("Blockers (issues blocking this one):");
+                if open_blockers.is_empty() {
+                    ui.label("  None");
+                } else {
+                    for dep in open_blockers {
+                        ui.horizontal(|ui| {
+                            ui.add_space(8.0);
+                            if ui.button(&dep.id).clicked() {
+                                // Find the index of this dependency in the issues list
+                                if let Some(dep_idx) =
+                                    self.issues.iter().position(|i| i.id == dep.id)
+                                {
+                                    nav_to_issue_idx = Some(dep_idx);
                                 }
+                            }
+                            ui.label(format!("- {}", dep.title));
+                            // Add remove button - shows confirmation dialog
+                            if ui
+                                .small_button("✕")
+                                .on_hover_text("Remove blocker")
+                                .clicked()
+                            {
+                                self.pending_blocker_removal = Some((
+                                    issue.id.clone(),
+                                    issue.title.clone(),
+                                    dep.id.clone(),
+                                    dep.title.clone(),
+                                ));
+                            }
+                        });
+                    }
+                }
+
+                // Add blocker UI: pick any other loaded issue, searching by id or title
+                egui::ComboBox::from_id_salt("add_blocker_combo")
+                    .selected_text("Add blocker…")
+                    .width(240.0)
+                    .height(300.0)
+                    .show_ui(ui, |ui| {
+                        let search = ui.add(
+                            egui::TextEdit::singleline(&mut self.add_blocker_text)
+                                .hint_text("Search id or title"),
+                        );
+                        search.request_focus();
+                        let needle = self.add_blocker_text.trim().to_lowercase();
+                        let candidates: Vec<&Issue> = self
+                            .issues
+                            .iter()
+                            .filter(|other| {
+                                other.id != issue.id
+                                    && !issue.dependencies.iter().any(|dep| dep.id == other.id)
+                                    && (other.id.to_lowercase().contains(&needle)
+                                        || other.title.to_lowercase().contains(&needle))
+                            })
+                            .take(BLOCKER_PICKER_LIMIT)
+                            .collect();
+                        if candidates.is_empty() {
+                            ui.weak("No matching issues");
+                        }
+                        // Enter picks the first match
+                        if ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                            if let Some(first) = candidates.first() {
+                                blocker_to_add = Some(first.id.clone());
+                                ui.memory_mut(|m| m.close_popup());
+                            }
+                        }
+                        for other in candidates {
+                            let label = format!("{} - {}", other.id, other.title);
+                            if ui.selectable_label(false, label).clicked() {
+                                blocker_to_add = Some(other.id.clone());
+                            }
+                        }
+                    });
+                if blocker_to_add.is_some() {
+                    self.add_blocker_text.clear();
+                }
 
-                                if is_row_hovered {
-                                    ui.painter().rect_filled(
-                                        rect,
-                                        0.0,
-                                        ui.visuals().widgets.hovered.bg_fill,
-                                    );
+                // Show resolved dependencies (closed blockers)
+                if !closed_blockers.is_empty() {
+                    ui.separator();
+                    ui.label("Resolved Dependencies:");
+                    for dep in closed_blockers {
+                        ui.horizontal(|ui| {
+                            ui.add_space(8.0);
+                            if ui.button(&dep.id).clicked() {
+                                // Find the index of this dependency in the issues list
+                                if let Some(dep_idx) =
+                                    self.issues.iter().position(|i| i.id == dep.id)
+                                {
+                                    nav_to_issue_idx = Some(dep_idx);
                                 }
+                            }
+                            ui.label(format!("- {}", dep.title));
+                            // Add remove button - shows confirmation dialog
+                            if ui
+                                .small_button("✕")
+                                .on_hover_text("Remove blocker")
+                                .clicked()
+                            {
+                                self.pending_blocker_removal = Some((
+                                    issue.id.clone(),
+                                    issue.title.clone(),
+                                    dep.id.clone(),
+                                    dep.title.clone(),
+                                ));
+                            }
+                        });
+                    }
+                }
 
-                                let mut child_ui = ui.new_child(
-                                    egui::UiBuilder::new()
-                                        .max_rect(rect)
-                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
-                                );
-                                child_ui.set_clip_rect(rect);
-                                let assignee_text =
-                                    issue.assignee.as_ref().unwrap_or(&"-".to_string()).clone();
-                                child_ui.add(egui::Label::new(&assignee_text).selectable(false));
-
-                                if response.clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
-                                if response.double_clicked() {
-                                    *new_selected = Some(Some(original_idx));
+                // Always show Dependents section (issues blocked by this one)
+                ui.separator();
+                ui.label("Dependents (issues blocked by this one):");
+                if let Some(dependent_ids) = self.dependents_map.get(&issue.id) {
+                    for dependent_id in dependent_ids {
+                        if let Some(dependent) = self.issues.iter().find(|i| &i.id == dependent_id)
+                        {
+                            ui.horizontal(|ui| {
+                                ui.add_space(8.0);
+                                if ui.button(&dependent.id).clicked() {
+                                    // Find the index of this dependent in the issues list
+                                    if let Some(dep_idx) =
+                                        self.issues.iter().position(|i| i.id == dependent.id)
+                                    {
+                                        nav_to_issue_idx = Some(dep_idx);
+                                    }
                                 }
+                                ui.label(format!("- {}", dependent.title));
+                            });
+                        }
+                    }
+                } else {
+                    ui.label("  None");
+                }
 
-                                let assignee_value = assignee_text.clone();
-                                response.context_menu(|ui| {
-                                    if assignee_cardinality > 20 {
-                                        ui.label(format!(
-                                            "⚠ High cardinality ({} values)",
-                                            assignee_cardinality
-                                        ));
-                                        ui.label("Filtering not available");
-                                    } else {
-                                        let current_filter =
-                                            self.column_filters.get(&SortColumn::Assignee);
-                                        let is_filtered = current_filter
-                                            .map(|f| f.is_filtered(&assignee_value))
-                                            .unwrap_or(false);
-
-                                        if ui
-                                            .button(if is_filtered {
-                                                format!("✓ Include \"{}\"", assignee_value)
-                                            } else {
-                                                format!("✗ Exclude \"{}\"", assignee_value)
-                                            })
-                                            .clicked()
-                                        {
-                                            *filter_toggle = Some((
-                                                SortColumn::Assignee,
-                                                assignee_value.clone(),
-                                            ));
-                                            ui.close_menu();
+                // Issues mentioned in the text that aren't linked yet
+                let known_ids: HashSet<String> = self.issues.iter().map(|i| i.id.clone()).collect();
+                let mut suggestions: Vec<(String, &str)> = Vec::new();
+                let notes = issue.notes.as_deref().unwrap_or("");
+                let fields = [
+                    (issue.description.as_str(), "description"),
+                    (notes, "notes"),
+                ];
+                for (text, field) in fields {
+                    for id in extract_issue_refs(text, &known_ids) {
+                        let linked = id == issue.id
+                            || issue.dependencies.iter().any(|dep| dep.id == id)
+                            || suggestions.iter().any(|(other, _)| *other == id);
+                        if !linked {
+                            suggestions.push((id, field));
+                        }
+                    }
+                }
+                if !suggestions.is_empty() {
+                    ui.separator();
+                    egui::CollapsingHeader::new(format!("Suggested Links ({})", suggestions.len()))
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            for (id, field) in &suggestions {
+                                egui::Frame::group(ui.style()).show(ui, |ui| {
+                                    ui.label(format!(
+                                        "🔗 {} found in {} – Add as dependency?",
+                                        id, field
+                                    ));
+                                    ui.horizontal(|ui| {
+                                        if ui.button("Add Blocker").clicked() {
+                                            blocker_to_add = Some(id.clone());
                                         }
-                                    }
+                                        if ui.button("Mark Related").clicked() {
+                                            related_to_add = Some(id.clone());
+                                        }
+                                    });
                                 });
-                            });
+                            }
+                        });
+                }
+            }
+        });
+        self.detail_scroll_positions
+            .insert(issue_id.to_string(), scroll_output.state.offset.y);
 
-                            // Blockers column
-                            row.col(|ui| {
-                                let available_size = ui.available_size();
-                                let (id, rect) = ui.allocate_space(available_size);
-                                let response = ui.interact(rect, id, egui::Sense::click());
+        // Handle actions after borrowing
+        if should_refresh {
+            self.current_issue = None;
+            self.edit_modified = false;
+        }
 
-                                if response.hovered() {
-                                    any_cell_hovered = true;
-                                }
+        if should_save {
+            if let Some(issue) = self.current_issue.clone() {
+                self.save_issue_changes(&issue);
+            }
+        }
 
-                                if is_row_hovered {
-                                    ui.painter().rect_filled(
-                                        rect,
-                                        0.0,
-                                        ui.visuals().widgets.hovered.bg_fill,
-                                    );
-                                }
+        if let Some(pos) = crumb_clicked {
+            let target = &self.nav_history[pos];
+            nav_to_issue_idx = self.issues.iter().position(|i| &i.id == target);
+        }
 
-                                let mut child_ui = ui.new_child(
-                                    egui::UiBuilder::new()
-                                        .max_rect(rect)
-                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
-                                );
-                                child_ui.set_clip_rect(rect);
-                                let blockers_count = display.blockers_count;
-                                child_ui.add(
-                                    egui::Label::new(blockers_count.to_string()).selectable(false),
-                                );
+        if let Some(new_idx) = nav_to_issue_idx {
+            self.request_selection(Some(new_idx));
+            // The trail only moves once the unsaved-changes prompt, if any, is past
+            if self.selected_index == Some(new_idx) {
+                match crumb_clicked {
+                    Some(pos) => self.nav_history.truncate(pos + 1),
+                    None => self.nav_history.push(self.issues[new_idx].id.clone()),
+                }
+            }
+        }
 
-                                if response.clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
-                                if response.double_clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
-                            });
+        // Handle blocker addition
+        if let Some(blocker_id) = blocker_to_add {
+            if let Some(issue) = self.current_issue.as_ref().filter(|i| i.id == blocker_id) {
+                self.error_message = Some(format!("{} can't block itself", issue.id));
+            } else if let Some(issue) = &self.current_issue {
+                // Look up the db_path for this issue from the snapshot cache
+                let db_path = self
+                    .snapshot_cache
+                    .issue_sources
+                    .get(&issue.id)
+                    .and_then(|(_, path)| path.clone());
 
-                            // Dependents column
-                            row.col(|ui| {
-                                let available_size = ui.available_size();
-                                let (id, rect) = ui.allocate_space(available_size);
-                                let response = ui.interact(rect, id, egui::Sense::click());
+                match BdClient::add_dependency(&issue.id, &blocker_id, db_path.as_ref()) {
+                    Ok(_) => {
+                        // Refresh the current issue and the list
+                        self.current_issue = None;
+                        self.refresh();
+                    }
+                    Err(e) => {
+                        self.error_message = Some(format!("Failed to add blocker: {}", e));
+                    }
+                }
+            }
+        }
 
-                                if response.hovered() {
-                                    any_cell_hovered = true;
-                                }
+        if let Some(related_id) = related_to_add {
+            if let Some(issue) = &self.current_issue {
+                let db_path = self
+                    .snapshot_cache
+                    .issue_sources
+                    .get(&issue.id)
+                    .and_then(|(_, path)| path.clone());
 
-                                if is_row_hovered {
-                                    ui.painter().rect_filled(
-                                        rect,
-                                        0.0,
-                                        ui.visuals().widgets.hovered.bg_fill,
-                                    );
-                                }
+                match BdClient::add_dependency_of_type(
+                    &issue.id,
+                    &related_id,
+                    "related",
+                    db_path.as_ref(),
+                ) {
+                    Ok(_) => {
+                        self.current_issue = None;
+                        self.refresh();
+                    }
+                    Err(e) => {
+                        self.error_message = Some(format!("Failed to add related link: {}", e));
+                    }
+                }
+            }
+        }
+    }
 
-                                let mut child_ui = ui.new_child(
-                                    egui::UiBuilder::new()
-                                        .max_rect(rect)
-                                        .layout(egui::Layout::left_to_right(egui::Align::Center)),
-                                );
-                                child_ui.set_clip_rect(rect);
-                                let dependents_count = display.dependents_count;
-                                child_ui.add(
-                                    egui::Label::new(dependents_count.to_string())
-                                        .selectable(false),
-                                );
+    fn show_settings_window(&mut self, ctx: &egui::Context) {
+        let mut config_changed = false;
+        let mut open = self.show_settings;
+        let names_before: HashMap<PathBuf, String> = self
+            .config
+            .directories
+            .iter()
+            .map(|d| (d.path.clone(), d.display_name.clone()))
+            .collect();
 
-                                if response.clicked() {
-                                    *new_selected = Some(Some(original_idx));
-                                }
-                                if response.double_clicked() {
-                                    *new_selected = Some(Some(original_idx));
+        egui::Window::new("Settings")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(420.0)
+            .show(ctx, |ui| {
+                ui.heading("Directories");
+                egui::Grid::new("settings_directories_grid")
+                    .num_columns(3)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for dir in &mut self.config.directories {
+                            ui.label(AppConfig::abbreviate_path(&dir.path));
+                            let name_response = ui.add(
+                                egui::TextEdit::singleline(&mut dir.display_name)
+                                    .desired_width(160.0),
+                            );
+                            if name_response.changed() {
+                                dir.custom_display_name = true;
+                            }
+                            if name_response.lost_focus() {
+                                config_changed = true;
+                            }
+                            if dir.custom_display_name {
+                                if ui
+                                    .small_button("Reset")
+                                    .on_hover_text("Use the automatic name")
+                                    .clicked()
+                                {
+                                    dir.custom_display_name = false;
+                                    config_changed = true;
                                 }
-                            });
-
-                            if any_cell_hovered {
-                                *new_hovered_row = Some(Some(original_idx));
+                            } else {
+                                ui.label("");
                             }
+                            ui.end_row();
                         }
                     });
-                });
-        }); // Close ScrollArea
-    }
-
-    fn sortable_header_ui(
-        &mut self,
-        ui: &mut egui::Ui,
-        label: &str,
-        column: SortColumn,
-        cardinality: usize,
-        filter_toggle: &mut Option<(SortColumn, String)>,
-        hide_column: &mut Option<SortColumn>,
-    ) -> bool {
-        let mut text = label.to_string();
-
-        // Add filter indicator if column has active filters
-        if let Some(filter) = self.column_filters.get(&column) {
-            if filter.has_active_filters() {
-                text = format!("{} •", text);
-            }
-        }
 
-        // Add sort indicator if this is the sort column
-        if self.sort_by == column {
-            text = format!("{} {}", text, if self.sort_ascending { "▲" } else { "▼" });
-        }
+                ui.separator();
+                ui.heading("Personal");
+                egui::Grid::new("settings_user_grid")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Current user:");
+                        let mut user = self.config.current_user.clone().unwrap_or_default();
+                        let user_response = ui.add(
+                            egui::TextEdit::singleline(&mut user)
+                                .hint_text("assignee name")
+                                .desired_width(160.0),
+                        );
+                        if user_response.changed() {
+                            self.config.current_user =
+                                if user.is_empty() { None } else { Some(user) };
+                        }
+                        if user_response.lost_focus() {
+                            config_changed = true;
+                        }
+                        ui.end_row();
 
-        let button_response = ui.button(text);
-        let clicked = button_response.clicked();
+                        ui.label("My Issues:");
+                        if ui
+                            .checkbox(
+                                &mut self.config.my_issues_includes_created,
+                                "Include issues I created",
+                            )
+                            .changed()
+                        {
+                            config_changed = true;
+                        }
+                        ui.end_row();
+
+                        ui.label("Show assignees as:");
+                        let format_label = |format: AssigneeDisplayFormat| match format {
+                            AssigneeDisplayFormat::Raw => "As stored",
+                            AssigneeDisplayFormat::Initials => "Initials",
+                            AssigneeDisplayFormat::FirstName => "First name",
+                            AssigneeDisplayFormat::TruncatedEmail => "Email without domain",
+                        };
+                        egui::ComboBox::from_id_salt("assignee_display_format")
+                            .selected_text(format_label(self.config.assignee_display))
+                            .show_ui(ui, |ui| {
+                                for format in [
+                                    AssigneeDisplayFormat::Raw,
+                                    AssigneeDisplayFormat::Initials,
+                                    AssigneeDisplayFormat::FirstName,
+                                    AssigneeDisplayFormat::TruncatedEmail,
+                                ] {
+                                    if ui
+                                        .selectable_value(
+                                            &mut self.config.assignee_display,
+                                            format,
+                                            format_label(format),
+                                        )
+                                        .changed()
+                                    {
+                                        config_changed = true;
+                                    }
+                                }
+                            });
+                        ui.end_row();
 
-        // Skip filter menu for ID and Title columns (always high cardinality)
-        let skip_filter_menu = matches!(column, SortColumn::Id | SortColumn::Title);
+                        ui.label("My in-progress rows:");
+                        let mut color = self.config.my_active_issue_fill();
+                        if ui.color_edit_button_srgba(&mut color).changed() {
+                            self.config.my_active_issue_color = color.to_hex();
+                            config_changed = true;
+                        }
+                        ui.end_row();
+                    });
 
-        // Add context menu to header for filter management
-        if !skip_filter_menu {
-            // Pre-compute values outside the closure to avoid borrow issues
-            let values: Vec<String> = if cardinality <= 20 {
-                let issues_clone = self.issues.clone();
-                let mut vals: Vec<String> = issues_clone
-                    .iter()
-                    .map(|issue| self.get_column_value(issue, column))
-                    .collect::<std::collections::HashSet<_>>()
-                    .into_iter()
-                    .collect();
-                vals.sort();
-                vals
-            } else {
-                Vec::new()
-            };
+                ui.separator();
+                ui.heading("Layout");
+                ui.horizontal(|ui| {
+                    ui.label("Sidebar position:");
+                    for (position, label) in [
+                        (SidebarPosition::Left, "Left"),
+                        (SidebarPosition::Right, "Right"),
+                    ] {
+                        if ui
+                            .radio_value(&mut self.config.sidebar_position, position, label)
+                            .changed()
+                        {
+                            config_changed = true;
+                        }
+                    }
+                });
 
-            let current_filter_excluded = self
-                .column_filters
-                .get(&column)
-                .map(|f| f.excluded_values.clone())
-                .unwrap_or_default();
-            let has_active_filters = !current_filter_excluded.is_empty();
+                ui.separator();
+                ui.heading("System Tray");
+                let tray_supported = cfg!(feature = "tray");
+                let tray_checkbox = ui.add_enabled(
+                    tray_supported,
+                    egui::Checkbox::new(
+                        &mut self.config.systray_enabled,
+                        "Show tray icon with in-progress count",
+                    ),
+                );
+                if !tray_supported {
+                    tray_checkbox
+                        .on_disabled_hover_text("This build was compiled without tray support");
+                } else if tray_checkbox.changed() {
+                    config_changed = true;
+                }
 
-            button_response.context_menu(|ui| {
-                ui.label(format!("{} Column Filters", label));
                 ui.separator();
+                ui.heading("Table");
+                if ui
+                    .checkbox(
+                        &mut self.config.auto_hide_single_directory_column,
+                        "Hide Directory column when only one directory is visible",
+                    )
+                    .changed()
+                {
+                    config_changed = true;
+                }
 
-                if cardinality > 20 {
-                    ui.label(format!("⚠ High cardinality ({} values)", cardinality));
-                    ui.label("Filtering not available");
-                } else {
-                    for value in &values {
-                        let is_filtered = current_filter_excluded.contains(value);
-
+                ui.horizontal(|ui| {
+                    ui.label("Long titles:");
+                    for (wrap, label) in [(false, "Truncate"), (true, "Wrap")] {
                         if ui
-                            .button(if is_filtered {
-                                format!("☐ {}", value)
-                            } else {
-                                format!("☑ {}", value)
-                            })
-                            .clicked()
+                            .radio_value(&mut self.config.wrap_titles, wrap, label)
+                            .changed()
                         {
-                            *filter_toggle = Some((column, value.clone()));
+                            config_changed = true;
                         }
                     }
-
-                    // Add "Clear all filters" option if there are active filters
-                    if has_active_filters {
-                        ui.separator();
-                        if ui.button("Clear all filters").clicked() {
-                            // Toggle the first filtered value to clear it
-                            if let Some(excluded_value) = current_filter_excluded.iter().next() {
-                                *filter_toggle = Some((column, excluded_value.clone()));
-                            }
+                    if !self.config.wrap_titles {
+                        ui.label("after");
+                        if ui
+                            .add(
+                                egui::DragValue::new(&mut self.config.title_max_chars)
+                                    .range(10..=500)
+                                    .suffix(" chars"),
+                            )
+                            .changed()
+                        {
+                            config_changed = true;
                         }
                     }
-                }
+                });
 
-                // Add "Hide column" option at the bottom
                 ui.separator();
-                if ui.button("Hide column").clicked() {
-                    *hide_column = Some(column);
-                    ui.close_menu();
+                ui.heading("Storage");
+                if let Some(path) = self.config.save_path() {
+                    ui.label(format!("Config file: {}", AppConfig::abbreviate_path(path)));
                 }
-            });
-        } else {
-            // Even for ID and Title, show context menu with just "Hide column"
-            button_response.context_menu(|ui| {
-                if ui.button("Hide column").clicked() {
-                    *hide_column = Some(column);
-                    ui.close_menu();
+                if !self.config.portable {
+                    let can_make_portable = !self.config.directories.is_empty();
+                    if ui
+                        .add_enabled(can_make_portable, egui::Button::new("Make Portable"))
+                        .on_hover_text(
+                            "Copy this config to .beadui/config.yaml in the first directory \
+                             so it can be committed with the project",
+                        )
+                        .clicked()
+                    {
+                        self.config.portable = true;
+                        // The global copy now leads launches from other directories to
+                        // the portable one
+                        if let Some(global_path) = AppConfig::global_config_path() {
+                            let _ = self.config.save_to(&global_path);
+                        }
+                        config_changed = true;
+                    }
                 }
             });
-        }
 
-        clicked
-    }
+        self.show_settings = open;
 
-    fn show_detail_view_split(&mut self, _ctx: &egui::Context, ui: &mut egui::Ui, issue_id: &str) {
-        // Load issue if not already loaded or if different issue
-        if self.current_issue.is_none()
-            || self.current_issue.as_ref().map(|i| &i.id) != Some(&issue_id.to_string())
-        {
-            match self.snapshot_cache.get_issue(issue_id) {
-                Ok(issue) => {
-                    self.current_issue = Some(issue);
-                    self.edit_modified = false;
-                    self.error_message = None;
-                }
-                Err(e) => {
-                    self.error_message = Some(format!("Error loading issue: {}", e));
-                    self.current_issue = None;
+        if config_changed {
+            // Recompute automatic names (custom names are left alone)
+            self.config.compute_display_names();
+            let _ = self.config.save();
+        }
+
+        // Directory filters hold display names, so follow any rename
+        if let Some(filter) = self.column_filters.get_mut(&SortColumn::Directory) {
+            for dir in &self.config.directories {
+                match names_before.get(&dir.path) {
+                    Some(before) if *before != dir.display_name => {
+                        filter.rename_value(before, &dir.display_name);
+                    }
+                    _ => {}
                 }
             }
         }
+    }
 
-        let mut should_save = false;
-        let mut should_refresh = false;
-        let mut nav_to_issue_idx = None;
-        let mut blocker_to_add: Option<String> = None;
+    fn show_stats_window(&mut self, ctx: &egui::Context) {
+        let stats = self.summary_stats;
+        let my_active_count = self
+            .issues
+            .iter()
+            .filter(|issue| is_my_active_issue(issue, &self.config))
+            .count();
 
-        // Add spacing at top to prevent overdraw with list panel
-        ui.add_space(4.0);
+        egui::Window::new("Stats")
+            .open(&mut self.show_stats)
+            .collapsible(true)
+            .resizable(true)
+            .default_width(300.0)
+            .show(ctx, |ui| {
+                egui::Grid::new("stats_summary_grid")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        for (label, value) in [
+                            ("Total", stats.total),
+                            ("Closed", stats.closed),
+                            ("In progress", stats.in_progress),
+                            ("Blocked", stats.blocked),
+                            ("Stale open", stats.stale_open),
+                        ] {
+                            ui.label(label);
+                            ui.label(value.to_string());
+                            ui.end_row();
+                        }
+                    });
 
-        // Header
-        ui.horizontal(|ui| {
-            ui.label(egui::RichText::new(format!("Issue: {}", issue_id)).strong());
-            ui.separator();
+                let top_loaded = sorted_workloads(&self.assignee_workloads);
+                if !top_loaded.is_empty() {
+                    ui.separator();
+                    ui.label(egui::RichText::new("Most loaded assignees").strong());
+                    for (name, count) in top_loaded.into_iter().take(3) {
+                        ui.label(format!("{} - {} open", name, count));
+                    }
+                }
 
-            if ui.button("Refresh").clicked() {
-                should_refresh = true;
-            }
+                if let Some(days) = self.config.time_horizon_days {
+                    ui.separator();
+                    ui.label(format!("Showing issues from the last {} days", days));
+                }
 
-            ui.separator();
+                let cycle = &self.cycle_time_stats;
+                if !cycle.samples.is_empty() {
+                    ui.separator();
+                    ui.label(format!("Avg cycle time: {:.1} days", cycle.mean));
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "Median {:.1} days, P90 {:.1} days over {} closed",
+                            cycle.median,
+                            cycle.p90,
+                            cycle.samples.len()
+                        ))
+                        .weak(),
+                    );
+                    draw_count_chart(ui, "stats_cycle_time_chart", &cycle.histogram());
+                }
 
-            if self.edit_modified {
-                if ui.button("💾 Save").clicked() {
-                    should_save = true;
+                ui.separator();
+                ui.label(egui::RichText::new("Burn Estimate").strong());
+                match &self.burn_estimate {
+                    Some(burn) => {
+                        ui.label(format!(
+                            "At current velocity (~{:.1} closes/day), remaining open issues \
+                             will be done in ~{:.0} days",
+                            burn.velocity_per_day, burn.estimated_days_remaining
+                        ));
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "Around {}",
+                                burn.estimated_completion.format("%Y-%m-%d")
+                            ))
+                            .weak(),
+                        );
+                    }
+                    None => {
+                        ui.label("Insufficient data")
+                            .on_hover_text("Needs at least 3 issues closed this week");
+                    }
                 }
-                ui.colored_label(egui::Color32::YELLOW, "Unsaved changes");
-            }
-        });
 
-        if let Some(ref error) = self.error_message {
-            ui.colored_label(egui::Color32::RED, error);
-        }
+                let cache = &self.snapshot_cache;
+                ui.separator();
+                ui.label(egui::RichText::new("Issue cache").strong());
+                egui::Grid::new("stats_cache_grid")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        for (label, value) in [
+                            (
+                                "Entries",
+                                format!("{} / {}", cache.get_issue_cache.len(), cache.capacity()),
+                            ),
+                            ("Hits", cache.hits.to_string()),
+                            ("Misses", cache.misses.to_string()),
+                            ("Evictions", cache.evictions.to_string()),
+                        ] {
+                            ui.label(label);
+                            ui.label(value);
+                            ui.end_row();
+                        }
+                    });
 
-        ui.separator();
+                if let Some(user) = &self.config.current_user {
+                    ui.separator();
+                    ui.label(format!(
+                        "You ({}) have {} issue{} in progress",
+                        user,
+                        my_active_count,
+                        if my_active_count == 1 { "" } else { "s" }
+                    ));
+                }
+            });
+    }
 
-        // Content
-        egui::ScrollArea::vertical()
-            .id_salt("detail_scroll")
-            .show(ui, |ui| {
-                if let Some(ref mut issue) = self.current_issue {
-                    ui.horizontal(|ui| {
-                        ui.label("ID:");
-                        ui.label(&issue.id);
-                    });
+    fn show_mark_ready_dialog(&mut self, ctx: &egui::Context) {
+        let Some(ids) = self.pending_mark_ready.clone() else {
+            return;
+        };
 
-                    ui.horizontal(|ui| {
-                        ui.label("Directory:");
-                        ui.label(&issue.source_directory);
-                    });
+        let mut confirmed = false;
+        let mut cancelled = false;
 
-                    ui.horizontal(|ui| {
-                        ui.label("Title:");
-                        let title_edit = egui::TextEdit::singleline(&mut issue.title)
-                            .desired_width(f32::INFINITY);
-                        if ui.add(title_edit).changed() {
-                            self.edit_modified = true;
+        egui::Window::new("Mark Newly Ready Issues")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label("All blockers of these issues are closed. Move them to in_progress?");
+                ui.separator();
+                egui::ScrollArea::vertical()
+                    .max_height(240.0)
+                    .show(ui, |ui| {
+                        for id in &ids {
+                            let title = self
+                                .issues
+                                .iter()
+                                .find(|i| &i.id == id)
+                                .map(|i| i.title.as_str())
+                                .unwrap_or("");
+                            ui.label(format!("{} - {}", id, title));
                         }
                     });
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Yes, mark in_progress").clicked() {
+                        confirmed = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        cancelled = true;
+                    }
+                });
+            });
 
-                    ui.horizontal(|ui| {
-                        ui.label("Status:");
-                        let old_status = issue.status.clone();
-                        egui::ComboBox::from_id_salt("status_combo")
-                            .selected_text(&issue.status)
-                            .show_ui(ui, |ui| {
-                                ui.selectable_value(&mut issue.status, "open".to_string(), "open");
-                                ui.selectable_value(
-                                    &mut issue.status,
-                                    "in_progress".to_string(),
-                                    "in_progress",
-                                );
-                                ui.selectable_value(
-                                    &mut issue.status,
-                                    "closed".to_string(),
-                                    "closed",
-                                );
-                            });
-                        if issue.status != old_status {
-                            self.edit_modified = true;
-                        }
-                    });
+        if confirmed {
+            let mut errors = Vec::new();
+            for id in &ids {
+                let db_path = self
+                    .snapshot_cache
+                    .issue_sources
+                    .get(id)
+                    .and_then(|(_, path)| path.clone());
+                if let Err(e) =
+                    BdClient::update_issue(id, "status", "in_progress", db_path.as_ref())
+                {
+                    errors.push(format!("{}: {}", id, e));
+                }
+            }
+            self.pending_mark_ready = None;
+            self.current_issue = None;
+            self.refresh();
+            if !errors.is_empty() {
+                self.error_message = Some(format!("Failed to update: {}", errors.join(", ")));
+            }
+        } else if cancelled {
+            self.pending_mark_ready = None;
+        }
+    }
 
-                    ui.horizontal(|ui| {
-                        ui.label("Priority:");
-                        let old_priority = issue.priority;
-                        egui::ComboBox::from_id_salt("priority_combo")
-                            .selected_text(format!("P{}", issue.priority))
-                            .show_ui(ui, |ui| {
-                                for p in 0..=4 {
-                                    ui.selectable_value(&mut issue.priority, p, format!("P{}", p));
-                                }
-                            });
-                        if issue.priority != old_priority {
-                            self.edit_modified = true;
-                        }
-                    });
+    fn show_discard_changes_dialog(&mut self, ctx: &egui::Context) {
+        let Some(selected) = self.pending_selection else {
+            return;
+        };
 
-                    ui.horizontal(|ui| {
-                        ui.label("Type:");
-                        ui.label(&issue.issue_type);
-                    });
+        let mut discard = false;
+        let mut stay = false;
 
-                    ui.horizontal(|ui| {
-                        ui.label("Assignee:");
-                        let mut assignee_text = issue.assignee.clone().unwrap_or_default();
-                        let assignee_edit = egui::TextEdit::singleline(&mut assignee_text)
-                            .desired_width(f32::INFINITY);
-                        if ui.add(assignee_edit).changed() {
-                            issue.assignee = if assignee_text.is_empty() {
-                                None
-                            } else {
-                                Some(assignee_text)
-                            };
-                            self.edit_modified = true;
-                        }
-                    });
+        egui::Window::new("Unsaved Changes")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label("You have unsaved changes. Discard and continue, or Stay?");
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Discard").clicked() {
+                        discard = true;
+                    }
+                    if ui.button("Stay").clicked() {
+                        stay = true;
+                    }
+                });
+            });
 
-                    ui.horizontal(|ui| {
-                        ui.label("Created:");
-                        ui.label(&issue.created_at);
-                    });
+        if discard {
+            self.pending_selection = None;
+            self.edit_modified = false;
+            self.current_issue = None;
+            self.selected_index = selected;
+            self.scroll_to_selected = true;
+        } else if stay {
+            self.pending_selection = None;
+        }
+    }
 
-                    ui.horizontal(|ui| {
-                        ui.label("Updated:");
-                        ui.label(&issue.updated_at);
-                    });
+    fn show_delete_dialog(&mut self, ctx: &egui::Context) {
+        let Some(issue_id) = self.pending_delete.clone() else {
+            return;
+        };
 
-                    ui.separator();
-                    ui.label("Description:");
-                    ui.label(&issue.description);
+        let mut confirm = false;
+        let mut cancel = false;
 
-                    ui.separator();
-                    ui.label("Notes:");
-                    let mut notes_text = issue.notes.clone().unwrap_or_default();
-                    let notes_edit = egui::TextEdit::multiline(&mut notes_text)
-                        .desired_width(f32::INFINITY)
-                        .id_source("notes_edit");
-                    let notes_response = ui.add(notes_edit);
-                    if notes_response.changed() {
-                        issue.notes = if notes_text.is_empty() {
-                            None
-                        } else {
-                            Some(notes_text)
-                        };
-                        self.edit_modified = true;
-                        // Request focus to prevent losing it when Save button appears
-                        notes_response.request_focus();
+        egui::Window::new("Delete Issue")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(format!("Delete {}? This cannot be undone.", issue_id));
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui
+                        .button(egui::RichText::new("Delete").color(self.theme_tokens.error))
+                        .clicked()
+                    {
+                        confirm = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        cancel = true;
                     }
+                });
+            });
 
-                    // Separate dependencies into open/in_progress and closed
-                    let (open_blockers, closed_blockers): (Vec<_>, Vec<_>) = issue
-                        .dependencies
-                        .iter()
-                        .partition(|dep| dep.status != "closed");
+        if confirm {
+            self.pending_delete = None;
+            let db_path = self
+                .snapshot_cache
+                .issue_sources
+                .get(&issue_id)
+                .and_then(|(_, path)| path.clone());
+            match BdClient::delete_issue(&issue_id, db_path.as_ref()) {
+                Ok(()) => {
+                    self.current_issue = None;
+                    self.selected_index = None;
+                    self.edit_modified = false;
+                    self.refresh();
+                }
+                Err(e) => {
+                    self.error_message = Some(format!("Failed to delete issue: {}", e));
+                }
+            }
+        } else if cancel {
+            self.pending_delete = None;
+        }
+    }
 
-                    // Always show Blockers section (issues that must be completed before this one)
-                    ui.separator();
-                    ui.label("Blockers (issues blocking this one):");
-                    if open_blockers.is_empty() {
-                        ui.label("  None");
-                    } else {
-                        for dep in open_blockers {
-                            ui.horizontal(|ui| {
-                                ui.add_space(8.0);
-                                if ui.button(&dep.id).clicked() {
-                                    // Find the index of this dependency in the issues list
-                                    if let Some(dep_idx) =
-                                        self.issues.iter().position(|i| i.id == dep.id)
-                                    {
-                                        nav_to_issue_idx = Some(dep_idx);
-                                    }
-                                }
-                                ui.label(format!("- {}", dep.title));
-                                // Add remove button - shows confirmation dialog
-                                if ui.small_button("X").clicked() {
-                                    self.pending_blocker_removal = Some((
-                                        issue.id.clone(),
-                                        issue.title.clone(),
-                                        dep.id.clone(),
-                                        dep.title.clone(),
-                                    ));
-                                }
-                            });
+    fn show_health_banner(&mut self, ctx: &egui::Context) {
+        if !self
+            .health_warnings
+            .iter()
+            .any(|w| w.severity == Severity::Warning)
+        {
+            return;
+        }
+
+        let mut dismissed = false;
+        egui::TopBottomPanel::top("health_banner").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.vertical(|ui| {
+                    for warning in &self.health_warnings {
+                        if warning.severity == Severity::Warning {
+                            ui.colored_label(self.theme_tokens.warning, &warning.message);
                         }
                     }
+                });
+                if ui.small_button("✕").on_hover_text("Dismiss").clicked() {
+                    dismissed = true;
+                }
+            });
+        });
+
+        if dismissed {
+            self.health_warnings
+                .retain(|w| w.severity != Severity::Warning);
+        }
+    }
+
+    fn show_health_dialog(&mut self, ctx: &egui::Context) {
+        if !self
+            .health_warnings
+            .iter()
+            .any(|w| w.severity == Severity::Critical)
+        {
+            return;
+        }
+
+        let mut retry = false;
+        let mut dismissed = false;
+
+        egui::Window::new("bd Health Check")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label("beadui cannot load issues until these problems are fixed:");
+                for warning in &self.health_warnings {
+                    if warning.severity == Severity::Critical {
+                        ui.colored_label(self.theme_tokens.error, &warning.message);
+                    }
+                }
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Retry").clicked() {
+                        retry = true;
+                    }
+                    if ui.button("Continue Anyway").clicked() {
+                        dismissed = true;
+                    }
+                });
+            });
+
+        if retry {
+            self.health_warnings = BdClient::check_health(&self.config.directories);
+            self.refresh();
+        } else if dismissed {
+            self.health_warnings
+                .retain(|w| w.severity != Severity::Critical);
+        }
+    }
+
+    fn close_selected_issue(&mut self, ctx: &egui::Context) {
+        let Some(issue) = self.selected_index.and_then(|idx| self.issues.get(idx)) else {
+            return;
+        };
+        if issue.status == "closed" {
+            return;
+        }
+        // Closing reloads the issue, which would silently drop the edits
+        if self.edit_modified {
+            self.error_message = Some(format!(
+                "Save or discard the changes to {} before closing it",
+                issue.id
+            ));
+            return;
+        }
+        match self.config.transition_rule(&issue.status, "closed") {
+            Some(rule) if rule.forbidden => {
+                self.error_message =
+                    Some(format!("Cannot close {} from {}", issue.id, issue.status));
+                return;
+            }
+            Some(rule) if rule.requires_comment => {
+                // Same path as picking "closed" in the detail panel: ask for a reason first
+                self.pending_status_change =
+                    Some((issue.id.clone(), issue.status.clone(), "closed".to_string()));
+                self.status_change_reason.clear();
+                return;
+            }
+            _ => {}
+        }
+
+        let issue_id = issue.id.clone();
+        let previous_status = issue.status.clone();
+        let db_path = self
+            .snapshot_cache
+            .issue_sources
+            .get(&issue_id)
+            .and_then(|(_, path)| path.clone());
+        match BdClient::update_issue(&issue_id, "status", "closed", db_path.as_ref()) {
+            Ok(_) => {
+                self.selected_index = None;
+                self.current_issue = None;
+                self.edit_modified = false;
+                self.pending_undo = Some((issue_id, previous_status));
+                self.undo_deadline = ctx.input(|i| i.time) + 2.0;
+                self.refresh();
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to close issue: {}", e));
+            }
+        }
+    }
 
-                    // Add blocker UI
+    fn show_undo_toast(&mut self, ctx: &egui::Context) {
+        let Some((issue_id, previous_status)) = self.pending_undo.clone() else {
+            return;
+        };
+        let remaining = self.undo_deadline - ctx.input(|i| i.time);
+        if remaining <= 0.0 {
+            self.pending_undo = None;
+            return;
+        }
+        ctx.request_repaint_after(std::time::Duration::from_secs_f64(remaining));
+
+        let mut undo = false;
+        egui::Area::new(egui::Id::new("undo_toast"))
+            .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -24.0])
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
                     ui.horizontal(|ui| {
-                        ui.label("Add blocker:");
-                        let text_edit = ui.text_edit_singleline(&mut self.add_blocker_text);
-                        if ui.button("Add").clicked() && !self.add_blocker_text.trim().is_empty() {
-                            blocker_to_add = Some(self.add_blocker_text.trim().to_string());
-                            self.add_blocker_text.clear();
-                        }
-                        // Submit on Enter key
-                        if text_edit.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
-                            if !self.add_blocker_text.trim().is_empty() {
-                                blocker_to_add = Some(self.add_blocker_text.trim().to_string());
-                                self.add_blocker_text.clear();
-                            }
+                        ui.label(format!("Issue {} closed.", issue_id));
+                        if ui.button("Undo?").clicked() {
+                            undo = true;
                         }
                     });
+                });
+            });
 
-                    // Show resolved dependencies (closed blockers)
-                    if !closed_blockers.is_empty() {
-                        ui.separator();
-                        ui.label("Resolved Dependencies:");
-                        for dep in closed_blockers {
-                            ui.horizontal(|ui| {
-                                ui.add_space(8.0);
-                                if ui.button(&dep.id).clicked() {
-                                    // Find the index of this dependency in the issues list
-                                    if let Some(dep_idx) =
-                                        self.issues.iter().position(|i| i.id == dep.id)
-                                    {
-                                        nav_to_issue_idx = Some(dep_idx);
-                                    }
-                                }
-                                ui.label(format!("- {}", dep.title));
-                                // Add remove button - shows confirmation dialog
-                                if ui.small_button("X").clicked() {
-                                    self.pending_blocker_removal = Some((
-                                        issue.id.clone(),
-                                        issue.title.clone(),
-                                        dep.id.clone(),
-                                        dep.title.clone(),
-                                    ));
-                                }
-                            });
-                        }
-                    }
-
-                    // Always show Dependents section (issues blocked by this one)
-                    ui.separator();
-                    ui.label("Dependents (issues blocked by this one):");
-                    if let Some(dependent_ids) = self.dependents_map.get(&issue.id) {
-                        for dependent_id in dependent_ids {
-                            if let Some(dependent) =
-                                self.issues.iter().find(|i| &i.id == dependent_id)
-                            {
-                                ui.horizontal(|ui| {
-                                    ui.add_space(8.0);
-                                    if ui.button(&dependent.id).clicked() {
-                                        // Find the index of this dependent in the issues list
-                                        if let Some(dep_idx) =
-                                            self.issues.iter().position(|i| i.id == dependent.id)
-                                        {
-                                            nav_to_issue_idx = Some(dep_idx);
-                                        }
-                                    }
-                                    ui.label(format!("- {}", dependent.title));
-                                });
-                            }
-                        }
-                    } else {
-                        ui.label("  None");
-                    }
+        if undo {
+            self.pending_undo = None;
+            let db_path = self
+                .snapshot_cache
+                .issue_sources
+                .get(&issue_id)
+                .and_then(|(_, path)| path.clone());
+            match BdClient::update_issue(&issue_id, "status", &previous_status, db_path.as_ref()) {
+                Ok(_) => self.refresh(),
+                Err(e) => {
+                    self.error_message = Some(format!("Failed to reopen issue: {}", e));
                 }
+            }
+        }
+    }
+
+    /// Put text on the clipboard and confirm it with a short toast. This goes through
+    /// egui rather than a separate arboard::Clipboard: eframe already holds the platform
+    /// clipboard (arboard on desktop) and writes to it at the end of the frame
+    fn copy_to_clipboard(&mut self, ctx: &egui::Context, text: String) {
+        ctx.copy_text(text);
+        let deadline = ctx.input(|i| i.time) + COPIED_TOAST_SECS;
+        self.copied_toast = Some(("Copied!".to_string(), deadline));
+    }
+
+    fn show_copied_toast(&mut self, ctx: &egui::Context) {
+        let Some((message, deadline)) = self.copied_toast.clone() else {
+            return;
+        };
+        let remaining = deadline - ctx.input(|i| i.time);
+        if remaining <= 0.0 {
+            self.copied_toast = None;
+            return;
+        }
+        ctx.request_repaint_after(std::time::Duration::from_secs_f64(remaining));
+
+        // Sits above the undo toast so both can show at once
+        egui::Area::new(egui::Id::new("copied_toast"))
+            .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -64.0])
+            .order(egui::Order::Foreground)
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.label(message);
+                });
             });
+    }
 
-        // Handle actions after borrowing
-        if should_refresh {
-            self.current_issue = None;
-            self.edit_modified = false;
+    fn show_bulk_dialog(&mut self, ctx: &egui::Context) {
+        if !self.show_bulk_dialog || self.multi_selected.is_empty() {
+            self.show_bulk_dialog = false;
+            return;
         }
 
-        if should_save {
-            if let Some(issue) = self.current_issue.clone() {
-                self.save_issue_changes(&issue);
+        // Tags may only be complete on the full issues
+        let selected_issues: Vec<Issue> = self
+            .issues
+            .iter()
+            .filter(|issue| self.multi_selected.contains(&issue.id))
+            .map(|issue| {
+                self.snapshot_cache
+                    .get_issue(&issue.id)
+                    .unwrap_or_else(|_| issue.clone())
+            })
+            .collect();
+        let common_tags = compute_common_tags(&self.multi_selected, &selected_issues);
+        if !common_tags.contains(&self.bulk_remove_tag) {
+            self.bulk_remove_tag = common_tags.first().cloned().unwrap_or_default();
+        }
+
+        let mut add_tags = None;
+        let mut remove_tag = None;
+        let mut open = true;
+
+        egui::Window::new(format!(
+            "Bulk Update ({} issues)",
+            self.multi_selected.len()
+        ))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label("Add Tag to Selected:");
+            ui.horizontal(|ui| {
+                ui.add(TagAutocomplete::new(&self.all_tags, &mut self.bulk_add_tag));
+                let tags = parse_tags(&self.bulk_add_tag);
+                if ui
+                    .add_enabled(!tags.is_empty(), egui::Button::new("Add Tag"))
+                    .clicked()
+                {
+                    add_tags = Some(tags);
+                }
+            });
+
+            ui.separator();
+            ui.label("Remove Tag from Selected:");
+            if common_tags.is_empty() {
+                ui.weak("The selected issues share no tags");
+            } else {
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_salt("bulk_remove_tag")
+                        .selected_text(&self.bulk_remove_tag)
+                        .show_ui(ui, |ui| {
+                            for tag in &common_tags {
+                                ui.selectable_value(&mut self.bulk_remove_tag, tag.clone(), tag);
+                            }
+                        });
+                    if ui.button("Remove Tag").clicked() {
+                        remove_tag = Some(self.bulk_remove_tag.clone());
+                    }
+                });
             }
+        });
+
+        if !open {
+            self.show_bulk_dialog = false;
+        }
+        if add_tags.is_none() && remove_tag.is_none() {
+            return;
         }
 
-        if let Some(new_idx) = nav_to_issue_idx {
-            self.selected_index = Some(new_idx);
-            self.current_issue = None;
-            self.edit_modified = false;
+        let mut errors = Vec::new();
+        for issue in &selected_issues {
+            let id = &issue.id;
+            let mut tags = issue.tags.clone();
+            if let Some(new_tags) = &add_tags {
+                for tag in new_tags {
+                    if !tags.contains(tag) {
+                        tags.push(tag.clone());
+                    }
+                }
+            }
+            if let Some(tag) = &remove_tag {
+                tags.retain(|t| t != tag);
+            }
+            if tags == issue.tags {
+                continue;
+            }
+
+            let db_path = self
+                .snapshot_cache
+                .issue_sources
+                .get(id)
+                .and_then(|(_, path)| path.clone());
+            if let Err(e) = BdClient::update_issue(id, "tags", &tags.join(","), db_path.as_ref()) {
+                errors.push(format!("{}: {}", id, e));
+            }
+        }
+        self.bulk_add_tag.clear();
+        self.current_issue = None;
+        self.refresh();
+        if !errors.is_empty() {
+            self.error_message = Some(format!("Failed to update tags: {}", errors.join(", ")));
         }
+    }
 
-        // Handle blocker addition
-        if let Some(blocker_id) = blocker_to_add {
-            if let Some(issue) = &self.current_issue {
-                // Look up the db_path for this issue from the snapshot cache
-                let db_path = self
-                    .snapshot_cache
-                    .issue_sources
-                    .get(&issue.id)
-                    .and_then(|(_, path)| path.clone());
+    fn show_status_reason_dialog(&mut self, ctx: &egui::Context) {
+        let Some((issue_id, from, to)) = self.pending_status_change.clone() else {
+            return;
+        };
 
-                match BdClient::add_dependency(&issue.id, &blocker_id, db_path.as_ref()) {
-                    Ok(_) => {
-                        // Refresh the current issue and the list
-                        self.current_issue = None;
-                        self.refresh();
+        let mut confirmed = false;
+        let mut cancelled = false;
+
+        egui::Window::new("Reason Required")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(format!(
+                    "Moving {} from {} to {} requires a reason:",
+                    issue_id, from, to
+                ));
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.status_change_reason)
+                        .desired_rows(3)
+                        .desired_width(320.0),
+                );
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let has_reason = !self.status_change_reason.trim().is_empty();
+                    if ui
+                        .add_enabled(has_reason, egui::Button::new("Change Status"))
+                        .clicked()
+                    {
+                        confirmed = true;
                     }
-                    Err(e) => {
-                        self.error_message = Some(format!("Failed to add blocker: {}", e));
+                    if ui.button("Cancel").clicked() {
+                        cancelled = true;
                     }
-                }
+                });
+            });
+
+        if confirmed {
+            if let Some(issue) = self.current_issue.as_mut().filter(|i| i.id == issue_id) {
+                let entry = format!(
+                    "Status changed from {} to {}: {}",
+                    from,
+                    to,
+                    self.status_change_reason.trim()
+                );
+                issue.notes = Some(match issue.notes.take().filter(|n| !n.is_empty()) {
+                    Some(notes) => format!("{}\n\n{}", notes, entry),
+                    None => entry,
+                });
+                issue.status = to;
+                self.edit_modified = true;
             }
+            self.pending_status_change = None;
+        } else if cancelled {
+            self.pending_status_change = None;
+        }
+    }
+
+    fn show_tour(&mut self, ctx: &egui::Context) {
+        let Some(tour) = self.tour.as_mut() else {
+            return;
+        };
+
+        // Anchors move with the layout, so rebuild the steps every frame
+        tour.steps = self.tour_anchors.steps();
+        let Some(current) = tour.steps.get(tour.step) else {
+            return;
+        };
+        let step_count = tour.steps.len();
+        let is_last = tour.step + 1 >= step_count;
+
+        let screen = ctx.screen_rect();
+        let spotlight = current.target_rect.expand(4.0);
+        let mut next_clicked = false;
+        let mut skip_clicked = false;
+
+        // Dim everything outside the spotlight and swallow clicks on the dimmed area
+        egui::Area::new(egui::Id::new("tour_dim"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(screen.min)
+            .show(ctx, |ui| {
+                ui.allocate_rect(screen, egui::Sense::click());
+                let dim = egui::Color32::from_black_alpha(160);
+                let painter = ui.painter();
+                for rect in [
+                    egui::Rect::from_min_max(screen.min, egui::pos2(screen.max.x, spotlight.min.y)),
+                    egui::Rect::from_min_max(egui::pos2(screen.min.x, spotlight.max.y), screen.max),
+                    egui::Rect::from_min_max(
+                        egui::pos2(screen.min.x, spotlight.min.y),
+                        egui::pos2(spotlight.min.x, spotlight.max.y),
+                    ),
+                    egui::Rect::from_min_max(
+                        egui::pos2(spotlight.max.x, spotlight.min.y),
+                        egui::pos2(screen.max.x, spotlight.max.y),
+                    ),
+                ] {
+                    painter.rect_filled(rect, 0.0, dim);
+                }
+                painter.rect_stroke(
+                    spotlight,
+                    4.0,
+                    egui::Stroke::new(2.0, ui.visuals().selection.stroke.color),
+                );
+            });
+
+        // Place the callout below the target, or above it when there is no room
+        const CALLOUT_WIDTH: f32 = 280.0;
+        let below = spotlight.max.y + 8.0;
+        let callout_y = if below + 140.0 < screen.max.y {
+            below
+        } else {
+            (spotlight.min.y - 148.0).max(screen.min.y)
+        };
+        let callout_x = spotlight
+            .min
+            .x
+            .min(screen.max.x - CALLOUT_WIDTH - 16.0)
+            .max(screen.min.x);
+
+        egui::Area::new(egui::Id::new("tour_callout"))
+            .order(egui::Order::Tooltip)
+            .fixed_pos(egui::pos2(callout_x, callout_y))
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.set_width(CALLOUT_WIDTH);
+                    ui.label(egui::RichText::new(&current.title).strong());
+                    ui.label(&current.body);
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{} of {}", tour.step + 1, step_count));
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if ui.button(if is_last { "Done" } else { "Next" }).clicked() {
+                                next_clicked = true;
+                            }
+                            if !is_last && ui.button("Skip Tour").clicked() {
+                                skip_clicked = true;
+                            }
+                        });
+                    });
+                });
+            });
+
+        if next_clicked && !is_last {
+            tour.step += 1;
+        } else if next_clicked || skip_clicked {
+            self.tour = None;
+            self.config.has_seen_tour = true;
+            let _ = self.config.save();
         }
     }
 
@@ -2453,35 +9597,92 @@ impl BeadUiApp {
             .get(&issue.id)
             .and_then(|(_, path)| path.clone());
 
-        // Update title
-        if let Err(e) = BdClient::update_issue(&issue.id, "title", &issue.title, db_path.as_ref()) {
-            errors.push(format!("title: {}", e));
+        // Compare against the issue as loaded so only edited fields are written
+        let original = self
+            .original_issue
+            .clone()
+            .filter(|original| original.id == issue.id);
+        let mut updates: Vec<(String, String)> = Vec::new();
+        let differs = |value: fn(&Issue) -> String| {
+            original.as_ref().is_none_or(|o| value(o) != value(issue))
+        };
+        if differs(|i| i.title.clone()) {
+            updates.push(("title".to_string(), issue.title.clone()));
         }
-
-        // Update status
-        if let Err(e) = BdClient::update_issue(&issue.id, "status", &issue.status, db_path.as_ref()) {
-            errors.push(format!("status: {}", e));
+        if differs(|i| i.status.clone()) {
+            updates.push(("status".to_string(), issue.status.clone()));
+        }
+        if differs(|i| i.priority.to_string()) {
+            updates.push(("priority".to_string(), issue.priority.to_string()));
+        }
+        if differs(|i| i.issue_type.clone()) {
+            updates.push(("type".to_string(), issue.issue_type.clone()));
+        }
+        // An emptied assignee or notes field is written as "" to clear it
+        if differs(|i| i.assignee.clone().unwrap_or_default()) {
+            let assignee = issue.assignee.clone().unwrap_or_default();
+            updates.push(("assignee".to_string(), assignee));
+        }
+        if differs(|i| i.description.clone()) {
+            updates.push(("description".to_string(), issue.description.clone()));
+        }
+        if differs(|i| i.notes.clone().unwrap_or_default()) {
+            updates.push(("notes".to_string(), issue.notes.clone().unwrap_or_default()));
+        }
+        if differs(|i| i.tags.join(",")) {
+            updates.push(("tags".to_string(), issue.tags.join(",")));
         }
 
-        // Update priority
-        if let Err(e) = BdClient::update_issue(&issue.id, "priority", &issue.priority.to_string(), db_path.as_ref()) {
-            errors.push(format!("priority: {}", e));
+        // Update custom fields that were added, edited or removed (removal clears the value)
+        let original_fields = original
+            .as_ref()
+            .map(|original| original.custom_fields.clone())
+            .unwrap_or_default();
+        let removed_fields = original_fields
+            .keys()
+            .filter(|key| !issue.custom_fields.contains_key(*key))
+            .map(|key| (key, ""));
+        let changed_fields = issue
+            .custom_fields
+            .iter()
+            .filter(|(key, value)| original_fields.get(*key) != Some(*value))
+            .map(|(key, value)| (key, value.as_str()));
+        for (key, value) in changed_fields.chain(removed_fields) {
+            updates.push((format!("custom.{}", key), value.to_string()));
         }
 
-        // Update assignee
-        if let Some(ref assignee) = issue.assignee {
-            if let Err(e) = BdClient::update_issue(&issue.id, "assignee", assignee, db_path.as_ref()) {
-                errors.push(format!("assignee: {}", e));
+        for (field, value) in &updates {
+            if let Err(e) = BdClient::update_issue(&issue.id, field, value, db_path.as_ref()) {
+                errors.push(format!("{}: {}", field, e));
             }
         }
-
-        // Update notes
-        if let Some(ref notes) = issue.notes {
-            if let Err(e) = BdClient::update_issue(&issue.id, "notes", notes, db_path.as_ref()) {
-                errors.push(format!("notes: {}", e));
+        let mut fields_changed = updates.len();
+
+        // Update comments whose resolved flag was toggled
+        let original_comments = original
+            .as_ref()
+            .map(|original| original.comments.clone())
+            .unwrap_or_default();
+        for comment in &issue.comments {
+            let was_resolved = original_comments
+                .iter()
+                .find(|c| c.id == comment.id)
+                .map(|c| c.resolved)
+                .unwrap_or(false);
+            if comment.resolved != was_resolved {
+                fields_changed += 1;
+                let marker = resolution_marker(comment.id, comment.resolved);
+                if let Err(e) = BdClient::add_comment(&issue.id, &marker, db_path.as_ref()) {
+                    errors.push(format!("comment {}: {}", comment.id, e));
+                }
             }
         }
 
+        let summary = match fields_changed {
+            1 => "1 field changed".to_string(),
+            n => format!("{} fields changed", n),
+        };
+        self.save_message = Some((issue.id.clone(), summary));
         if errors.is_empty() {
             self.error_message = None;
             self.edit_modified = false;
@@ -2505,6 +9706,42 @@ impl BeadUiApp {
             .default_width(500.0)
             .show(ctx, |ui| {
                 ui.vertical(|ui| {
+                    if !self.config.issue_templates.is_empty() {
+                        ui.horizontal(|ui| {
+                            ui.label("Template:");
+                            let selected_name = self
+                                .create_template_index
+                                .and_then(|idx| self.config.issue_templates.get(idx))
+                                .map(|t| t.name.as_str())
+                                .unwrap_or("(none)");
+                            let mut template_index = self.create_template_index;
+                            egui::ComboBox::from_id_salt("create_template_combo")
+                                .selected_text(selected_name)
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(&mut template_index, None, "(none)");
+                                    for (idx, template) in
+                                        self.config.issue_templates.iter().enumerate()
+                                    {
+                                        ui.selectable_value(
+                                            &mut template_index,
+                                            Some(idx),
+                                            &template.name,
+                                        );
+                                    }
+                                });
+                            if template_index != self.create_template_index {
+                                self.create_template_index = template_index;
+                                if let Some(template) = template_index
+                                    .and_then(|idx| self.config.issue_templates.get(idx))
+                                {
+                                    self.create_type = template.issue_type.clone();
+                                    self.create_priority = template.priority;
+                                    self.create_description = template.description.clone();
+                                }
+                            }
+                        });
+                    }
+
                     ui.horizontal(|ui| {
                         ui.label("Title:");
                         ui.add(
@@ -2512,16 +9749,41 @@ impl BeadUiApp {
                                 .desired_width(f32::INFINITY),
                         );
                     });
+                    for error in title_errors(&self.create_title, &self.config) {
+                        ui.colored_label(self.theme_tokens.error, error);
+                    }
 
                     ui.horizontal(|ui| {
                         ui.label("Description:");
-                        ui.add(
+                        let description_response = ui.add(
                             egui::TextEdit::multiline(&mut self.create_description)
                                 .desired_rows(4)
                                 .desired_width(f32::INFINITY),
                         );
+                        if description_response.has_focus() {
+                            description_response.show_tooltip_text(
+                                "Template Variables:\n\
+                                 {{title}} - issue title\n\
+                                 {{date}} - today's date\n\
+                                 {{assignee}} - assignee\n\
+                                 {{id}} - new issue id (filled in after creation)",
+                            );
+                        }
                     });
 
+                    // Live preview of placeholder substitution
+                    if self.create_description.contains("{{") {
+                        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+                        let mut vars =
+                            template_vars(&self.create_title, &today, &self.create_assignee);
+                        vars.insert("id", "<new id>");
+                        ui.label(egui::RichText::new("Preview:").small());
+                        egui::Frame::group(ui.style()).show(ui, |ui| {
+                            ui.set_width(ui.available_width());
+                            ui.label(substitute_template(&self.create_description, &vars));
+                        });
+                    }
+
                     ui.horizontal(|ui| {
                         ui.label("Type:");
                         egui::ComboBox::from_id_salt("create_type_combo")
@@ -2547,8 +9809,16 @@ impl BeadUiApp {
                                     "epic".to_string(),
                                     "epic",
                                 );
+                                ui.selectable_value(
+                                    &mut self.create_type,
+                                    "chore".to_string(),
+                                    "chore",
+                                );
                             });
                     });
+                    for error in issue_type_errors(&self.create_type, &self.config) {
+                        ui.colored_label(self.theme_tokens.error, error);
+                    }
 
                     ui.horizontal(|ui| {
                         ui.label("Priority:");
@@ -2573,6 +9843,11 @@ impl BeadUiApp {
                         );
                     });
 
+                    ui.horizontal(|ui| {
+                        ui.label("Tags:");
+                        ui.add(TagAutocomplete::new(&self.all_tags, &mut self.create_tags));
+                    });
+
                     ui.horizontal(|ui| {
                         ui.label("Directory:");
                         let selected_dir_name = self
@@ -2584,7 +9859,14 @@ impl BeadUiApp {
                         egui::ComboBox::from_id_salt("create_directory_combo")
                             .selected_text(selected_dir_name)
                             .show_ui(ui, |ui| {
-                                for (idx, dir) in self.config.directories.iter().enumerate() {
+                                // Issues can only land in directories that are being shown
+                                for (idx, dir) in self
+                                    .config
+                                    .directories
+                                    .iter()
+                                    .enumerate()
+                                    .filter(|(_, d)| d.visible)
+                                {
                                     ui.selectable_value(
                                         &mut self.create_directory_index,
                                         idx,
@@ -2597,7 +9879,13 @@ impl BeadUiApp {
                     ui.separator();
 
                     ui.horizontal(|ui| {
-                        if ui.button("Create").clicked() {
+                        let is_valid =
+                            validate_new_issue(&self.create_title, &self.create_type, &self.config)
+                                .is_empty();
+                        if ui
+                            .add_enabled(is_valid, egui::Button::new("Create"))
+                            .clicked()
+                        {
                             should_create = true;
                         }
                         if ui.button("Cancel").clicked() {
@@ -2609,8 +9897,9 @@ impl BeadUiApp {
 
         // Handle actions after dialog closes
         if should_create {
-            if self.create_title.is_empty() {
-                self.error_message = Some("Title is required".to_string());
+            let errors = validate_new_issue(&self.create_title, &self.create_type, &self.config);
+            if !errors.is_empty() {
+                self.error_message = Some(errors.join(", "));
             } else {
                 // Get the db_path for the selected directory
                 let db_path = self
@@ -2625,21 +9914,38 @@ impl BeadUiApp {
                     Some(self.create_assignee.as_str())
                 };
 
+                // {{id}} is only known once bd has created the issue
+                let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+                let vars = template_vars(&self.create_title, &today, &self.create_assignee);
+                let description = substitute_template(&self.create_description, &vars);
+
                 match BdClient::create_issue(
                     &self.create_title,
-                    &self.create_description,
+                    &description,
                     &self.create_type,
                     self.create_priority,
                     assignee,
+                    &parse_tags(&self.create_tags),
                     db_path.as_ref(),
                 ) {
-                    Ok(_) => {
+                    Ok(new_id) => {
+                        if let Some(new_id) = new_id.filter(|_| description.contains("{{id}}")) {
+                            let vars = HashMap::from([("id", new_id.as_str())]);
+                            let _ = BdClient::update_issue(
+                                &new_id,
+                                "description",
+                                &substitute_template(&description, &vars),
+                                db_path.as_ref(),
+                            );
+                        }
+
                         // Clear the form
                         self.create_title.clear();
                         self.create_description.clear();
                         self.create_type = "task".to_string();
                         self.create_priority = 2;
                         self.create_assignee.clear();
+                        self.create_tags.clear();
                         // Reset to first visible directory
                         self.create_directory_index = self
                             .config
@@ -2667,6 +9973,7 @@ impl BeadUiApp {
             self.create_type = "task".to_string();
             self.create_priority = 2;
             self.create_assignee.clear();
+            self.create_tags.clear();
             // Reset to first visible directory
             self.create_directory_index = self
                 .config
@@ -2680,7 +9987,19 @@ impl BeadUiApp {
 
 impl eframe::App for BeadUiApp {
     fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        self.tour_anchors = TourAnchors::default();
+        self.theme_tokens = ThemeTokens::from_config(&self.config.theme, &ctx.style().visuals);
+        self.poll_refresh();
+        if self.loading {
+            // The loader thread has no context to wake the window with
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+        #[cfg(feature = "tray")]
+        self.poll_tray(ctx);
+        self.update_window_title(ctx);
+        self.track_window_geometry(ctx);
         self.show_list_view(ctx, frame);
+        self.show_health_dialog(ctx);
 
         // Show create dialog if enabled
         if self.show_create_dialog {
@@ -2739,18 +10058,344 @@ impl eframe::App for BeadUiApp {
                 self.pending_blocker_removal = None;
             }
         }
+
+        if self.show_settings {
+            self.show_settings_window(ctx);
+        }
+
+        if self.show_stats {
+            self.show_stats_window(ctx);
+        }
+
+        self.show_mark_ready_dialog(ctx);
+        self.show_status_reason_dialog(ctx);
+        self.show_undo_toast(ctx);
+        self.show_copied_toast(ctx);
+        self.show_bulk_dialog(ctx);
+        self.show_discard_changes_dialog(ctx);
+        self.show_delete_dialog(ctx);
+
+        // Tour overlay is drawn last so it sits above everything else
+        self.show_tour(ctx);
+
+        self.persist_view_state();
     }
 }
 
+// How long the window must stay put before its geometry is written to config
+const WINDOW_GEOMETRY_SAVE_DELAY_SECS: f64 = 1.0;
+
+const WINDOW_TITLE: &str = "Beads Issue Tracker";
+
 fn main() -> eframe::Result<()> {
+    let config = AppConfig::load();
+    let size = config
+        .window_width
+        .zip(config.window_height)
+        .filter(|(w, h)| *w >= 200.0 && *h >= 150.0)
+        .unwrap_or((1200.0, 800.0));
+    let mut viewport = egui::ViewportBuilder::default().with_inner_size([size.0, size.1]);
+    if let Some((x, y)) = config.window_x.zip(config.window_y) {
+        viewport = viewport.with_position([x, y]);
+    }
+
     let options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default().with_inner_size([1200.0, 800.0]),
+        viewport,
         ..Default::default()
     };
 
     eframe::run_native(
-        "Beads Issue Tracker",
+        WINDOW_TITLE,
         options,
         Box::new(|cc| Ok(Box::new(BeadUiApp::new(cc)))),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod integration;
+
+    /// Minimal open issue with the given id
+    pub(super) fn test_issue(id: &str) -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "title": format!("Issue {}", id),
+            "description": "",
+            "status": "open",
+            "priority": 2,
+            "issue_type": "task",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z",
+        }))
+        .unwrap()
+    }
+
+    /// Fresh, empty scratch directory unique to this test run
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("beadui-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_update_issue_rejects_unknown_field() {
+        let result = BdClient::update_issue("bd-1", "colour", "red", None);
+        assert_eq!(result, Err("Unknown field 'colour'".to_string()));
+    }
+
+    #[test]
+    fn validate_update_field_accepts_known_and_custom_fields() {
+        for field in KNOWN_FIELDS {
+            assert!(validate_update_field(field).is_ok(), "{}", field);
+        }
+        assert!(validate_update_field("custom.team").is_ok());
+        assert!(validate_update_field("custom").is_err());
+        assert!(validate_update_field("Title").is_err());
+    }
+
+    #[test]
+    fn bd_commands_run_from_the_monitored_directory() {
+        let dir = scratch_dir("cwd");
+        fs::create_dir_all(dir.join(".beads")).unwrap();
+        fs::write(dir.join(".beads").join("issues.db"), "").unwrap();
+
+        let cmd = BdClient::command(Some(&dir));
+        assert_eq!(cmd.get_current_dir(), Some(dir.as_path()));
+        let args: Vec<&std::ffi::OsStr> = cmd.get_args().collect();
+        let db_file = dir.join(".beads").join("issues.db");
+        assert_eq!(args, [std::ffi::OsStr::new("--db"), db_file.as_os_str()]);
+
+        let cmd = BdClient::command(None);
+        assert_eq!(cmd.get_current_dir(), None);
+        assert_eq!(cmd.get_args().count(), 0);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn dependents_map_lists_issues_under_their_blockers() {
+        let issues: Vec<Issue> = ["a", "b", "c"].iter().map(|id| test_issue(id)).collect();
+        let mut cache = SnapshotCache::new(10);
+        for issue in &issues {
+            let mut full = issue.clone();
+            if issue.id != "a" {
+                full.dependencies.push(issues[0].clone());
+            }
+            cache.insert_issue(issue.id.clone(), full);
+        }
+
+        let map = build_dependents_map(&issues, &mut cache);
+        assert_eq!(map.len(), 1);
+        assert_eq!(map["a"], ["b", "c"]);
+    }
+
+    #[test]
+    fn snapshot_cache_evicts_the_least_recently_used() {
+        let mut cache = SnapshotCache::new(2);
+        cache.insert_issue("a".to_string(), test_issue("a"));
+        cache.insert_issue("b".to_string(), test_issue("b"));
+        // Using a makes b the oldest
+        assert!(cache.get_issue_ref("a").is_ok());
+        cache.insert_issue("c".to_string(), test_issue("c"));
+
+        assert!(cache.get_issue_cache.contains_key("a"));
+        assert!(!cache.get_issue_cache.contains_key("b"));
+        assert!(cache.get_issue_cache.contains_key("c"));
+        assert_eq!((cache.hits, cache.evictions), (1, 1));
+
+        // An invalidated id leaves the use order too, so it is never chosen for eviction
+        cache.invalidate("a");
+        cache.insert_issue("d".to_string(), test_issue("d"));
+        assert_eq!(cache.use_order.values().collect::<Vec<_>>(), ["c", "d"]);
+        assert_eq!(cache.evictions, 1);
+    }
+
+    #[test]
+    fn column_filters_roundtrip_through_yaml() {
+        let mut with_patterns = ColumnFilter::new_with_excluded(vec!["bob".to_string()]);
+        with_patterns.included_values.insert("alice".to_string());
+        with_patterns.add_pattern(regex::Regex::new("^bot-").unwrap());
+        let filters = HashMap::from([
+            (
+                SortColumn::Status,
+                ColumnFilter::new_with_excluded(vec!["closed".to_string(), "blocked".to_string()]),
+            ),
+            (SortColumn::Assignee, with_patterns),
+        ]);
+
+        let yaml = serde_yaml::to_string(&filters).unwrap();
+        let parsed: HashMap<SortColumn, ColumnFilter> = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed, filters);
+    }
+
+    #[test]
+    fn column_filter_yaml_is_a_list_of_excluded_values() {
+        let filters = HashMap::from([(
+            SortColumn::Status,
+            ColumnFilter::new_with_excluded(vec!["closed".to_string(), "blocked".to_string()]),
+        )]);
+        let yaml = serde_yaml::to_string(&filters).unwrap();
+        assert_eq!(yaml, "Status:\n- blocked\n- closed\n");
+    }
+
+    #[test]
+    fn column_filter_drops_patterns_that_no_longer_compile() {
+        let filter: ColumnFilter =
+            serde_yaml::from_str("excluded_values: [x]\nexcluded_patterns: ['(', 'ok']\n").unwrap();
+        assert!(filter.excluded_values.contains("x"));
+        let patterns: Vec<&str> = filter
+            .excluded_patterns
+            .iter()
+            .map(|re| re.as_str())
+            .collect();
+        assert_eq!(patterns, ["ok"]);
+    }
+
+    #[test]
+    fn latest_resolution_marker_wins_and_markers_are_hidden() {
+        let comment = |id: i64, text: &str| Comment {
+            id,
+            author: "alice".to_string(),
+            text: text.to_string(),
+            created_at: String::new(),
+            resolved: false,
+        };
+        let comments = apply_resolution_markers(vec![
+            comment(1, "looks off"),
+            comment(2, "typo here"),
+            comment(3, &resolution_marker(1, true)),
+            comment(4, &resolution_marker(2, true)),
+            comment(5, &resolution_marker(2, false)),
+            comment(6, "Resolved comment #x is not a marker"),
+        ]);
+        let state: Vec<(i64, bool)> = comments.iter().map(|c| (c.id, c.resolved)).collect();
+        assert_eq!(state, [(1, true), (2, false), (6, false)]);
+    }
+
+    #[test]
+    fn list_entries_need_every_show_only_field() {
+        let mut value = serde_json::json!({ "id": "a-1", "dependencies": [] });
+        assert!(!is_full_issue_json(&value));
+        value["comments"] = serde_json::json!([]);
+        assert!(is_full_issue_json(&value));
+    }
+
+    #[test]
+    fn renamed_values_keep_their_filter() {
+        let mut filter = ColumnFilter::new_with_excluded(vec!["old".to_string()]);
+        filter.toggle_include("kept".to_string());
+        filter.rename_value("old", "new");
+        filter.rename_value("missing", "other");
+        assert_eq!(filter.excluded_values, HashSet::from(["new".to_string()]));
+        assert_eq!(filter.included_values, HashSet::from(["kept".to_string()]));
+    }
+
+    #[test]
+    fn search_query_parses_fields_text_and_regexes() {
+        let values = vec![
+            (SearchField::Id, "bd-1".to_string()),
+            (SearchField::Title, "see http://example.com".to_string()),
+            (SearchField::Status, "open".to_string()),
+            (SearchField::Assignee, "alice".to_string()),
+        ];
+        let matches = |query: &str| SearchQuery::parse(query).unwrap().matches(&values);
+
+        assert!(SearchQuery::parse("  ").is_none());
+        assert!(SearchQuery::parse("/(unclosed").is_none());
+        assert!(matches("/^BD-\\d$"));
+        // Unknown prefixes and empty values are plain text
+        assert!(matches("http://example.com"));
+        assert!(!matches("status:"));
+        assert!(matches("open"));
+        // Field terms and free text must all match
+        assert!(matches("status:open see"));
+        assert!(!matches("status:open missing"));
+        assert!(!matches("assignee:bob see"));
+    }
+
+    #[test]
+    fn patterns_alone_count_as_an_active_filter() {
+        let mut filter = ColumnFilter::default();
+        assert!(!filter.has_active_filters());
+        filter.add_pattern(regex::Regex::new("^bot-").unwrap());
+        assert!(filter.has_active_filters());
+    }
+
+    #[test]
+    fn card_views_seed_a_sort_default() {
+        let config: AppConfig = serde_yaml::from_str("config_version: 1\n").unwrap();
+        let defaults = &config.view_mode_sort_defaults;
+        assert_eq!(defaults["board"], (SortColumn::Priority, true));
+        assert_eq!(defaults["matrix"], (SortColumn::TransitiveImpact, false));
+        assert!(!defaults.contains_key(ViewMode::List.name()));
+    }
+
+    #[test]
+    fn csv_quotes_commas_quotes_and_line_breaks() {
+        let rows = vec![vec![
+            "plain".to_string(),
+            "a, b".to_string(),
+            "say \"hi\"".to_string(),
+            "one\r\ntwo".to_string(),
+        ]];
+        assert_eq!(
+            export::to_csv(&["id", "title", "notes", "description"], &rows),
+            "id,title,notes,description\r\nplain,\"a, b\",\"say \"\"hi\"\"\",\"one\r\ntwo\"\r\n"
+        );
+    }
+
+    #[test]
+    fn markdown_tables_escape_pipes_and_newlines() {
+        let rows = vec![vec!["bd-1".to_string(), "a|b\r\nc".to_string()]];
+        assert_eq!(
+            export::to_gfm_table(&["id", "title"], &rows),
+            "| id   | title   |\n| ---- | ------- |\n| bd-1 | a\\|b  c |\n"
+        );
+    }
+}
+
+#[cfg(test)]
+mod bench {
+    use super::*;
+    use std::hint::black_box;
+    use std::time::Instant;
+
+    /// `count` issues in a chain, each blocked by the one before, all already cached
+    fn cached_chain(count: usize) -> (Vec<Issue>, SnapshotCache) {
+        let issues: Vec<Issue> = (0..count)
+            .map(|i| tests::test_issue(&format!("bench-{}", i)))
+            .collect();
+        let mut cache = SnapshotCache::new(count);
+        for (i, issue) in issues.iter().enumerate() {
+            let mut full = issue.clone();
+            if i > 0 {
+                full.dependencies.push(issues[i - 1].clone());
+            }
+            cache.register_issue_source(&issue.id, "bench", None);
+            cache.insert_issue(issue.id.clone(), full);
+        }
+        (issues, cache)
+    }
+
+    #[test]
+    fn bench_build_dependents_map() {
+        const ROUNDS: u32 = 20;
+        for count in [100, 500, 1000] {
+            let (issues, mut cache) = cached_chain(count);
+            let start = Instant::now();
+            for _ in 0..ROUNDS {
+                let map = build_dependents_map(black_box(&issues), &mut cache);
+                assert_eq!(black_box(map).len(), count - 1);
+            }
+            eprintln!(
+                "build_dependents_map, {} cached issues: {:?} per run",
+                count,
+                start.elapsed() / ROUNDS
+            );
+            // Every lookup was served from the cache, so no bd process was started
+            assert_eq!(cache.misses, 0);
+        }
+    }
+}
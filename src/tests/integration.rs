@@ -0,0 +1,145 @@
+// BdClient against tests/integration/mock_bd.py instead of a real bd, covering the
+// command line each call builds and the parsing of what comes back
+
+use crate::*;
+
+fn mock_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/integration/mock_bd.py")
+}
+
+// The environment is shared by every test thread, so only one test at a time may point
+// BEADUI_BD_BIN at the mock
+static MOCK_LOCK: Mutex<()> = Mutex::new(());
+
+/// Points bd_binary() at the mock until dropped
+struct MockBd {
+    _lock: std::sync::MutexGuard<'static, ()>,
+}
+
+impl MockBd {
+    fn new() -> Self {
+        let lock = MOCK_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        std::env::set_var("BEADUI_BD_BIN", mock_path());
+        Self { _lock: lock }
+    }
+}
+
+impl Drop for MockBd {
+    fn drop(&mut self) {
+        std::env::remove_var("BEADUI_BD_BIN");
+    }
+}
+
+#[test]
+fn list_issues_parses_the_mock_fixtures() {
+    let _mock = MockBd::new();
+    let dir = super::scratch_dir("mock-list");
+
+    let issues = BdClient::list_issues(Some(&dir), "mock").unwrap();
+    let ids: Vec<&str> = issues.iter().map(|issue| issue.id.as_str()).collect();
+    assert_eq!(ids, ["mock-1", "mock-2"]);
+
+    let first = &issues[0];
+    assert_eq!(first.title, "Parse the list output");
+    assert_eq!(first.priority, 1);
+    assert_eq!(first.assignee.as_deref(), Some("alice"));
+    assert_eq!(first.tags, ["cli"]);
+    assert!(issues.iter().all(|issue| issue.source_directory == "mock"));
+    assert!(issues.iter().all(|issue| issue.source_path == dir));
+    // The mock leaves dependencies and comments to bd show
+    assert!(issues.iter().all(|issue| !issue.listed_in_full));
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn get_issue_includes_dependencies_from_show() {
+    let _mock = MockBd::new();
+    let dir = super::scratch_dir("mock-show");
+
+    let issue = BdClient::get_issue_uncached("mock-2", Some(&dir)).unwrap();
+    assert_eq!(issue.status, "in_progress");
+    let deps: Vec<&str> = issue
+        .dependencies
+        .iter()
+        .map(|dep| dep.id.as_str())
+        .collect();
+    assert_eq!(deps, ["mock-1"]);
+
+    assert!(BdClient::get_issue_uncached("mock-404", Some(&dir)).is_err());
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn update_issue_sends_the_field_flag() {
+    let _mock = MockBd::new();
+    let dir = super::scratch_dir("mock-update");
+
+    BdClient::update_issue("mock-1", "title", "Renamed", Some(&dir)).unwrap();
+    let log = fs::read_to_string(dir.join("mock_bd_updates.log")).unwrap();
+    assert_eq!(log, "mock-1 title Renamed\n");
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn pool_falls_back_to_one_shot_calls_when_the_server_never_answers() {
+    let dir = super::scratch_dir("mock-pool");
+    // The mock's silent mode is set for this pool's processes alone
+    let silent_bd = dir.join("silent_bd");
+    fs::write(
+        &silent_bd,
+        format!(
+            "#!/bin/sh\nMOCK_BD_SERVER=silent exec '{}' \"$@\"\n",
+            mock_path().display()
+        ),
+    )
+    .unwrap();
+    let executable = std::os::unix::fs::PermissionsExt::from_mode(0o755);
+    fs::set_permissions(&silent_bd, executable).unwrap();
+
+    let pool = BdPool::with_program(2, silent_bd.display().to_string());
+    let jobs = ["mock-1", "mock-2"]
+        .iter()
+        .map(|id| (BdClient::show_args(id, Some(&dir)), Some(dir.clone())))
+        .collect();
+    let replies = pool.run_all(jobs);
+    let ids: Vec<String> = replies
+        .into_iter()
+        .map(|reply| serde_json::from_str::<Issue>(&reply.unwrap()).unwrap().id)
+        .collect();
+    assert_eq!(ids, ["mock-1", "mock-2"]);
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn app_loads_issues_and_details_through_the_mock() {
+    let _mock = MockBd::new();
+    let dir = super::scratch_dir("mock-app");
+    let config = AppConfig {
+        directories: vec![DirectoryConfig {
+            path: dir.clone(),
+            visible: true,
+            display_name: "mock".to_string(),
+            custom_display_name: true,
+            is_glob: false,
+            is_glob_child: false,
+        }],
+        ..AppConfig::default()
+    };
+
+    let mut app = BeadUiApp::with_config(config);
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(20);
+    while app.loading && std::time::Instant::now() < deadline {
+        thread::sleep(std::time::Duration::from_millis(20));
+        app.poll_refresh();
+    }
+    assert!(!app.loading, "refresh did not finish");
+
+    assert!(app.bd_available);
+    assert!(app.directory_errors.is_empty());
+    let ids: Vec<&str> = app.issues.iter().map(|issue| issue.id.as_str()).collect();
+    assert_eq!(ids, ["mock-1", "mock-2"]);
+    // Details fetched by the refresh are cached, so no further bd show is needed
+    let cached = &app.snapshot_cache.get_issue_cache["mock-2"];
+    assert_eq!(cached.dependencies[0].id, "mock-1");
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,90 @@
+#!/usr/bin/env python3
+"""Stand-in for the bd CLI with canned issues, for the BdClient integration tests.
+
+Understands `list --json`, `show <id> --json`, `update <id> --<field> <value>` and
+`--version`. Global `--db <file>` flags are accepted and ignored. Updates are appended
+to mock_bd_updates.log in the working directory so tests can check what was sent.
+
+`server --stdio` is rejected like any unknown command, unless MOCK_BD_SERVER=silent,
+in which case it reads requests and never answers.
+"""
+
+import json
+import os
+import sys
+
+ISSUES = [
+    {
+        "id": "mock-1",
+        "title": "Parse the list output",
+        "description": "First fixture issue",
+        "status": "open",
+        "priority": 1,
+        "issue_type": "task",
+        "assignee": "alice",
+        "created_at": "2024-01-01T10:00:00Z",
+        "updated_at": "2024-01-02T10:00:00Z",
+        "labels": ["cli"],
+    },
+    {
+        "id": "mock-2",
+        "title": "Blocked by the first issue",
+        "description": "",
+        "status": "in_progress",
+        "priority": 2,
+        "issue_type": "bug",
+        "created_at": "2024-01-03T10:00:00Z",
+        "updated_at": "2024-01-04T10:00:00Z",
+    },
+]
+
+# Only bd show includes dependencies
+DEPENDENCIES = {"mock-2": ["mock-1"]}
+
+
+def show(issue_id):
+    issue = next((issue for issue in ISSUES if issue["id"] == issue_id), None)
+    if issue is None:
+        sys.stderr.write("Error: issue {} not found\n".format(issue_id))
+        return 1
+    full = dict(issue)
+    full["dependencies"] = [
+        next(dep for dep in ISSUES if dep["id"] == dep_id)
+        for dep_id in DEPENDENCIES.get(issue_id, [])
+    ]
+    print(json.dumps(full))
+    return 0
+
+
+def main(argv):
+    args = []
+    while argv:
+        arg = argv.pop(0)
+        if arg == "--db":
+            argv = argv[1:]
+        else:
+            args.append(arg)
+
+    if args == ["server", "--stdio"] and os.environ.get("MOCK_BD_SERVER") == "silent":
+        for _ in sys.stdin:
+            pass
+        return 0
+    if args == ["--version"]:
+        print("bd version 0.0.0 (mock)")
+        return 0
+    if args == ["list", "--json"]:
+        print(json.dumps(ISSUES))
+        return 0
+    if len(args) == 3 and args[0] == "show" and args[2] == "--json":
+        return show(args[1])
+    if len(args) == 4 and args[0] == "update" and args[2].startswith("--"):
+        with open("mock_bd_updates.log", "a") as log:
+            log.write("{} {} {}\n".format(args[1], args[2][2:], args[3]))
+        return 0
+
+    sys.stderr.write("mock bd: unsupported arguments {}\n".format(args))
+    return 2
+
+
+if __name__ == "__main__":
+    sys.exit(main(sys.argv[1:]))